@@ -9,10 +9,13 @@ Commands:
     gitt admin payout-issue (alias: a payout-issue)
     gitt admin set-owner (alias: a set-owner)
     gitt admin set-treasury (alias: a set-treasury)
+    gitt admin execute-config (alias: a execute-config)
     gitt admin add-vali (alias: a add-vali)
     gitt admin remove-vali (alias: a remove-vali)
 """
 
+from typing import Optional
+
 import click
 from rich.panel import Panel
 
@@ -45,11 +48,23 @@ def admin():
 
 @admin.command('cancel-issue')
 @click.argument('issue_id', type=CONTRACT_ISSUE)
+@click.option(
+    '--reason',
+    default=None,
+    help='Optional cancellation reason recorded on-chain (max 256 bytes)',
+)
 @with_wallet_options()
 @with_network_contract_options('Contract address (uses config if empty)')
 @with_cli_behavior_options(include_yes=True)
 def admin_cancel(
-    issue_id: int, network: str, rpc_url: str, contract: str, wallet_name: str, wallet_hotkey: str, yes: bool
+    issue_id: int,
+    reason: Optional[str],
+    network: str,
+    rpc_url: str,
+    contract: str,
+    wallet_name: str,
+    wallet_hotkey: str,
+    yes: bool,
 ):
     """Cancel an issue (owner only).
 
@@ -62,6 +77,7 @@ def admin_cancel(
     [dim]Examples:
         $ gitt admin cancel-issue 1
         $ gitt a cancel-issue 5 --network test
+        $ gitt a cancel-issue 7 --reason "Duplicate of #6"
     [/dim]
     """
     contract_addr, ws_endpoint, network_name = _resolve_contract_and_network(contract, network, rpc_url)
@@ -81,7 +97,8 @@ def admin_cancel(
             Panel(
                 f'[cyan]Issue:[/cyan] {issue.repository_full_name}#{issue.issue_number}\n'
                 f'[cyan]Status:[/cyan] {issue.status.name}\n'
-                f'[cyan]Bounty:[/cyan] {format_alpha(issue.bounty_amount, 4)} ALPHA',
+                f'[cyan]Bounty:[/cyan] {format_alpha(issue.bounty_amount, 4)} ALPHA\n'
+                f'[cyan]Reason:[/cyan] {reason or "-"}',
                 title=f'Cancel Issue #{issue_id}',
                 border_style='yellow',
             )
@@ -91,7 +108,7 @@ def admin_cancel(
             return
 
         with err_console.status('[bold cyan]Submitting cancellation...', spinner='dots'):
-            result = client.cancel_issue(issue_id, wallet)
+            result = client.cancel_issue(issue_id, wallet, reason)
 
         if result:
             print_success(f'Issue {issue_id} cancelled successfully!')
@@ -170,7 +187,10 @@ def admin_payout(
 def admin_set_owner(
     new_owner: str, network: str, rpc_url: str, contract: str, wallet_name: str, wallet_hotkey: str, yes: bool
 ):
-    """Transfer contract ownership (owner only).
+    """Schedule a contract ownership transfer (owner only).
+
+    [dim]The transfer waits out the contract's config timelock, during which validators can cancel it.
+    Run `gitt admin execute-config` once the timelock has passed to apply it.[/dim]
 
     [dim]Arguments:
         NEW_OWNER: SS58 address of the new owner
@@ -187,24 +207,28 @@ def admin_set_owner(
     err_console.print(
         Panel(
             f'[cyan]New Owner:[/cyan] {new_owner}\n'
-            '[bold red]This transfer is IRREVERSIBLE. A mistyped address makes the contract unrecoverable.[/bold red]',
-            title='Transfer Ownership',
+            '[bold red]Once executed this transfer is IRREVERSIBLE. '
+            'A mistyped address makes the contract unrecoverable.[/bold red]',
+            title='Schedule Ownership Transfer',
             border_style='red',
         )
     )
 
-    if not confirm_or_abort(f'Transfer ownership to {new_owner}?', yes):
+    if not confirm_or_abort(f'Schedule ownership transfer to {new_owner}?', yes):
         return
 
     try:
-        with err_console.status('[bold cyan]Transferring ownership...', spinner='dots'):
+        from gittensor.validator.issue_competitions.contract_client import ConfigChangeKind
+
+        with err_console.status('[bold cyan]Scheduling ownership transfer...', spinner='dots'):
             wallet, client = _make_contract_client(contract_addr, ws_endpoint, wallet_name, wallet_hotkey)
-            result = client.set_owner(new_owner, wallet)
+            result = client.schedule_config_change(ConfigChangeKind.OWNER, new_owner, wallet)
 
         if result:
-            print_success(f'Ownership transferred to {new_owner}!')
+            print_success(f'Ownership transfer to {new_owner} scheduled!')
+            err_console.print('[dim]Run `gitt admin execute-config` once the config timelock has passed.[/dim]')
         else:
-            print_error('Ownership transfer failed.')
+            print_error('Scheduling ownership transfer failed.')
             raise SystemExit(1)
     except Exception as e:
         _handle_command_error(e)
@@ -218,10 +242,11 @@ def admin_set_owner(
 def admin_set_treasury(
     new_treasury: str, network: str, rpc_url: str, contract: str, wallet_name: str, wallet_hotkey: str, yes: bool
 ):
-    """Change treasury hotkey (owner only).
+    """Schedule a treasury hotkey change (owner only).
 
     [dim]The treasury hotkey receives staking emissions that fund bounty payouts. Changing the treasury resets all
-    Active/Registered issue bounty amounts to 0 (they will be re-funded on the next harvest from the new treasury).[/dim]
+    Active/Registered issue bounty amounts to 0 (they will be re-funded on the next harvest from the new treasury).
+    The change waits out the contract's config timelock; run `gitt admin execute-config` afterwards to apply it.[/dim]
 
     [dim]Arguments:
         NEW_TREASURY: SS58 address of the new treasury hotkey
@@ -238,28 +263,67 @@ def admin_set_treasury(
     err_console.print(
         Panel(
             f'[cyan]New Treasury:[/cyan] {new_treasury}',
-            title='Change Treasury Hotkey',
+            title='Schedule Treasury Hotkey Change',
             border_style='yellow',
         )
     )
 
     if not confirm_or_abort(
-        f'Change treasury to {new_treasury}? This resets active/registered bounty amounts to 0.', yes
+        f'Schedule treasury change to {new_treasury}? Once executed this resets active/registered bounty amounts to 0.',
+        yes,
     ):
         return
 
     try:
-        with err_console.status('[bold cyan]Updating treasury hotkey...', spinner='dots'):
+        from gittensor.validator.issue_competitions.contract_client import ConfigChangeKind
+
+        with err_console.status('[bold cyan]Scheduling treasury hotkey change...', spinner='dots'):
             wallet, client = _make_contract_client(contract_addr, ws_endpoint, wallet_name, wallet_hotkey)
-            result = client.set_treasury_hotkey(new_treasury, wallet)
+            result = client.schedule_config_change(ConfigChangeKind.TREASURY_HOTKEY, new_treasury, wallet)
 
         if result:
-            print_success(f'Treasury hotkey updated to {new_treasury}!')
-            err_console.print(
-                '[dim]Note: Issue bounty amounts have been reset. Run harvest to re-fund from new treasury.[/dim]'
-            )
+            print_success(f'Treasury hotkey change to {new_treasury} scheduled!')
+            err_console.print('[dim]Run `gitt admin execute-config` once the config timelock has passed.[/dim]')
         else:
-            print_error('Treasury hotkey update failed.')
+            print_error('Scheduling treasury hotkey change failed.')
+            raise SystemExit(1)
+    except Exception as e:
+        _handle_command_error(e)
+
+
+@admin.command('execute-config')
+@with_wallet_options()
+@with_network_contract_options('Contract address')
+@with_cli_behavior_options(include_yes=True)
+def admin_execute_config(network: str, rpc_url: str, contract: str, wallet_name: str, wallet_hotkey: str, yes: bool):
+    """Apply the scheduled config change once its timelock has passed.
+
+    [dim]Applies a pending ownership or treasury hotkey change scheduled with `set-owner` or `set-treasury`.
+    A treasury change resets Active/Registered issue bounty amounts to 0; run harvest afterwards to re-fund them.[/dim]
+
+    [dim]Examples:
+        $ gitt admin execute-config
+    [/dim]
+    """
+    contract_addr, ws_endpoint, network_name = _resolve_contract_and_network(contract, network, rpc_url)
+
+    print_network_header(network_name, contract_addr)
+
+    if not confirm_or_abort('Execute the scheduled config change?', yes):
+        return
+
+    try:
+        with err_console.status('[bold cyan]Executing config change...', spinner='dots'):
+            wallet, client = _make_contract_client(contract_addr, ws_endpoint, wallet_name, wallet_hotkey)
+            result = client.execute_config_change(wallet)
+
+        if result:
+            print_success('Scheduled config change applied!')
+        else:
+            print_error('Config change execution failed.')
+            err_console.print('[yellow]Possible reasons:[/yellow]')
+            err_console.print('  \u2022 No config change is scheduled')
+            err_console.print('  \u2022 The config timelock has not passed yet')
             raise SystemExit(1)
     except Exception as e:
         _handle_command_error(e)
@@ -656,7 +656,33 @@ def _resolve_contract_and_network(
     ws_endpoint, network_name = resolve_network(network, rpc_url)
     if not contract_addr:
         raise click.ClickException(missing_contract_message)
-    return contract_addr, ws_endpoint, network_name
+    return resolve_registry_name(contract_addr, ws_endpoint), ws_endpoint, network_name
+
+
+def resolve_registry_name(contract_addr: str, ws_endpoint: str) -> str:
+    """Resolve a registry name (e.g. ``issues-v0``) to its SS58 address.
+
+    Values that are not registry names are returned unchanged, so existing
+    ``--contract <ss58>`` usage never touches the registry.
+
+    Raises:
+        click.ClickException: If the name is not registered or no registry is configured.
+    """
+    from gittensor.utils.utils import is_registry_name
+
+    if not is_registry_name(contract_addr):
+        return contract_addr
+
+    from async_substrate_interface import SubstrateInterface
+
+    from gittensor.validator.issue_competitions.registry_client import resolve_contract_name
+
+    resolved = resolve_contract_name(SubstrateInterface(url=ws_endpoint), contract_addr)
+    if not resolved:
+        raise click.ClickException(
+            f'Could not resolve contract name "{contract_addr}". Set REGISTRY_ADDRESS or pass an SS58 address.'
+        )
+    return resolved
 
 
 # ============================================================================
@@ -9,6 +9,8 @@ Commands:
     gitt harvest
 """
 
+from typing import Optional, Tuple
+
 import click
 from rich.panel import Panel
 
@@ -66,6 +68,18 @@ def _bounty_callback(ctx: click.Context, param: click.Parameter, value: str) ->
     callback=_bounty_callback,
     help='Bounty amount in ALPHA (e.g. 10 or 10.5)',
 )
+@click.option(
+    '--difficulty',
+    default=None,
+    type=click.IntRange(0, 255),
+    help='Optional difficulty rating (0-255) used to weight payouts',
+)
+@click.option(
+    '--label',
+    'labels',
+    multiple=True,
+    help='Issue label to record on-chain as part of the label hash (repeatable)',
+)
 @click.option(
     '--network',
     '-n',
@@ -107,6 +121,8 @@ def issue_register(
     repo: str,
     issue_number: int,
     bounty_amount: int,
+    difficulty: Optional[int],
+    labels: Tuple[str, ...],
     network: str,
     rpc_url: str,
     contract: str,
@@ -124,12 +140,15 @@ def issue_register(
         --repo: Repository in owner/repo format
         --issue: GitHub issue number
         --bounty: Target bounty amount in ALPHA
+        --difficulty: Optional difficulty rating (0-255)
+        --label: Optional issue label, repeatable; the sorted set is hashed on-chain
     [/dim]
 
     [dim]Examples:
         $ gitt issues register --repo latent-to/btcli --issue 144 --bounty 100
         $ gitt i reg --repo tensorflow/tensorflow --issue 12345 --bounty 50
         $ gitt i reg --repo owner/repo --issue 1 --bounty 10 -y
+        $ gitt i reg --repo owner/repo --issue 2 --bounty 10 --difficulty 3 --label bug
     [/dim]
     """
     err_console.print('\n[bold cyan]Register Issue for Bounty[/bold cyan]\n')
@@ -161,6 +180,8 @@ def issue_register(
             f'[cyan]Issue Number:[/cyan] #{issue_number}\n'
             f'[cyan]GitHub URL:[/cyan] {github_url}\n'
             f'[cyan]Target Bounty:[/cyan] {format_alpha(bounty_amount, 2)} ALPHA\n'
+            f'[cyan]Difficulty:[/cyan] {difficulty if difficulty is not None else "-"}\n'
+            f'[cyan]Labels:[/cyan] {", ".join(labels) if labels else "-"}\n'
             f'[cyan]Network:[/cyan] {network_name}\n'
             f'[cyan]RPC Endpoint:[/cyan] {ws_endpoint}\n'
             f'[cyan]Contract:[/cyan] {contract_addr}',
@@ -180,6 +201,7 @@ def issue_register(
 
         from gittensor.validator.issue_competitions.contract_client import (
             IssueCompetitionContractClient,
+            compute_label_hash,
         )
 
         with err_console.status('[bold cyan]Connecting to network...', spinner='dots'):
@@ -206,6 +228,11 @@ def issue_register(
             subtensor=subtensor,
         )
 
+        existing_id = client.get_issue_id_by_url(github_url)
+        if existing_id:
+            print_error(f'Issue already registered as #{existing_id}')
+            raise SystemExit(1)
+
         err_console.print('[dim]Submitting transaction...[/dim]')
 
         tx_hash, error_msg = client.register_issue(
@@ -214,6 +241,8 @@ def issue_register(
             issue_number=issue_number,
             target_bounty=bounty_amount,
             keypair=keypair,
+            difficulty=difficulty,
+            label_hash=compute_label_hash(list(labels)) if labels else None,
         )
 
         if error_msg is None:
@@ -20,6 +20,7 @@ from .helpers import (
     print_network_header,
     print_warning,
     resolve_network,
+    resolve_registry_name,
     with_cli_behavior_options,
     with_network_contract_options,
 )
@@ -60,6 +61,10 @@ def issues_submissions(
 
     if not contract_addr:
         handle_exception(as_json, 'Contract address not configured.', 'config_error')
+    try:
+        contract_addr = resolve_registry_name(contract_addr, ws_endpoint)
+    except click.ClickException as e:
+        handle_exception(as_json, str(e), 'config_error')
 
     print_network_header(network_name, contract_addr)
 
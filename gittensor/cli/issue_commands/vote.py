@@ -155,12 +155,20 @@ def val_vote_solution(
 @vote.command('cancel')
 @click.argument('issue_id', type=CONTRACT_ISSUE)
 @click.argument('reason', type=str)
+@click.option(
+    '--destination',
+    type=click.Choice(['recycle', 'pool'], case_sensitive=False),
+    default='recycle',
+    show_default=True,
+    help='Where the cancelled bounty goes (must match the first cancel vote)',
+)
 @with_wallet_options()
 @with_network_contract_options('Contract address (uses config if empty)')
 @with_cli_behavior_options(include_yes=True)
 def val_vote_cancel_issue(
     issue_id: int,
     reason: str,
+    destination: str,
     wallet_name: str,
     wallet_hotkey: str,
     network: str,
@@ -177,7 +185,7 @@ def val_vote_cancel_issue(
 
     [dim]Examples:
         $ gitt vote cancel 1 "External solution found"
-        $ gitt vote cancel 42 "Issue invalid"
+        $ gitt vote cancel 42 "Issue invalid" --destination pool
     [/dim]
     """
     contract_addr, ws_endpoint, network_name = _resolve_contract_and_network(contract, network, rpc_url)
@@ -186,7 +194,8 @@ def val_vote_cancel_issue(
 
     err_console.print(
         Panel(
-            f'[cyan]Issue ID:[/cyan] {issue_id}\n[cyan]Reason:[/cyan] {reason}',
+            f'[cyan]Issue ID:[/cyan] {issue_id}\n[cyan]Reason:[/cyan] {reason}\n'
+            f'[cyan]Destination:[/cyan] {destination.lower()}',
             title='Vote Cancel Issue',
             border_style='yellow',
         )
@@ -196,9 +205,11 @@ def val_vote_cancel_issue(
         return
 
     try:
+        from gittensor.validator.issue_competitions.contract_client import CancelDestination
+
         with err_console.status('[bold cyan]Submitting cancel vote...', spinner='dots'):
             wallet, client = _make_contract_client(contract_addr, ws_endpoint, wallet_name, wallet_hotkey)
-            result = client.vote_cancel_issue(issue_id, reason, wallet)
+            result = client.vote_cancel_issue(issue_id, reason, wallet, CancelDestination[destination.upper()])
 
         if result:
             print_success('Cancel vote submitted!')
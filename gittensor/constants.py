@@ -181,6 +181,11 @@ MAX_OPEN_PR_THRESHOLD = 30  # Maximum open PR threshold (base + bonus capped at
 # Issues Competition
 # =============================================================================
 CONTRACT_ADDRESS = '5FWNdk8YNtNcHKrAx2krqenFrFAZG7vmsd2XN2isJSew3MrD'
+# Contract registry mapping deployment names to addresses; empty until deployed,
+# in which case CONTRACT_ADDRESS above is used directly
+REGISTRY_ADDRESS = ''
+ISSUES_CONTRACT_NAME = 'issues-v0'  # registry name of the issues contract
 ISSUES_TREASURY_UID = 111  # UID of the smart contract neuron, if set to RECYCLE_UID then it's disabled
 ISSUES_TREASURY_EMISSION_SHARE = 0.10  # % of emissions allocated to funding issues treasury
 MAX_ISSUE_ID = 1_000_000  # sanity-check upper bound for any real deployment
+VALIDATOR_HEARTBEAT_INTERVAL_BLOCKS = 7_200  # ~1 day; well inside the contract's 50,400-block inactivity window
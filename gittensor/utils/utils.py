@@ -3,18 +3,45 @@ GitTensor Utilities
 """
 
 import os
+import re
 
-from gittensor.constants import CONTRACT_ADDRESS
+from gittensor.constants import CONTRACT_ADDRESS, ISSUES_CONTRACT_NAME
 
 
 def backoff_seconds(attempt: int, base: int = 5, cap: int = 30) -> int:
     return min(base * (2**attempt), cap)
 
 
-def get_contract_address() -> str:
+def is_registry_name(value: str) -> bool:
+    """Whether `value` is a contract registry name rather than an SS58 address.
+
+    Matches the registry contract's name rules exactly: 1-64 lowercase ASCII
+    letters, digits, '-' and '.'. SS58 addresses contain uppercase letters,
+    so they never match.
+    """
+    return bool(re.fullmatch(r'[a-z0-9.-]{1,64}', value))
+
+
+def get_contract_address(substrate=None) -> str:
     """Get contract address. Override via CONTRACT_ADDRESS env var for dev/testing.
 
+    Priority:
+        1. CONTRACT_ADDRESS env var (an SS58 address, or a registry name such as "issues-v0")
+        2. Address registered under ISSUES_CONTRACT_NAME in the contract registry
+        3. constants.py default
+
+    Registry lookups need `substrate`; without it, or when no registry is
+    configured or the lookup fails, the constants.py default is used.
+
     Returns:
-        Contract address string (env var override or constants.py default)
+        Contract address string
     """
-    return os.environ.get('CONTRACT_ADDRESS') or CONTRACT_ADDRESS
+    value = os.environ.get('CONTRACT_ADDRESS') or ''
+    if value and not is_registry_name(value):
+        return value
+    if substrate is None:
+        return CONTRACT_ADDRESS
+
+    from gittensor.validator.issue_competitions.registry_client import resolve_contract_name
+
+    return resolve_contract_name(substrate, value or ISSUES_CONTRACT_NAME) or CONTRACT_ADDRESS
@@ -14,6 +14,7 @@ from typing import Dict, List, Optional, Tuple
 import bittensor as bt
 from async_substrate_interface.errors import ExtrinsicNotFound
 from bittensor_wallet import Keypair
+from Crypto.Hash import keccak
 
 from gittensor.constants import MAX_ISSUE_ID
 from gittensor.validator.issue_competitions.storage_utils import (
@@ -65,6 +66,85 @@ def _scale_compact_length(n: int) -> bytes:
     raise ValueError(f'Length too large for compact encoding: {n}')
 
 
+def dry_run_contract_call(substrate, contract_address: str, input_data: bytes) -> Optional[bytes]:
+    """Dry-run a contract message via the ContractsApi_call runtime API.
+
+    Returns the ink! return payload (after stripping the ContractExecResult
+    envelope, ExecReturnValue flags, data Vec wrapper, and ink! Result
+    discriminant).  Returns None on any error or revert.
+    """
+    try:
+        caller = Keypair.create_from_uri('//Alice')
+
+        origin = bytes.fromhex(substrate.ss58_decode(caller.ss58_address))
+        dest = bytes.fromhex(substrate.ss58_decode(contract_address))
+        # Subtensor chain Balance is u64, not u128
+        value = b'\x00' * 8
+        gas_limit = b'\x00'
+        storage_limit = b'\x00'
+
+        compact_len = _scale_compact_length(len(input_data))
+
+        call_params = origin + dest + value + gas_limit + storage_limit + compact_len + input_data
+
+        result = substrate.rpc_request('state_call', ['ContractsApi_call', '0x' + call_params.hex()])
+
+        if not result.get('result'):
+            return None
+
+        raw = bytes.fromhex(result['result'].replace('0x', ''))
+
+        if len(raw) < 32:
+            return None
+
+        # Parse ContractExecResult (after 16-byte gas prefix):
+        #   StorageDeposit: 1 byte enum + 8 bytes u64 = 9
+        #   debug_message:  1 byte (compact 0 = empty)
+        #   Result:         1 byte (0x00 = Ok)
+        #   flags:          4 bytes u32 (0 = success, 1 = REVERT)
+        #   data:           compact len + bytes
+        #   ink! data[0]:   Result discriminant (0x00 = Ok)
+        #   ink! data[1:]:  SCALE-encoded return value
+        r = raw[16:]
+
+        # Check Result discriminant at offset 10
+        if len(r) < 15 or r[10] != 0x00:
+            return None
+
+        # Check REVERT flag at offset 11-14
+        flags = struct.unpack_from('<I', r, 11)[0]
+        if flags & 1:
+            return None
+
+        # Read data Vec<u8> compact length at offset 15
+        data_compact = r[15]
+        data_mode = data_compact & 0x03
+        if data_mode == 0:
+            data_len = data_compact >> 2
+            data_start = 16
+        elif data_mode == 1:
+            if len(r) < 17:
+                return None
+            data_len = (r[15] | (r[16] << 8)) >> 2
+            data_start = 17
+        else:
+            return None
+
+        if len(r) < data_start + data_len or data_len < 1:
+            return None
+
+        # First byte of data is ink! Result discriminant (0x00 = Ok)
+        if r[data_start] != 0x00:
+            return None
+
+        # Return the actual SCALE-encoded return value
+        return r[data_start + 1 : data_start + data_len]
+
+    except Exception as e:
+        bt.logging.debug(f'Raw contract read failed: {e}')
+        return None
+
+
 class IssueStatus(Enum):
     """Status of an issue in its lifecycle"""
 
@@ -74,6 +154,71 @@ class IssueStatus(Enum):
     CANCELLED = 3
 
 
+def compute_label_hash(labels: List[str]) -> bytes:
+    """Hash an issue's label set for register_issue.
+
+    Labels are lowercased, de-duplicated and sorted before hashing so the
+    same set always produces the same hash regardless of order.
+    """
+    canonical = '\n'.join(sorted({label.strip().lower() for label in labels if label.strip()}))
+    return hashlib.sha256(canonical.encode('utf-8')).digest()
+
+
+def canonical_issue_url(url: str) -> str:
+    """Canonical form of an issue URL, matching the contract's canonicalize_issue_url.
+
+    Scheme, host, owner and repo are lowercased; the query string, fragment
+    and trailing slashes are dropped. The rest of the path keeps its case.
+    """
+    end = min((i for i in (url.find('?'), url.find('#')) if i >= 0), default=len(url))
+    url = url[:end].rstrip('/')
+
+    # Lowercase up to the separator after the repo segment:
+    # "scheme://host/owner/repo" | "/issues/N"
+    scheme_end = url.find('://')
+    path_start = scheme_end + 3 if scheme_end >= 0 else 0
+    slashes = [i for i in range(path_start, len(url)) if url[i] == '/']
+    case_insensitive_end = slashes[2] if len(slashes) > 2 else len(url)
+    return url[:case_insensitive_end].lower() + url[case_insensitive_end:]
+
+
+def hash_string(value: str) -> bytes:
+    """Keccak-256 of a UTF-8 string, matching the contract's hash_string."""
+    return keccak.new(data=value.encode('utf-8'), digest_bits=256).digest()
+
+
+def compute_url_hash(url: str) -> bytes:
+    """Keccak-256 of the canonical issue URL, as the contract deduplicates issues."""
+    return hash_string(canonical_issue_url(url))
+
+
+class CancelDestination(Enum):
+    """Where the bounty of a cancelled issue goes"""
+
+    RECYCLE = 0
+    POOL = 1
+
+
+class ConfigChangeKind(Enum):
+    """Variant of the contract's ScheduledChange accepted by schedule_config_change"""
+
+    OWNER = 0
+    TREASURY_HOTKEY = 1
+    NETUID = 2
+    CONFIG_TIMELOCK_BLOCKS = 3
+    OWNER_RECOVERY_TIMELOCK_BLOCKS = 4
+
+
+# SCALE type of the payload carried by each ScheduledChange variant
+_CONFIG_CHANGE_PAYLOAD_TYPES = {
+    ConfigChangeKind.OWNER: 'AccountId',
+    ConfigChangeKind.TREASURY_HOTKEY: 'AccountId',
+    ConfigChangeKind.NETUID: 'u16',
+    ConfigChangeKind.CONFIG_TIMELOCK_BLOCKS: 'u32',
+    ConfigChangeKind.OWNER_RECOVERY_TIMELOCK_BLOCKS: 'u32',
+}
+
+
 @dataclass
 class ContractIssue:
     """Issue data from the smart contract."""
@@ -230,6 +375,17 @@ class IssueCompetitionContractClient:
             bt.logging.error(f'Error fetching issue {issue_id}: {e}')
             return None
 
+    def get_issue_id_by_url(self, github_url: str) -> int:
+        """Get the id of the issue registered under `github_url`, or 0 if none.
+
+        The URL is canonicalized first, so case, query string, fragment and
+        trailing-slash variants of a registered URL all find it.
+        """
+        response = self._raw_contract_read('get_issue_by_url_hash', {'url_hash': compute_url_hash(github_url)})
+        if not response or len(response) < 8:
+            return 0
+        return struct.unpack_from('<Q', response, 0)[0]
+
     def get_alpha_pool(self) -> int:
         """Get the current alpha pool balance."""
         try:
@@ -260,90 +416,18 @@ class IssueCompetitionContractClient:
     def _raw_contract_read(self, method_name: str, args: dict = None) -> Optional[bytes]:  # type: ignore[assignment]
         """Read from contract using raw RPC call.
 
-        Returns the ink! return payload (after stripping the ContractExecResult
-        envelope, ExecReturnValue flags, data Vec wrapper, and ink! Result
-        discriminant).  Returns None on any error or revert.
+        Returns the ink! return payload (see dry_run_contract_call), or None
+        on any error or revert.
         """
+        selector = CONTRACT_SELECTORS.get(method_name)
+        if not selector:
+            return None
         try:
-            selector = CONTRACT_SELECTORS.get(method_name)
-            if not selector:
-                return None
-
-            input_data = selector
-
-            caller = Keypair.create_from_uri('//Alice')
-
-            origin = bytes.fromhex(self.subtensor.substrate.ss58_decode(caller.ss58_address))
-            dest = bytes.fromhex(self.subtensor.substrate.ss58_decode(self.contract_address))
-            # Subtensor chain Balance is u64, not u128
-            value = b'\x00' * 8
-            gas_limit = b'\x00'
-            storage_limit = b'\x00'
-
-            data_len = len(input_data)
-            if data_len < 64:
-                compact_len = bytes([data_len << 2])
-            else:
-                compact_len = bytes([(data_len << 2) | 1, data_len >> 6])
-
-            call_params = origin + dest + value + gas_limit + storage_limit + compact_len + input_data
-
-            result = self.subtensor.substrate.rpc_request('state_call', ['ContractsApi_call', '0x' + call_params.hex()])
-
-            if not result.get('result'):
-                return None
-
-            raw = bytes.fromhex(result['result'].replace('0x', ''))
-
-            if len(raw) < 32:
-                return None
-
-            # Parse ContractExecResult (after 16-byte gas prefix):
-            #   StorageDeposit: 1 byte enum + 8 bytes u64 = 9
-            #   debug_message:  1 byte (compact 0 = empty)
-            #   Result:         1 byte (0x00 = Ok)
-            #   flags:          4 bytes u32 (0 = success, 1 = REVERT)
-            #   data:           compact len + bytes
-            #   ink! data[0]:   Result discriminant (0x00 = Ok)
-            #   ink! data[1:]:  SCALE-encoded return value
-            r = raw[16:]
-
-            # Check Result discriminant at offset 10
-            if len(r) < 15 or r[10] != 0x00:
-                return None
-
-            # Check REVERT flag at offset 11-14
-            flags = struct.unpack_from('<I', r, 11)[0]
-            if flags & 1:
-                return None
-
-            # Read data Vec<u8> compact length at offset 15
-            data_compact = r[15]
-            data_mode = data_compact & 0x03
-            if data_mode == 0:
-                data_len = data_compact >> 2
-                data_start = 16
-            elif data_mode == 1:
-                if len(r) < 17:
-                    return None
-                data_len = (r[15] | (r[16] << 8)) >> 2
-                data_start = 17
-            else:
-                return None
-
-            if len(r) < data_start + data_len or data_len < 1:
-                return None
-
-            # First byte of data is ink! Result discriminant (0x00 = Ok)
-            if r[data_start] != 0x00:
-                return None
-
-            # Return the actual SCALE-encoded return value
-            return r[data_start + 1 : data_start + data_len]
-
+            input_data = selector + self._encode_args(method_name, args or {})
         except Exception as e:
             bt.logging.debug(f'Raw contract read failed: {e}')
             return None
+        return dry_run_contract_call(self.subtensor.substrate, self.contract_address, input_data)
 
     def _extract_u32_from_response(self, response_bytes: bytes) -> Optional[int]:
         """Extract u32 value from SCALE-encoded return bytes."""
@@ -402,6 +486,8 @@ class IssueCompetitionContractClient:
         issue_number: int,
         target_bounty: int,
         keypair,
+        difficulty: Optional[int] = None,
+        label_hash: Optional[bytes] = None,
     ) -> Tuple[Optional[str], Optional[str]]:
         """
         Register a new issue with a target bounty (OWNER ONLY).
@@ -412,6 +498,8 @@ class IssueCompetitionContractClient:
             issue_number: GitHub issue number
             target_bounty: Target bounty in raw alpha (u128)
             keypair: Coldkey of the contract owner
+            difficulty: Optional difficulty rating (0-255) used to weight payouts
+            label_hash: Optional 32-byte hash of the issue's label set (see compute_label_hash)
 
         Returns:
             (hash, error). Revert: both set. Pre-submission failure: hash is None.
@@ -423,6 +511,8 @@ class IssueCompetitionContractClient:
                 'repository_full_name': repository_full_name,
                 'issue_number': issue_number,
                 'target_bounty': target_bounty,
+                'difficulty': difficulty,
+                'label_hash': label_hash,
             },
             keypair=keypair,
             gas_limit={'ref_time': 10_000_000_000, 'proof_size': 1_000_000},
@@ -470,24 +560,35 @@ class IssueCompetitionContractClient:
         issue_id: int,
         reason: str,
         wallet: bt.Wallet,
+        destination: CancelDestination = CancelDestination.RECYCLE,
     ) -> bool:
         """
         Vote to cancel an issue.
 
+        The first vote fixes the reason hash and destination; the contract
+        rejects later votes that disagree, so validators must derive both
+        deterministically from the issue state.
+
         Args:
             issue_id: Issue to cancel
-            reason: Reason for cancellation
+            reason: Reason for cancellation (hashed and recorded on-chain)
             wallet: Validator wallet for signing
+            destination: Where the cancelled bounty goes (recycled or returned to the pool)
 
         Returns:
             True if vote succeeded
         """
-        reason_hash = hashlib.sha256(reason.encode()).digest()
+        reason_hash = hash_string(reason)
         return self._exec_tx_bool(
             method_name='vote_cancel_issue',
-            args={'issue_id': issue_id, 'reason_hash': reason_hash},
+            args={
+                'issue_id': issue_id,
+                'reason_hash': reason_hash,
+                'destination': destination,
+                'reason': reason,
+            },
             keypair=wallet.hotkey,
-            label=f'Voting cancel for issue {issue_id}: {reason}',
+            label=f'Voting cancel for issue {issue_id} ({destination.name.lower()}): {reason}',
         )
 
     # =========================================================================
@@ -576,42 +677,59 @@ class IssueCompetitionContractClient:
             if arg_name not in args:
                 raise ValueError(f'Missing argument: {arg_name}')
 
-            value = args[arg_name]
-
-            if type_def == 'u32':
-                encoded += struct.pack('<I', value)
-            elif type_def == 'u64':
-                encoded += struct.pack('<Q', value)
-            elif type_def == 'u128':
-                encoded += struct.pack('<QQ', value & 0xFFFFFFFFFFFFFFFF, value >> 64)
-            elif type_def == 'str':
-                if not isinstance(value, str):
-                    raise ValueError(f'Expected str for {arg_name}, got {type(value).__name__}')
-                data = value.encode('utf-8')
-                encoded += _scale_compact_length(len(data)) + data
-            elif type_def == 'AccountId':
-                if isinstance(value, str):
-                    encoded += bytes.fromhex(self.subtensor.substrate.ss58_decode(value))
-                elif isinstance(value, (list, bytes)):
-                    encoded += bytes(value) if isinstance(value, list) else value
-                else:
-                    raise ValueError(f'Unknown AccountId format: {type(value)}')
-            elif type_def == 'array32':
-                if isinstance(value, bytes):
-                    if len(value) != 32:
-                        raise ValueError('Array must be 32 bytes')
-                    encoded += value
-                elif isinstance(value, list):
-                    if len(value) != 32:
-                        raise ValueError('Array must be 32 bytes')
-                    encoded += bytes(value)
-                else:
-                    raise ValueError(f'Unknown array format: {type(value)}')
-            else:
-                raise ValueError(f'Unsupported type: {type_def} for arg {arg_name}')
+            encoded += self._encode_value(arg_name, type_def, args[arg_name])
 
         return encoded
 
+    def _encode_value(self, arg_name: str, type_def: str, value) -> bytes:
+        """SCALE-encode a single argument value of the given type."""
+        if type_def.startswith('Option<') and type_def.endswith('>'):
+            if value is None:
+                return b'\x00'
+            return b'\x01' + self._encode_value(arg_name, type_def[len('Option<') : -1], value)
+
+        if type_def == 'u8':
+            return struct.pack('<B', value)
+        if type_def == 'u16':
+            return struct.pack('<H', value)
+        if type_def == 'u32':
+            return struct.pack('<I', value)
+        if type_def == 'u64':
+            return struct.pack('<Q', value)
+        if type_def == 'u128':
+            return struct.pack('<QQ', value & 0xFFFFFFFFFFFFFFFF, value >> 64)
+        if type_def == 'enum':
+            # Field-less enums encode as their variant index
+            return struct.pack('<B', value.value if isinstance(value, Enum) else value)
+        if type_def == 'ScheduledChange':
+            kind, payload = value
+            return struct.pack('<B', kind.value) + self._encode_value(
+                arg_name, _CONFIG_CHANGE_PAYLOAD_TYPES[kind], payload
+            )
+        if type_def == 'str':
+            if not isinstance(value, str):
+                raise ValueError(f'Expected str for {arg_name}, got {type(value).__name__}')
+            data = value.encode('utf-8')
+            return _scale_compact_length(len(data)) + data
+        if type_def == 'AccountId':
+            if isinstance(value, str):
+                return bytes.fromhex(self.subtensor.substrate.ss58_decode(value))
+            if isinstance(value, (list, bytes)):
+                return bytes(value) if isinstance(value, list) else value
+            raise ValueError(f'Unknown AccountId format: {type(value)}')
+        if type_def == 'array32':
+            if isinstance(value, bytes):
+                if len(value) != 32:
+                    raise ValueError('Array must be 32 bytes')
+                return value
+            if isinstance(value, list):
+                if len(value) != 32:
+                    raise ValueError('Array must be 32 bytes')
+                return bytes(value)
+            raise ValueError(f'Unknown array format: {type(value)}')
+
+        raise ValueError(f'Unsupported type: {type_def} for arg {arg_name}')
+
     # =========================================================================
     # Emission Harvesting Functions
     # =========================================================================
@@ -678,6 +796,31 @@ class IssueCompetitionContractClient:
             bt.logging.error(f'Error fetching last harvest block: {e}')
             return 0
 
+    def get_validator_last_seen(self, hotkey: str) -> Optional[int]:
+        """Query the last block a validator voted or sent a heartbeat.
+
+        Returns:
+            Block number, or None if the hotkey is not whitelisted or the read fails.
+        """
+        response = self._raw_contract_read('get_validator_last_seen', {'hotkey': hotkey})
+        # Option<u32>: 0x00 = None, 0x01 followed by 4 bytes = Some
+        if not response or response[0] != 0x01 or len(response) < 5:
+            return None
+        return struct.unpack_from('<I', response, 1)[0]
+
+    def heartbeat(self, wallet: bt.Wallet) -> bool:
+        """Signal that this validator is still active.
+
+        Validators silent for longer than the contract's inactivity window
+        are left out of the consensus quorum until they vote or heartbeat.
+        """
+        return self._exec_tx_bool(
+            method_name='heartbeat',
+            args={},
+            keypair=wallet.hotkey,
+            label='Sending validator heartbeat',
+        )
+
     def harvest_emissions(self, wallet: bt.Wallet) -> Optional[dict]:
         """Harvest emissions from the treasury hotkey and distribute to bounties."""
         try:
@@ -742,46 +885,74 @@ class IssueCompetitionContractClient:
         self,
         issue_id: int,
         wallet: bt.Wallet,
+        reason: Optional[str] = None,
     ) -> bool:
         """Cancel an issue (owner only).
 
         Args:
             issue_id: The ID of the issue to cancel
             wallet: Owner wallet for signing (uses coldkey)
+            reason: Optional cancellation reason recorded on-chain
 
         Returns:
             True if cancellation succeeded
         """
         return self._exec_tx_bool(
             method_name='cancel_issue',
-            args={'issue_id': issue_id},
+            args={'issue_id': issue_id, 'reason': reason},
+            keypair=wallet.coldkey,
+            label=f'Cancelling issue {issue_id}' + (f': {reason}' if reason else ''),
+            gas_limit=DEFAULT_GAS_LIMIT,
+        )
+
+    def schedule_config_change(
+        self,
+        kind: ConfigChangeKind,
+        value,
+        wallet: bt.Wallet,
+    ) -> bool:
+        """Schedule a timelocked config change (owner only).
+
+        Owner and treasury hotkey changes only take effect once
+        execute_config_change is called after the contract's config timelock;
+        until then validators can cancel the change by consensus.
+
+        Args:
+            kind: Which setting to change
+            value: New value (SS58 address for OWNER/TREASURY_HOTKEY, int otherwise)
+            wallet: Owner wallet for signing (uses coldkey)
+
+        Returns:
+            True if the change was scheduled
+        """
+        return self._exec_tx_bool(
+            method_name='schedule_config_change',
+            args={'change': (kind, value)},
             keypair=wallet.coldkey,
-            label=f'Cancelling issue {issue_id}',
+            label=f'Scheduling {kind.name.lower()} change to {value}',
             gas_limit=DEFAULT_GAS_LIMIT,
         )
 
-    def set_owner(
+    def execute_config_change(
         self,
-        new_owner: str,
         wallet: bt.Wallet,
     ) -> bool:
-        """Transfer contract ownership (owner only).
+        """Apply the scheduled config change once its timelock has passed.
 
-        WARNING: This operation is irreversible. The current owner will
-        lose all admin privileges.
+        Permissionless on the contract; signed with the wallet's coldkey here
+        since the admin CLI drives it.
 
         Args:
-            new_owner: SS58 address of the new owner
-            wallet: Current owner wallet for signing (uses coldkey)
+            wallet: Wallet for signing (uses coldkey)
 
         Returns:
-            True if ownership transfer succeeded
+            True if the change was applied
         """
         return self._exec_tx_bool(
-            method_name='set_owner',
-            args={'new_owner': new_owner},
+            method_name='execute_config_change',
+            args={},
             keypair=wallet.coldkey,
-            label=f'Transferring ownership to {new_owner}',
+            label='Executing scheduled config change',
             gas_limit=DEFAULT_GAS_LIMIT,
         )
 
@@ -888,25 +1059,3 @@ class IssueCompetitionContractClient:
             offset += 32
 
         return validators
-
-    def set_treasury_hotkey(
-        self,
-        new_hotkey: str,
-        wallet: bt.Wallet,
-    ) -> bool:
-        """Change the treasury hotkey (owner only).
-
-        Args:
-            new_hotkey: SS58 address of the new treasury hotkey
-            wallet: Owner wallet for signing (uses coldkey)
-
-        Returns:
-            True if treasury hotkey change succeeded
-        """
-        return self._exec_tx_bool(
-            method_name='set_treasury_hotkey',
-            args={'new_hotkey': new_hotkey},
-            keypair=wallet.coldkey,
-            label=f'Setting treasury hotkey to {new_hotkey}',
-            gas_limit=DEFAULT_GAS_LIMIT,
-        )
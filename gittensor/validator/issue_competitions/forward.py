@@ -8,6 +8,7 @@ from typing import TYPE_CHECKING, Dict
 import bittensor as bt
 
 from gittensor.classes import MinerEvaluation
+from gittensor.constants import VALIDATOR_HEARTBEAT_INTERVAL_BLOCKS
 from gittensor.utils.github_api_tools import check_github_issue_closed
 from gittensor.utils.utils import get_contract_address
 from gittensor.validator.issue_competitions.contract_client import IssueCompetitionContractClient, IssueStatus
@@ -18,6 +19,23 @@ if TYPE_CHECKING:
     from neurons.base.validator import BaseValidatorNeuron
 
 
+def _heartbeat_if_stale(self: 'BaseValidatorNeuron', contract_client: IssueCompetitionContractClient) -> None:
+    """Heartbeat when this validator has not been seen by the contract for a while.
+
+    Validators silent past the contract's inactivity window drop out of the
+    consensus quorum. Votes count as activity, so this only sends a
+    transaction when there was nothing to vote on for a day.
+    """
+    last_seen = contract_client.get_validator_last_seen(self.wallet.hotkey.ss58_address)
+    if last_seen is None:
+        # Not whitelisted (or the read failed): a heartbeat would be rejected
+        return
+    if self.block - last_seen < VALIDATOR_HEARTBEAT_INTERVAL_BLOCKS:
+        return
+    if contract_client.heartbeat(self.wallet):
+        bt.logging.info(f'Sent validator heartbeat (last seen at block {last_seen})')
+
+
 async def issue_competitions(
     self: 'BaseValidatorNeuron',
     miner_evaluations: Dict[int, MinerEvaluation],
@@ -42,7 +60,7 @@ async def issue_competitions(
             )
             return
 
-        contract_addr = get_contract_address()
+        contract_addr = get_contract_address(self.subtensor.substrate)
         if not contract_addr:
             bt.logging.warning('Issue bounties: no contract address configured')
             return
@@ -61,6 +79,8 @@ async def issue_competitions(
         if harvest_result and harvest_result.get('status') == 'success':
             bt.logging.success(f'Harvested emissions! Extrinsic: {harvest_result.get("tx_hash", "")}')
 
+        _heartbeat_if_stale(self, contract_client)
+
         # Build mapping of github_id->hotkey for every non-failed registered
         # miner. Bounty payouts are not normal eligibility-gated, but failed
         # evaluations are not valid payout targets. Duplicate GitHub IDs are
@@ -0,0 +1,47 @@
+# The MIT License (MIT)
+# Copyright 2025 Entrius
+
+"""Resolve deployment names (e.g. "issues-v0") through the contract registry"""
+
+import os
+from typing import Optional
+
+import bittensor as bt
+
+from gittensor.constants import REGISTRY_ADDRESS
+from gittensor.validator.issue_competitions.contract_client import _scale_compact_length, dry_run_contract_call
+
+# Selector of the registry's resolve(name) -> Option<AccountId> message
+RESOLVE_SELECTOR = bytes.fromhex('9e30d65d')
+
+
+def get_registry_address() -> str:
+    """Get the registry address. Override via REGISTRY_ADDRESS env var for dev/testing."""
+    return os.environ.get('REGISTRY_ADDRESS') or REGISTRY_ADDRESS
+
+
+def resolve_contract_name(substrate, name: str, registry_address: Optional[str] = None) -> Optional[str]:
+    """Look up the address registered under `name`.
+
+    Args:
+        substrate: Connected substrate interface.
+        name: Registry name, e.g. "issues-v0".
+        registry_address: Registry SS58 address (defaults to get_registry_address()).
+
+    Returns:
+        SS58 address, or None if no registry is configured, the name is not
+        registered, or the lookup fails.
+    """
+    registry_address = registry_address or get_registry_address()
+    if not registry_address:
+        return None
+
+    data = name.encode('utf-8')
+    input_data = RESOLVE_SELECTOR + _scale_compact_length(len(data)) + data
+    response = dry_run_contract_call(substrate, registry_address, input_data)
+
+    # Option<AccountId>: 0x00 = None, 0x01 followed by 32 bytes = Some
+    if not response or response[0] != 0x01 or len(response) < 33:
+        bt.logging.debug(f'Registry has no address for {name}')
+        return None
+    return substrate.ss58_encode(response[1:33].hex())
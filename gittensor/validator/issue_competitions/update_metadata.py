@@ -19,18 +19,21 @@ METHODS_WE_USE = [
     'cancel_issue',
     'vote_solution',
     'vote_cancel_issue',
-    'set_owner',
-    'set_treasury_hotkey',
+    'schedule_config_change',
+    'execute_config_change',
     'get_treasury_stake',
     'get_last_harvest_block',
     'harvest_emissions',
     'payout_bounty',
     'get_alpha_pool',
     'get_issue',
+    'get_issue_by_url_hash',
     'get_issues_by_status',
     'add_validator',
     'remove_validator',
     'get_validators',
+    'get_validator_last_seen',
+    'heartbeat',
 ]
 
 
@@ -50,6 +53,16 @@ def get_type_string(type_id: int, types: list) -> str:
             if 'composite' in type_def:
                 if path and 'AccountId' in path[-1]:
                     return 'AccountId'
+            if 'variant' in type_def:
+                if path == ['Option']:
+                    inner = t['type']['params'][0]['type']
+                    return f'Option<{get_type_string(inner, types)}>'
+                # Field-less enums encode as a single variant-index byte
+                if all(not v.get('fields') for v in type_def['variant'].get('variants', [])):
+                    return 'enum'
+                # Enums carrying data need a dedicated encoder in the client
+                if path:
+                    return path[-1]
     return 'unknown'
 
 
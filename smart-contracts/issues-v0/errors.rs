@@ -10,7 +10,7 @@ pub enum Error {
     IssueNotFound,
     /// Issue with the same URL already exists
     IssueAlreadyExists,
-    /// Bounty amount is below minimum (10 ALPHA)
+    /// Bounty amount is below the configured minimum
     BountyTooLow,
     /// Issue cannot be cancelled in its current state
     CannotCancel,
@@ -46,4 +46,77 @@ pub enum Error {
     ValidatorAlreadyWhitelisted,
     // Validator doesn't exist in whitelist
     ValidatorNotWhitelisted,
+    /// Treasury stake is lower than the requested payout
+    InsufficientTreasuryBalance {
+        available: u128,
+        requested: u128,
+    },
+    /// Amount does not fit into AlphaCurrency (u64)
+    AmountOverflow,
+    /// An owner recovery is already pending
+    OwnerRecoveryPending,
+    /// No owner recovery has been proposed
+    NoOwnerRecovery,
+    /// Owner recovery has not reached the required votes
+    OwnerRecoveryNotApproved,
+    /// Owner recovery timelock has not elapsed yet
+    OwnerRecoveryTimelocked,
+    /// Issue already holds the maximum number of notes
+    TooManyNotes,
+    /// Validator whitelist is full
+    TooManyValidators,
+    /// Configuration value is out of range
+    InvalidConfig,
+    /// Cancellation reason exceeds MAX_CANCEL_REASON_LEN bytes
+    ReasonTooLong,
+    /// Caller is neither the recorded solver nor the owner
+    NotSolver,
+    /// Batch exceeds MAX_BATCH_SIZE items
+    BatchTooLarge,
+    /// Harvesting is paused until the owner acknowledges a stake anomaly
+    HarvestPaused,
+    /// No stake anomaly is pending acknowledgement
+    NoStakeAnomaly,
+    /// Issue is not in Registered status
+    IssueNotRegistered,
+    /// A scoring snapshot hash is already recorded for this issue
+    ScoringSnapshotAlreadySet,
+    /// Solver hotkey is not registered on the contract's subnet
+    SolverNotRegistered,
+    /// Repository is not on the allow-list
+    RepositoryNotAllowed,
+    /// Caller has no vote recorded on this proposal
+    NotVoted,
+    /// Cancel vote's reason hash or destination differs from the open proposal
+    CancelProposalMismatch,
+    /// Cancel vote's reason hash is not hash_string of its reason text
+    ReasonHashMismatch,
+    /// A timelocked config change is already scheduled
+    ConfigChangePending,
+    /// No timelocked config change is scheduled
+    NoConfigChange,
+    /// Config change timelock has not elapsed yet
+    ConfigChangeTimelocked,
+    /// No scheduled repair exists with this id
+    RepairNotFound,
+    /// Repair timelock has not elapsed yet
+    RepairTimelocked,
+    /// MAX_PENDING_REPAIRS repairs are already scheduled
+    TooManyPendingRepairs,
+    /// An open bounty adjustment proposal already exists for this issue
+    BountyAdjustmentPending,
+    /// No open bounty adjustment proposal exists for this issue
+    NoBountyAdjustment,
+    /// Proposed target bounty equals the current one
+    BountyTargetUnchanged,
+    /// An open registration proposal already exists for this issue URL
+    IssueProposalPending,
+    /// No open registration proposal exists for this issue URL
+    NoIssueProposal,
+    /// Self-service validator onboarding is disabled (min_validator_stake is 0)
+    ValidatorApplicationsClosed,
+    /// Treasury stake on the applicant hotkey is below min_validator_stake
+    InsufficientValidatorStake,
+    /// Validator set and quorum settings are frozen while a config change or repair is pending
+    QuorumLocked,
 }
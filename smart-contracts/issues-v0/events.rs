@@ -1,15 +1,28 @@
 use ink::prelude::string::String;
 use ink::primitives::AccountId;
 
+use crate::types::{
+    CancelDestination, ConfigField, InvariantViolation, RepairAction, ScheduledChange, StakeOpKind,
+    VoteKind,
+};
+
 /// Event emitted when a new issue is registered
 #[ink::event]
 pub struct IssueRegistered {
     #[ink(topic)]
     pub issue_id: u64,
+    #[ink(topic)]
     pub github_url_hash: [u8; 32],
+    /// Keccak-256 of repository_full_name, for filtering by repository
+    #[ink(topic)]
+    pub repository_hash: [u8; 32],
     pub repository_full_name: String,
     pub issue_number: u32,
     pub target_bounty: u128,
+    pub difficulty: Option<u8>,
+    pub label_hash: Option<[u8; 32]>,
+    pub registered_by: AccountId,
+    pub registered_at_block: u32,
 }
 
 /// Event emitted when an issue is cancelled
@@ -17,7 +30,162 @@ pub struct IssueRegistered {
 pub struct IssueCancelled {
     #[ink(topic)]
     pub issue_id: u64,
+    #[ink(topic)]
+    pub repository_hash: [u8; 32],
     pub returned_bounty: u128,
+    /// Where the returned bounty went
+    pub destination: CancelDestination,
+    pub reason_hash: [u8; 32],
+    pub reason: Option<String>,
+}
+
+/// Event emitted when an issue is completed via solution consensus.
+/// Carries the winning PR's repository and number so it can be audited on-chain.
+#[ink::event]
+pub struct IssueCompleted {
+    #[ink(topic)]
+    pub issue_id: u64,
+    #[ink(topic)]
+    pub solver_hotkey: AccountId,
+    #[ink(topic)]
+    pub repository_hash: [u8; 32],
+    pub solver_coldkey: AccountId,
+    pub repository_full_name: String,
+    pub pr_number: u32,
+    pub bounty_amount: u128,
+}
+
+/// Event emitted once an issue completes, summarising its lifecycle so the
+/// history can be studied without replaying every funding and vote event.
+#[ink::event]
+pub struct IssueLifecycleSummary {
+    #[ink(topic)]
+    pub issue_id: u64,
+    #[ink(topic)]
+    pub solver_hotkey: AccountId,
+    pub registered_at_block: u32,
+    pub completed_at_block: u32,
+    /// Blocks from registration to completion (and auto-payout)
+    pub blocks_open: u32,
+    pub target_bounty: u128,
+    /// Bounty allocated to the issue over its lifetime
+    pub total_funding: u128,
+    /// Amount transferred by the auto-payout, 0 if it failed or nothing was funded
+    pub paid_out: u128,
+    /// Contract-wide total paid to solvers after this completion
+    pub total_paid: u128,
+}
+
+/// Event emitted when the owner adds a repository to the allow-list
+#[ink::event]
+pub struct RepositoryAllowed {
+    /// Keccak-256 of the lowercased repository_full_name
+    #[ink(topic)]
+    pub repository_hash: [u8; 32],
+    pub repository_full_name: String,
+}
+
+/// Event emitted when the owner removes a repository from the allow-list
+#[ink::event]
+pub struct RepositoryDisallowed {
+    /// Keccak-256 of the lowercased repository_full_name
+    #[ink(topic)]
+    pub repository_hash: [u8; 32],
+    pub repository_full_name: String,
+}
+
+/// Event emitted when the owner sets or clears an issue's funding deadline
+#[ink::event]
+pub struct FundingDeadlineSet {
+    #[ink(topic)]
+    pub issue_id: u64,
+    pub deadline_block: Option<u32>,
+}
+
+/// Event emitted when the owner records the scoring config used for a completed issue
+#[ink::event]
+pub struct ScoringSnapshotRecorded {
+    #[ink(topic)]
+    pub issue_id: u64,
+    pub snapshot_hash: [u8; 32],
+}
+
+/// Event emitted when the owner flags or unflags an issue as security-critical
+#[ink::event]
+pub struct SecurityCriticalSet {
+    #[ink(topic)]
+    pub issue_id: u64,
+    pub critical: bool,
+}
+
+/// Event emitted when a validator proposes registering an issue
+#[ink::event]
+pub struct IssueProposed {
+    #[ink(topic)]
+    pub github_url_hash: [u8; 32],
+    #[ink(topic)]
+    pub proposer: AccountId,
+    pub repository_full_name: String,
+    pub issue_number: u32,
+    pub target_bounty: u128,
+}
+
+/// Event emitted when a validator proposes a new target bounty for an issue
+#[ink::event]
+pub struct BountyAdjustmentProposed {
+    #[ink(topic)]
+    pub issue_id: u64,
+    #[ink(topic)]
+    pub proposer: AccountId,
+    pub old_target: u128,
+    pub new_target: u128,
+}
+
+/// Event emitted when validator consensus changes an issue's target bounty
+#[ink::event]
+pub struct BountyAdjusted {
+    #[ink(topic)]
+    pub issue_id: u64,
+    pub old_target: u128,
+    pub new_target: u128,
+    /// Bounty above the new target, returned to the alpha pool
+    pub released: u128,
+    /// True if the issue is now fully funded and became Active
+    pub activated: bool,
+}
+
+/// Event emitted when a validator withdraws a vote from an open proposal
+#[ink::event]
+pub struct VoteRevoked {
+    #[ink(topic)]
+    pub issue_id: u64,
+    #[ink(topic)]
+    pub voter: AccountId,
+    pub kind: VoteKind,
+    /// Votes remaining on the proposal; 0 means it was discarded
+    pub votes_count: u32,
+}
+
+/// Event emitted when an open proposal outlives vote_expiry_blocks and is cleared
+#[ink::event]
+pub struct VoteExpired {
+    #[ink(topic)]
+    pub issue_id: u64,
+    pub kind: VoteKind,
+    /// Votes the proposal had when it was cleared
+    pub votes_count: u32,
+}
+
+/// Event emitted when a validator attaches a note to an issue
+#[ink::event]
+pub struct IssueNoteAdded {
+    #[ink(topic)]
+    pub issue_id: u64,
+    #[ink(topic)]
+    pub author: AccountId,
+    pub note_hash: [u8; 32],
+    /// Index of the note within the issue's note list
+    pub index: u32,
 }
 
 /// Event emitted when emissions are harvested
@@ -29,12 +197,33 @@ pub struct EmissionsHarvested {
     pub recycled: u128,
 }
 
-/// Event emitted when a bounty is filled from emissions
+/// Event emitted when a bounty is filled from emissions and the issue becomes
+/// Active. Carries the funding snapshot at that moment.
 #[ink::event]
 pub struct BountyFilled {
     #[ink(topic)]
     pub issue_id: u64,
+    #[ink(topic)]
+    pub repository_hash: [u8; 32],
+    /// Total funded amount (bounty_amount) once filled
     pub amount: u128,
+    /// Target bounty the issue was funded against
+    pub target_bounty: u128,
+}
+
+/// Event emitted when an issue receives funds without reaching its target
+#[ink::event]
+pub struct BountyPartiallyFilled {
+    #[ink(topic)]
+    pub issue_id: u64,
+    #[ink(topic)]
+    pub repository_hash: [u8; 32],
+    /// Amount allocated in this fill
+    pub amount: u128,
+    /// Total bounty allocated so far
+    pub total: u128,
+    /// Target bounty amount
+    pub target: u128,
 }
 
 /// Event emitted when excess emissions are recycled (destroyed via recycle_alpha)
@@ -47,6 +236,13 @@ pub struct EmissionsRecycled {
     pub destination: AccountId,
 }
 
+/// Event emitted when unallocated emissions stay in the pool instead of being
+/// recycled, because of recycle_bps, a pending fill budget or a failed recycle
+#[ink::event]
+pub struct EmissionsRetained {
+    pub amount: u128,
+}
+
 /// Event emitted when a bounty is paid out to a solver
 #[ink::event]
 pub struct BountyPaidOut {
@@ -54,9 +250,36 @@ pub struct BountyPaidOut {
     pub issue_id: u64,
     #[ink(topic)]
     pub miner: AccountId,
+    /// Solver hotkey recorded at completion (the miner identity)
+    #[ink(topic)]
+    pub solver_hotkey: Option<AccountId>,
+    pub amount: u128,
+}
+
+/// Event emitted per allocation when verbose harvest is enabled, so auditors
+/// can reconstruct how each harvest was split across issues
+#[ink::event]
+pub struct HarvestAllocation {
+    #[ink(topic)]
+    pub issue_id: u64,
     pub amount: u128,
 }
 
+/// Event emitted for every proxied stake movement the contract dispatches, so
+/// off-chain reconciliation can match each one to the Subtensor call it made
+#[ink::event]
+pub struct StakeOperation {
+    /// Monotonically increasing id, starting at 1
+    #[ink(topic)]
+    pub op_id: u64,
+    pub kind: StakeOpKind,
+    /// blake2_256 of the SCALE-encoded runtime call
+    #[ink(topic)]
+    pub call_hash: [u8; 32],
+    pub amount: u128,
+    pub success: bool,
+}
+
 /// Event emitted when harvest fails due to recycling error
 #[ink::event]
 pub struct HarvestFailed {
@@ -100,3 +323,122 @@ pub struct ValidatorRemoved {
     #[ink(topic)]
     pub hotkey: AccountId,
 }
+
+/// Event emitted when validators propose replacing the owner
+#[ink::event]
+pub struct OwnerRecoveryProposed {
+    #[ink(topic)]
+    pub new_owner: AccountId,
+    #[ink(topic)]
+    pub proposer: AccountId,
+    /// First block at which the recovery can be executed
+    pub executable_at_block: u32,
+}
+
+/// Event emitted when a validator votes for a pending owner recovery
+#[ink::event]
+pub struct OwnerRecoveryVoted {
+    #[ink(topic)]
+    pub voter: AccountId,
+    pub votes_count: u32,
+    pub required_votes: u32,
+}
+
+/// Event emitted when the current owner cancels a pending recovery
+#[ink::event]
+pub struct OwnerRecoveryCancelled {
+    #[ink(topic)]
+    pub new_owner: AccountId,
+}
+
+/// Event emitted when the owner schedules a timelocked config change
+#[ink::event]
+pub struct ConfigChangeScheduled {
+    pub change: ScheduledChange,
+    /// First block at which the change can be executed
+    pub executable_at_block: u32,
+}
+
+/// Event emitted when a scheduled config change is withdrawn by the owner or
+/// cancelled by validator consensus
+#[ink::event]
+pub struct ConfigChangeCancelled {
+    pub change: ScheduledChange,
+    pub by_validators: bool,
+}
+
+/// Event emitted when a scheduled config change is applied
+#[ink::event]
+pub struct ConfigChangeExecuted {
+    pub change: ScheduledChange,
+}
+
+/// Event emitted when the owner schedules a timelocked state repair
+#[ink::event]
+pub struct RepairScheduled {
+    #[ink(topic)]
+    pub repair_id: u64,
+    pub action: RepairAction,
+    /// First block at which the repair can be executed
+    pub executable_at_block: u32,
+}
+
+/// Event emitted when a scheduled repair is withdrawn by the owner or
+/// cancelled by validator consensus
+#[ink::event]
+pub struct RepairCancelled {
+    #[ink(topic)]
+    pub repair_id: u64,
+    pub action: RepairAction,
+    pub by_validators: bool,
+}
+
+/// Event emitted when a scheduled repair is applied
+#[ink::event]
+pub struct RepairExecuted {
+    #[ink(topic)]
+    pub repair_id: u64,
+    pub action: RepairAction,
+}
+
+/// Event emitted when a scheduled repair fixes an inconsistency reported by audit()
+#[ink::event]
+pub struct StateRepaired {
+    #[ink(topic)]
+    pub issue_id: u64,
+    pub violation: InvariantViolation,
+}
+
+/// Event emitted when an owner recovery is executed
+#[ink::event]
+pub struct OwnerRecovered {
+    #[ink(topic)]
+    pub old_owner: AccountId,
+    #[ink(topic)]
+    pub new_owner: AccountId,
+}
+
+/// Event emitted when the owner changes a configuration value
+#[ink::event]
+pub struct ConfigUpdated {
+    #[ink(topic)]
+    pub field: ConfigField,
+    pub old_value: u128,
+    pub new_value: u128,
+}
+
+/// Event emitted when the treasury stake falls short of commitments by more
+/// than the anomaly threshold. Harvesting pauses until the owner acknowledges.
+#[ink::event]
+pub struct StakeAnomalyDetected {
+    pub current_stake: u128,
+    pub committed: u128,
+    pub shortfall: u128,
+}
+
+/// Event emitted when the owner acknowledges a stake anomaly and resumes harvesting
+#[ink::event]
+pub struct StakeAnomalyAcknowledged {
+    #[ink(topic)]
+    pub owner: AccountId,
+}
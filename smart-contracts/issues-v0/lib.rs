@@ -13,6 +13,12 @@ pub use types::*;
 // Chain Extension for Subtensor Staking Operations
 // ============================================================================
 
+/// Subtensor chain extension id; must match the `extension` attribute below.
+pub const SUBTENSOR_EXTENSION_ID: u16 = 5001;
+
+/// Chain extension function id of get_stake_info; must match its attribute below.
+pub const GET_STAKE_INFO_FUNC_ID: u16 = 0;
+
 /// Subtensor chain extension for staking operations.
 /// These functions allow the contract to interact with the Subtensor runtime
 /// for querying and transferring stake.
@@ -65,7 +71,7 @@ impl ink::env::Environment for CustomEnvironment {
 #[ink::contract(env = crate::CustomEnvironment)]
 mod issue_bounty_manager {
     use crate::events::*;
-    use crate::runtime_calls::RawCall;
+    use crate::runtime_calls::{self, AlphaAmount, RawCall};
     use crate::types::*;
     use crate::Error;
     use ink::prelude::string::String;
@@ -76,9 +82,68 @@ mod issue_bounty_manager {
     // Constants
     // ========================================================================
 
-    /// Minimum bounty amount: 10 ALPHA (9 decimals)
+    /// Default minimum bounty amount: 10 ALPHA (9 decimals)
     pub const MIN_BOUNTY: u128 = 10_000_000_000;
 
+    /// Maximum number of items in a register_issues or vote_solutions_batch call
+    pub const MAX_BATCH_SIZE: usize = 50;
+
+    /// Maximum length in bytes of a human-readable cancellation reason
+    pub const MAX_CANCEL_REASON_LEN: usize = 256;
+
+    /// Default treasury shortfall (10% of commitments) that pauses harvesting
+    pub const STAKE_ANOMALY_THRESHOLD_BPS: u32 = 1_000;
+
+    /// Upper bound on issue ids examined by a single get_issues_by_status call
+    pub const MAX_STATUS_SCAN: u32 = 1_000;
+
+    /// Cancellation reason recorded when an issue misses its funding deadline
+    pub const FUNDING_DEADLINE_REASON: &str = "funding deadline passed";
+
+    /// Default subnet tempo: blocks per epoch over which StakeInfo.emission accrues
+    pub const EMISSION_TEMPO_BLOCKS: u32 = 360;
+
+    /// Largest accepted emission_tempo_blocks; subtensor stores a subnet's tempo as u16
+    pub const MAX_EMISSION_TEMPO_BLOCKS: u32 = u16::MAX as u32;
+
+    /// Default share of each harvest (30%) reserved for security-critical issues
+    pub const SECURITY_RESERVE_BPS: u32 = 3_000;
+
+    /// Default maximum number of bounty queue entries processed per harvest
+    pub const MAX_FILLS_PER_CALL: u32 = 50;
+
+    /// Default maximum number of whitelisted validators
+    pub const MAX_VALIDATORS: u32 = 64;
+
+    /// Default number of blocks (~7 days at 12s) of silence after which
+    /// a validator is excluded from the consensus quorum
+    pub const VALIDATOR_INACTIVITY_BLOCKS: u32 = 50_400;
+
+    /// Default floor on the votes any proposal needs, so a validator returning
+    /// after the rest of the set went quiet cannot decide alone
+    pub const MIN_VOTES_REQUIRED: u32 = 2;
+
+    /// Default number of blocks (~7 days at 12s) an open solution or cancel
+    /// proposal stays valid before it is cleared
+    pub const VOTE_EXPIRY_BLOCKS: u32 = 50_400;
+
+    /// Default blocks (~1 day at 12s) a treasury, netuid or timelock change
+    /// waits between scheduling and execution
+    pub const CONFIG_TIMELOCK_BLOCKS: u32 = 7_200;
+
+    /// Default blocks (~14 days at 12s) between an owner recovery proposal and
+    /// its execution
+    pub const OWNER_RECOVERY_TIMELOCK_BLOCKS: u32 = 100_800;
+
+    /// Maximum number of validator notes per issue
+    pub const MAX_NOTES_PER_ISSUE: u32 = 32;
+
+    /// Number of recent harvests kept for funding estimates and emission rate
+    pub const HARVEST_HISTORY_LEN: usize = 10;
+
+    /// Maximum number of state repairs scheduled at once
+    pub const MAX_PENDING_REPAIRS: usize = 32;
+
     // ========================================================================
     // Contract Storage
     // ========================================================================
@@ -95,27 +160,132 @@ mod issue_bounty_manager {
         next_issue_id: u64,
         /// Unallocated emissions storage (alpha pool)
         alpha_pool: Balance,
+        /// Reason and block for each cancelled issue
+        cancellations: Mapping<u64, CancellationRecord>,
+        /// Hash of the off-chain scoring config used for each completed issue
+        scoring_snapshots: Mapping<u64, [u8; 32]>,
+        /// Cumulative emissions harvested
+        total_harvested: Balance,
+        /// Cumulative alpha allocated to bounties. Never decreases: released
+        /// excess goes back to the pool and is counted again if re-allocated.
+        total_committed: Balance,
+        /// Cumulative alpha paid out to solvers
+        total_paid: Balance,
+        /// Cumulative alpha recycled
+        total_recycled: Balance,
+        /// Minimum target bounty for new issues
+        min_bounty: Balance,
+        /// Maximum number of whitelisted validators
+        max_validators: u32,
+        /// Fixed votes required for consensus, None for simple majority
+        required_votes_override: Option<u32>,
+        /// Floor on the votes any proposal needs, so a shrunken quorum cannot
+        /// let a single validator decide alone
+        min_votes_required: u32,
+        /// Blocks of silence after which a validator leaves the quorum
+        validator_inactivity_blocks: u32,
+        /// Stake an applying coldkey must hold on a hotkey to whitelist it via
+        /// apply_as_validator; 0 disables self-service onboarding
+        min_validator_stake: Balance,
+        /// Treasury shortfall, in basis points of commitments, that pauses harvesting
+        stake_anomaly_threshold_bps: u32,
+        /// Set when a stake anomaly is detected, cleared by the owner
+        harvest_paused: bool,
+        /// Maximum bounty queue entries processed per harvest
+        max_fills_per_call: u32,
+        /// Share of unallocated harvest, in basis points, recycled by each harvest
+        recycle_bps: u32,
+        /// Funds the last harvest left unallocated and did not recycle. Already
+        /// counted as harvested, so excluded from the next harvest's totals.
+        carried_over: Balance,
+        /// Funds returned to the pool since the last harvest by cancellations,
+        /// expired funding deadlines, released excess and failed recycles.
+        /// Already counted as harvested, so excluded from the next harvest's totals.
+        returned_to_pool: Balance,
 
         /// Mapping from issue ID to Issue struct
         issues: Mapping<u64, Issue>,
         /// Mapping from URL hash to issue ID for deduplication
         url_hash_to_id: Mapping<[u8; 32], u64>,
+        /// Allow-listed repositories, keyed by hash of the lowercased name
+        allowed_repositories: Mapping<[u8; 32], bool>,
+        /// Whether register_issue only accepts allow-listed repositories
+        repo_allowlist_enabled: bool,
+        /// Subnet tempo in blocks, used to derive the per-block emission rate
+        emission_tempo_blocks: u32,
+        /// Emit a HarvestAllocation event for every allocation a harvest makes
+        verbose_harvest: bool,
+        /// Id of the last dispatched stake movement (see StakeOperation)
+        stake_op_count: u64,
+        /// Block of the last write to each issue, for sync_state
+        issue_updated_at: Mapping<u64, u32>,
+        /// Blocks after its first vote that a solution or cancel proposal expires
+        vote_expiry_blocks: u32,
         /// FIFO queue of issue IDs awaiting bounty fill
         bounty_queue: Vec<u64>,
+        /// Security-critical issues awaiting fill, funded ahead of bounty_queue
+        /// from the reserved share of each harvest
+        security_queue: Vec<u64>,
+        /// Issues flagged security-critical by the owner
+        security_critical: Mapping<u64, bool>,
+        /// Share of each harvest, in basis points, reserved for security_queue
+        security_reserve_bps: u32,
+        /// Block by which a Registered issue must be fully funded
+        funding_deadlines: Mapping<u64, u32>,
+        /// Issues with a funding deadline, checked on every harvest
+        deadline_queue: Vec<u64>,
 
         validators: Vec<AccountId>,
-
-        // Solution votes (vote on issues directly)
-        solution_votes: Mapping<u64, SolutionVote>,
+        /// Incremented on every whitelist change; proposals recheck their voters
+        /// when it moves
+        whitelist_version: u32,
+        /// Last block each whitelisted validator voted or sent a heartbeat
+        validator_last_seen: Mapping<AccountId, u32>,
+
+        // Solution votes (vote on issues directly); one entry per candidate
+        // (solver hotkey, solver coldkey, PR), at most one per validator
+        solution_votes: Mapping<u64, Vec<SolutionVote>>,
         solution_vote_voters: Mapping<(u64, AccountId), bool>,
 
         // Issue cancel votes (validators can cancel issues at any stage)
         cancel_issue_votes: Mapping<u64, CancelVote>,
         cancel_issue_voters: Mapping<(u64, AccountId), bool>,
 
+        // Validator issue proposals, keyed by canonical URL hash; voters are
+        // checked against the proposal's own list
+        issue_proposals: Mapping<[u8; 32], IssueProposal>,
+
+        // Target bounty adjustments (validators re-price Registered issues)
+        bounty_adjustments: Mapping<u64, BountyAdjustment>,
+        bounty_adjustment_voters: Mapping<(u64, AccountId), bool>,
+
+        // Validator evidence notes per issue, indexed 0..issue_note_count
+        issue_notes: Mapping<(u64, u32), IssueNote>,
+        issue_note_count: Mapping<u64, u32>,
+
+        // Owner recovery (validators can replace a lost owner key)
+        owner_recovery: Option<OwnerRecovery>,
+        /// Blocks between a recovery proposal and its execution, changed only
+        /// through the config timelock
+        owner_recovery_timelock_blocks: u32,
+
+        // Timelocked config changes (validators can cancel before execution)
+        pending_config_change: Option<PendingConfigChange>,
+        config_timelock_blocks: u32,
+
+        // Timelocked state repairs, keyed by repair id so they queue
+        // independently of the single pending config change
+        pending_repairs: Mapping<u64, PendingRepair>,
+        /// Ids of scheduled repairs, bounded by MAX_PENDING_REPAIRS
+        pending_repair_ids: Vec<u64>,
+        /// Id assigned to the next scheduled repair
+        next_repair_id: u64,
+
         // Emission management
         /// Block number of last harvest
         last_harvest_block: u32,
+        /// Most recent harvests (oldest first), bounded by HARVEST_HISTORY_LEN
+        recent_harvests: Vec<HarvestRecord>,
     }
 
     impl IssueBountyManager {
@@ -132,15 +302,60 @@ mod issue_bounty_manager {
                 netuid,
                 next_issue_id: 1,
                 alpha_pool: 0,
+                cancellations: Mapping::default(),
+                scoring_snapshots: Mapping::default(),
+                total_harvested: 0,
+                total_committed: 0,
+                total_paid: 0,
+                total_recycled: 0,
+                min_bounty: MIN_BOUNTY,
+                max_validators: MAX_VALIDATORS,
+                required_votes_override: None,
+                min_votes_required: MIN_VOTES_REQUIRED,
+                min_validator_stake: 0,
+                validator_inactivity_blocks: VALIDATOR_INACTIVITY_BLOCKS,
+                stake_anomaly_threshold_bps: STAKE_ANOMALY_THRESHOLD_BPS,
+                harvest_paused: false,
+                max_fills_per_call: MAX_FILLS_PER_CALL,
+                recycle_bps: 10_000,
+                carried_over: 0,
+                returned_to_pool: 0,
                 issues: Mapping::default(),
                 url_hash_to_id: Mapping::default(),
+                allowed_repositories: Mapping::default(),
+                repo_allowlist_enabled: false,
+                emission_tempo_blocks: EMISSION_TEMPO_BLOCKS,
+                verbose_harvest: false,
+                stake_op_count: 0,
+                issue_updated_at: Mapping::default(),
+                vote_expiry_blocks: VOTE_EXPIRY_BLOCKS,
                 bounty_queue: Vec::new(),
+                security_queue: Vec::new(),
+                security_critical: Mapping::default(),
+                security_reserve_bps: SECURITY_RESERVE_BPS,
+                funding_deadlines: Mapping::default(),
+                deadline_queue: Vec::new(),
                 validators: Vec::new(),
+                whitelist_version: 0,
+                validator_last_seen: Mapping::default(),
                 solution_votes: Mapping::default(),
                 solution_vote_voters: Mapping::default(),
                 cancel_issue_votes: Mapping::default(),
                 cancel_issue_voters: Mapping::default(),
+                issue_proposals: Mapping::default(),
+                bounty_adjustments: Mapping::default(),
+                bounty_adjustment_voters: Mapping::default(),
+                issue_notes: Mapping::default(),
+                issue_note_count: Mapping::default(),
+                owner_recovery: None,
+                owner_recovery_timelock_blocks: OWNER_RECOVERY_TIMELOCK_BLOCKS,
+                pending_config_change: None,
+                config_timelock_blocks: CONFIG_TIMELOCK_BLOCKS,
+                pending_repairs: Mapping::default(),
+                pending_repair_ids: Vec::new(),
+                next_repair_id: 1,
                 last_harvest_block: 0,
+                recent_harvests: Vec::new(),
             }
         }
 
@@ -148,7 +363,9 @@ mod issue_bounty_manager {
         // Issue Registry Functions
         // ========================================================================
 
-        /// Registers a new GitHub issue for bounty
+        /// Registers a new GitHub issue for bounty.
+        ///
+        /// `difficulty` and `label_hash` are optional metadata for the scoring pipeline.
         #[ink(message)]
         pub fn register_issue(
             &mut self,
@@ -156,27 +373,154 @@ mod issue_bounty_manager {
             repository_full_name: String,
             issue_number: u32,
             target_bounty: u128,
+            difficulty: Option<u8>,
+            label_hash: Option<[u8; 32]>,
         ) -> Result<u64, Error> {
             if self.env().caller() != self.owner {
                 return Err(Error::NotOwner);
             }
 
-            if target_bounty < MIN_BOUNTY {
+            let url_hash = self.validate_new_issue(
+                &github_url,
+                &repository_full_name,
+                issue_number,
+                target_bounty,
+            )?;
+            Ok(self.create_issue(
+                url_hash,
+                repository_full_name,
+                issue_number,
+                target_bounty,
+                difficulty,
+                label_hash,
+                self.env().caller(),
+            ))
+        }
+
+        /// Proposes registering an issue (validators only), counting the
+        /// proposer's vote. The issue is registered once vote_issue_proposal
+        /// reaches consensus. Proposals are keyed by the canonical URL hash.
+        #[ink(message)]
+        pub fn propose_issue(
+            &mut self,
+            github_url: String,
+            repository_full_name: String,
+            issue_number: u32,
+            target_bounty: u128,
+        ) -> Result<VoteTally, Error> {
+            let caller = self.validate_whitelisted_caller()?;
+            let url_hash = self.validate_new_issue(
+                &github_url,
+                &repository_full_name,
+                issue_number,
+                target_bounty,
+            )?;
+
+            self.clear_expired_issue_proposal(url_hash);
+            if self.issue_proposals.contains(url_hash) {
+                return Err(Error::IssueProposalPending);
+            }
+
+            let mut proposal = IssueProposal {
+                github_url_hash: url_hash,
+                repository_full_name: repository_full_name.clone(),
+                issue_number,
+                target_bounty,
+                proposer: caller,
+                votes_count: 1,
+                voters: Vec::new(),
+                whitelist_version: self.whitelist_version,
+                vote_started_at_block: self.env().block_number(),
+            };
+            proposal.voters.push(caller);
+            self.touch_validator(caller);
+
+            self.env().emit_event(IssueProposed {
+                github_url_hash: url_hash,
+                proposer: caller,
+                repository_full_name,
+                issue_number,
+                target_bounty,
+            });
+
+            Ok(self.tally_issue_proposal(proposal))
+        }
+
+        /// Votes for an open issue proposal, identified by its URL hash.
+        #[ink(message)]
+        pub fn vote_issue_proposal(&mut self, url_hash: [u8; 32]) -> Result<VoteTally, Error> {
+            if self.url_hash_to_id.get(url_hash).is_some() {
+                return Err(Error::IssueAlreadyExists);
+            }
+
+            self.clear_expired_issue_proposal(url_hash);
+            let mut proposal = self
+                .issue_proposals
+                .get(url_hash)
+                .ok_or(Error::NoIssueProposal)?;
+            self.drop_removed_voters(&mut proposal.voters, &mut proposal.whitelist_version);
+
+            if proposal.voters.contains(&self.env().caller()) {
+                return Err(Error::AlreadyVoted);
+            }
+            let caller = self.validate_whitelisted_caller()?;
+
+            self.touch_validator(caller);
+            proposal.voters.push(caller);
+            proposal.votes_count = u32::try_from(proposal.voters.len()).unwrap_or(u32::MAX);
+
+            Ok(self.tally_issue_proposal(proposal))
+        }
+
+        /// Returns the open registration proposal for a URL hash, with its voters.
+        #[ink(message)]
+        pub fn get_issue_proposal(&self, url_hash: [u8; 32]) -> Option<IssueProposal> {
+            self.issue_proposals.get(url_hash)
+        }
+
+        /// Checks registration inputs shared by register_issue and propose_issue.
+        /// Returns the canonical URL hash.
+        fn validate_new_issue(
+            &self,
+            github_url: &str,
+            repository_full_name: &str,
+            issue_number: u32,
+            target_bounty: u128,
+        ) -> Result<[u8; 32], Error> {
+            if target_bounty < self.min_bounty {
                 return Err(Error::BountyTooLow);
             }
             if issue_number == 0 {
                 return Err(Error::InvalidIssueNumber);
             }
-            if !self.is_valid_repo_name(&repository_full_name) {
+            if !self.is_valid_repo_name(repository_full_name) {
                 return Err(Error::InvalidRepositoryName);
             }
+            if self.repo_allowlist_enabled && !self.repo_allowed(repository_full_name) {
+                return Err(Error::RepositoryNotAllowed);
+            }
 
-            let url_hash = self.hash_string(&github_url);
+            let url_hash = self.hash_string(&self.canonical_issue_url(github_url));
 
             if self.url_hash_to_id.get(url_hash).is_some() {
                 return Err(Error::IssueAlreadyExists);
             }
+            Ok(url_hash)
+        }
 
+        /// Stores a validated issue, queues it for funding and emits
+        /// IssueRegistered. Returns the new issue ID.
+        #[allow(clippy::too_many_arguments)]
+        fn create_issue(
+            &mut self,
+            url_hash: [u8; 32],
+            repository_full_name: String,
+            issue_number: u32,
+            target_bounty: u128,
+            difficulty: Option<u8>,
+            label_hash: Option<[u8; 32]>,
+            registered_by: AccountId,
+        ) -> u64 {
             let current_block = self.env().block_number();
             let issue_id = self.next_issue_id;
             self.next_issue_id = self.next_issue_id.saturating_add(1);
@@ -190,32 +534,134 @@ mod issue_bounty_manager {
                 target_bounty,
                 status: IssueStatus::Registered,
                 registered_at_block: current_block,
+                registered_by,
                 solver_coldkey: None,
                 solver_hotkey: None,
                 winning_pr_number: None,
+                difficulty,
+                label_hash,
             };
 
-            self.issues.insert(issue_id, &new_issue);
+            self.save_issue(issue_id, &new_issue);
             self.url_hash_to_id.insert(url_hash, &issue_id);
             self.bounty_queue.push(issue_id);
 
             self.env().emit_event(IssueRegistered {
                 issue_id,
                 github_url_hash: url_hash,
+                repository_hash: self.hash_string(&repository_full_name),
                 repository_full_name,
                 issue_number,
                 target_bounty,
+                difficulty,
+                label_hash,
+                registered_by,
+                registered_at_block: current_block,
+            });
+
+            issue_id
+        }
+
+        /// Registers several issues in one call (owner only).
+        ///
+        /// Each item is validated independently; returns one result per input,
+        /// in order, so a duplicate or invalid entry does not block the rest.
+        #[ink(message)]
+        pub fn register_issues(
+            &mut self,
+            issues: Vec<IssueInput>,
+        ) -> Result<Vec<Result<u64, Error>>, Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if issues.len() > MAX_BATCH_SIZE {
+                return Err(Error::BatchTooLarge);
+            }
+
+            Ok(issues
+                .into_iter()
+                .map(|input| {
+                    self.register_issue(
+                        input.github_url,
+                        input.repository_full_name,
+                        input.issue_number,
+                        input.target_bounty,
+                        input.difficulty,
+                        input.label_hash,
+                    )
+                })
+                .collect())
+        }
+
+        /// Adds a repository ("owner/repo", case-insensitive) to the allow-list (owner only).
+        #[ink(message)]
+        pub fn add_allowed_repository(
+            &mut self,
+            repository_full_name: String,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if !self.is_valid_repo_name(&repository_full_name) {
+                return Err(Error::InvalidRepositoryName);
+            }
+            let repository_hash = self.repo_allowlist_key(&repository_full_name);
+            self.allowed_repositories.insert(repository_hash, &true);
+            self.env().emit_event(RepositoryAllowed {
+                repository_hash,
+                repository_full_name,
+            });
+            Ok(())
+        }
+
+        /// Removes a repository from the allow-list (owner only). Issues already
+        /// registered for it are unaffected.
+        #[ink(message)]
+        pub fn remove_allowed_repository(
+            &mut self,
+            repository_full_name: String,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let repository_hash = self.repo_allowlist_key(&repository_full_name);
+            self.allowed_repositories.remove(repository_hash);
+            self.env().emit_event(RepositoryDisallowed {
+                repository_hash,
+                repository_full_name,
             });
+            Ok(())
+        }
+
+        /// Turns allow-list enforcement at registration on or off (owner only).
+        #[ink(message)]
+        pub fn set_repo_allowlist_enabled(&mut self, enabled: bool) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let old_value = self.repo_allowlist_enabled;
+            self.repo_allowlist_enabled = enabled;
+            self.emit_config_updated(
+                ConfigField::RepoAllowlistEnabled,
+                old_value.into(),
+                enabled.into(),
+            );
+            Ok(())
+        }
 
-            Ok(issue_id)
+        /// Returns true if the repository is on the allow-list (case-insensitive)
+        #[ink(message)]
+        pub fn is_repository_allowed(&self, repository_full_name: String) -> bool {
+            self.repo_allowed(&repository_full_name)
         }
 
         /// Cancels an issue (owner only)
         #[ink(message)]
-        pub fn cancel_issue(&mut self, issue_id: u64) -> Result<(), Error> {
+        pub fn cancel_issue(&mut self, issue_id: u64, reason: Option<String>) -> Result<(), Error> {
             if self.env().caller() != self.owner {
                 return Err(Error::NotOwner);
             }
+            self.validate_cancel_reason(&reason)?;
 
             let mut issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
 
@@ -224,17 +670,27 @@ mod issue_bounty_manager {
             }
 
             let returned_bounty = issue.bounty_amount;
-            self.alpha_pool = self.alpha_pool.saturating_add(returned_bounty);
+            self.return_to_pool(returned_bounty);
 
             issue.status = IssueStatus::Cancelled;
             issue.bounty_amount = 0;
-            self.issues.insert(issue_id, &issue);
+            self.save_issue(issue_id, &issue);
 
-            self.remove_from_bounty_queue(issue_id);
+            self.dequeue_issue(issue_id);
+
+            let reason_hash = reason
+                .as_deref()
+                .map(|r| self.hash_string(r))
+                .unwrap_or([0u8; 32]);
+            self.record_cancellation(issue_id, reason_hash, reason.clone());
 
             self.env().emit_event(IssueCancelled {
                 issue_id,
+                repository_hash: self.hash_string(&issue.repository_full_name),
                 returned_bounty,
+                destination: CancelDestination::Pool,
+                reason_hash,
+                reason,
             });
 
             Ok(())
@@ -244,9 +700,57 @@ mod issue_bounty_manager {
         // Validator Consensus Functions
         // ========================================================================
 
+        /// Votes a proposal needs: the fixed override or a majority of active
+        /// validators, never below min_votes_required. The floor is capped at the
+        /// whitelist size so a smaller validator set can still reach consensus.
         fn required_validator_votes(&self) -> u32 {
-            let n = u32::try_from(self.validators.len()).unwrap_or(u32::MAX);
-            n.saturating_div(2).saturating_add(1)
+            let required = match self.required_votes_override {
+                Some(required) => required,
+                None => self
+                    .active_validator_count()
+                    .saturating_div(2)
+                    .saturating_add(1),
+            };
+            let whitelisted = u32::try_from(self.validators.len()).unwrap_or(u32::MAX);
+            required.max(self.min_votes_required.min(whitelisted))
+        }
+
+        /// Rejects whitelist and quorum setting changes while a config change
+        /// or repair awaits its timelock, so the cancel threshold stays where it
+        /// was when the change was scheduled and validators can still reach it.
+        fn ensure_quorum_unlocked(&self) -> Result<(), Error> {
+            if self.pending_config_change.is_some() || !self.pending_repair_ids.is_empty() {
+                return Err(Error::QuorumLocked);
+            }
+            Ok(())
+        }
+
+        /// Number of whitelisted validators seen within validator_inactivity_blocks.
+        fn active_validator_count(&self) -> u32 {
+            let active = self
+                .validators
+                .iter()
+                .filter(|v| self.is_validator_active(**v))
+                .count();
+            u32::try_from(active).unwrap_or(u32::MAX)
+        }
+
+        /// Adds a hotkey to the validator whitelist, bounded by max_validators.
+        fn whitelist_validator(&mut self, hotkey: AccountId) -> Result<(), Error> {
+            if self.validators.len() >= self.max_validators as usize {
+                return Err(Error::TooManyValidators);
+            }
+            self.validators.push(hotkey);
+            self.whitelist_version = self.whitelist_version.saturating_add(1);
+            self.touch_validator(hotkey);
+            self.env().emit_event(ValidatorAdded { hotkey });
+            Ok(())
+        }
+
+        /// Records activity for a validator at the current block.
+        fn touch_validator(&mut self, hotkey: AccountId) {
+            let current_block = self.env().block_number();
+            self.validator_last_seen.insert(hotkey, &current_block);
         }
 
         #[ink(message)]
@@ -254,13 +758,37 @@ mod issue_bounty_manager {
             if self.env().caller() != self.owner {
                 return Err(Error::NotOwner);
             }
+            self.ensure_quorum_unlocked()?;
             if self.validators.contains(&hotkey) {
                 return Err(Error::ValidatorAlreadyWhitelisted);
             }
-            self.validators.push(hotkey);
-            self.env().emit_event(ValidatorAdded { hotkey });
+            self.whitelist_validator(hotkey)
+        }
 
-            Ok(())
+        /// Whitelists `hotkey` without owner approval if the calling coldkey has
+        /// at least min_validator_stake of its own stake on it. Only the caller's
+        /// stake counts, so an applicant cannot qualify on stake it does not
+        /// control. The owner can still remove the validator with remove_validator.
+        #[ink(message)]
+        pub fn apply_as_validator(&mut self, hotkey: AccountId) -> Result<(), Error> {
+            let coldkey = self.env().caller();
+            if self.min_validator_stake == 0 {
+                return Err(Error::ValidatorApplicationsClosed);
+            }
+            self.ensure_quorum_unlocked()?;
+            if self.validators.contains(&hotkey) {
+                return Err(Error::ValidatorAlreadyWhitelisted);
+            }
+            let stake = self
+                .env()
+                .extension()
+                .get_stake_info(*hotkey.as_ref(), *coldkey.as_ref(), self.netuid)
+                .map(|info| u128::from(info.stake.0))
+                .unwrap_or(0);
+            if stake < self.min_validator_stake {
+                return Err(Error::InsufficientValidatorStake);
+            }
+            self.whitelist_validator(hotkey)
         }
 
         #[ink(message)]
@@ -268,12 +796,15 @@ mod issue_bounty_manager {
             if self.env().caller() != self.owner {
                 return Err(Error::NotOwner);
             }
+            self.ensure_quorum_unlocked()?;
             let pos = self
                 .validators
                 .iter()
                 .position(|v| v == &hotkey)
                 .ok_or(Error::ValidatorNotWhitelisted)?;
             self.validators.remove(pos);
+            self.whitelist_version = self.whitelist_version.saturating_add(1);
+            self.validator_last_seen.remove(hotkey);
             self.env().emit_event(ValidatorRemoved { hotkey });
             Ok(())
         }
@@ -283,9 +814,42 @@ mod issue_bounty_manager {
             self.validators.clone()
         }
 
+        /// Signals that a whitelisted validator is still active.
+        ///
+        /// Validators silent for more than validator_inactivity_blocks are excluded
+        /// from the quorum until they heartbeat or vote again. Votes count as activity.
+        #[ink(message)]
+        pub fn heartbeat(&mut self) -> Result<(), Error> {
+            let caller = self.validate_whitelisted_caller()?;
+            self.touch_validator(caller);
+            Ok(())
+        }
+
+        /// Returns the last block a validator voted or sent a heartbeat.
+        #[ink(message)]
+        pub fn get_validator_last_seen(&self, hotkey: AccountId) -> Option<u32> {
+            self.validator_last_seen.get(hotkey)
+        }
+
+        /// Returns true if the validator is whitelisted and counted towards the quorum.
+        #[ink(message)]
+        pub fn is_validator_active(&self, hotkey: AccountId) -> bool {
+            if !self.validators.contains(&hotkey) {
+                return false;
+            }
+            match self.validator_last_seen.get(hotkey) {
+                Some(last_seen) => {
+                    self.env().block_number().saturating_sub(last_seen)
+                        <= self.validator_inactivity_blocks
+                }
+                None => false,
+            }
+        }
+
         /// Votes for a solution on an active issue.
         ///
         /// When consensus is reached, the issue is completed and bounty paid out.
+        /// Returns the tally so the caller can see how close the vote is.
         #[ink(message)]
         pub fn vote_solution(
             &mut self,
@@ -293,46 +857,68 @@ mod issue_bounty_manager {
             solver_hotkey: AccountId,
             solver_coldkey: AccountId,
             pr_number: u32,
-        ) -> Result<(), Error> {
-            let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
-
-            if issue.status != IssueStatus::Active {
-                return Err(Error::IssueNotActive);
-            }
-
-            // Check not already voted
-            self.check_not_voted_solution(issue_id, self.env().caller())?;
-            let caller = self.validate_whitelisted_caller()?;
-
-            // Get or create vote
-            let mut vote = self.get_or_create_solution_vote(
+        ) -> Result<VoteTally, Error> {
+            self.cast_solution_vote(
                 issue_id,
                 solver_hotkey,
-                pr_number,
                 solver_coldkey,
-            );
-            self.solution_vote_voters.insert((issue_id, caller), &true);
-            vote.votes_count = vote.votes_count.saturating_add(1);
-            self.solution_votes.insert(issue_id, &vote);
+                pr_number,
+                &mut Vec::new(),
+            )
+        }
 
-            // Check consensus and execute (includes auto-payout)
-            if self.check_consensus(vote.votes_count) {
-                self.complete_issue(issue_id, solver_hotkey, pr_number, solver_coldkey);
-                self.clear_solution_vote(issue_id);
+        /// Casts several solution votes in one call (whitelisted validators only).
+        ///
+        /// Returns one result per vote, in order; a vote that fails (e.g. already
+        /// voted, issue no longer active) does not block the rest.
+        #[ink(message)]
+        pub fn vote_solutions_batch(
+            &mut self,
+            votes: Vec<SolutionVoteInput>,
+        ) -> Result<Vec<Result<VoteTally, Error>>, Error> {
+            self.validate_whitelisted_caller()?;
+            if votes.len() > MAX_BATCH_SIZE {
+                return Err(Error::BatchTooLarge);
             }
 
-            Ok(())
+            // Solver registration is looked up once per (hotkey, coldkey) for the batch
+            let mut registered = Vec::new();
+            Ok(votes
+                .into_iter()
+                .map(|vote| {
+                    self.cast_solution_vote(
+                        vote.issue_id,
+                        vote.solver_hotkey,
+                        vote.solver_coldkey,
+                        vote.pr_number,
+                        &mut registered,
+                    )
+                })
+                .collect())
         }
 
         /// Votes to cancel an issue (e.g., external solution found, issue invalid).
         ///
-        /// Works on issues in Registered or Active state.
+        /// Works on issues in Registered or Active state. The first vote selects
+        /// where the bounty goes (recycled or released to the pool) and the
+        /// optional reason text; later votes must carry the same reason hash and
+        /// destination, so every counted vote agrees on the outcome. When reason
+        /// text is given, reason_hash must be its hash_string (keccak256).
         #[ink(message)]
         pub fn vote_cancel_issue(
             &mut self,
             issue_id: u64,
             reason_hash: [u8; 32],
-        ) -> Result<(), Error> {
+            destination: CancelDestination,
+            reason: Option<String>,
+        ) -> Result<VoteTally, Error> {
+            self.validate_cancel_reason(&reason)?;
+            if reason
+                .as_deref()
+                .is_some_and(|r| self.hash_string(r) != reason_hash)
+            {
+                return Err(Error::ReasonHashMismatch);
+            }
             let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
 
             // Can cancel Registered or Active
@@ -343,69 +929,840 @@ mod issue_bounty_manager {
                 return Err(Error::IssueAlreadyFinalized);
             }
 
+            // Get or create vote, dropping an expired proposal and voters removed
+            // from the whitelist
+            self.clear_expired_cancel_vote(issue_id);
+            let mut vote =
+                self.get_or_create_cancel_issue_vote(issue_id, reason_hash, reason, destination);
+            for voter in self.drop_removed_voters(&mut vote.voters, &mut vote.whitelist_version) {
+                self.cancel_issue_voters.remove((issue_id, voter));
+            }
+
             // Standard vote validation
             self.check_not_voted_cancel_issue(issue_id, self.env().caller())?;
             let caller = self.validate_whitelisted_caller()?;
+            if vote.reason_hash != reason_hash || vote.destination != destination {
+                return Err(Error::CancelProposalMismatch);
+            }
 
-            // Get or create vote, increment count
-            let mut vote = self.get_or_create_cancel_issue_vote(issue_id, reason_hash);
+            self.touch_validator(caller);
             self.cancel_issue_voters.insert((issue_id, caller), &true);
-            vote.votes_count = vote.votes_count.saturating_add(1);
+            vote.voters.push(caller);
+            vote.votes_count = u32::try_from(vote.voters.len()).unwrap_or(u32::MAX);
             self.cancel_issue_votes.insert(issue_id, &vote);
 
             // Check consensus and execute
-            if self.check_consensus(vote.votes_count) {
-                self.execute_cancel_issue(issue_id, reason_hash);
+            let tally = self.vote_tally(vote.votes_count);
+            if tally.consensus_reached {
+                self.execute_cancel_issue(
+                    issue_id,
+                    vote.reason_hash,
+                    vote.reason,
+                    vote.destination,
+                )?;
                 self.clear_cancel_issue_vote(issue_id);
             }
 
-            Ok(())
+            Ok(tally)
         }
 
-        // ========================================================================
-        // Admin Functions
-        // ========================================================================
+        /// Clears expired solution and cancel proposals on the given issues
+        /// (callable by anyone). Returns the number of proposals cleared.
+        #[ink(message)]
+        pub fn sweep_expired_votes(&mut self, issue_ids: Vec<u64>) -> Result<u32, Error> {
+            if issue_ids.len() > MAX_BATCH_SIZE {
+                return Err(Error::BatchTooLarge);
+            }
+
+            let mut cleared: u32 = 0;
+            for issue_id in issue_ids {
+                cleared = cleared.saturating_add(self.clear_expired_solution_votes(issue_id));
+                if self.clear_expired_cancel_vote(issue_id) {
+                    cleared = cleared.saturating_add(1);
+                }
+                if self.clear_expired_bounty_adjustment(issue_id) {
+                    cleared = cleared.saturating_add(1);
+                }
+            }
+            Ok(cleared)
+        }
 
-        /// Sets a new owner
+        /// Withdraws the caller's vote from the solution candidate they backed.
+        /// A candidate left with no votes is discarded.
         #[ink(message)]
-        pub fn set_owner(&mut self, new_owner: AccountId) -> Result<(), Error> {
-            if self.env().caller() != self.owner {
-                return Err(Error::NotOwner);
+        pub fn revoke_solution_vote(&mut self, issue_id: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let mut candidates = self.solution_votes.get(issue_id).unwrap_or_default();
+            let (index, pos) = candidates
+                .iter()
+                .enumerate()
+                .find_map(|(i, c)| c.voters.iter().position(|v| v == &caller).map(|p| (i, p)))
+                .ok_or(Error::NotVoted)?;
+
+            let vote = &mut candidates[index];
+            vote.voters.remove(pos);
+            vote.votes_count = u32::try_from(vote.voters.len()).unwrap_or(u32::MAX);
+            let votes_count = vote.votes_count;
+            if votes_count == 0 {
+                candidates.remove(index);
             }
-            self.owner = new_owner;
+            self.solution_vote_voters.remove((issue_id, caller));
+            if candidates.is_empty() {
+                self.clear_solution_vote(issue_id);
+            } else {
+                self.solution_votes.insert(issue_id, &candidates);
+            }
+
+            self.env().emit_event(VoteRevoked {
+                issue_id,
+                voter: caller,
+                kind: VoteKind::Solution,
+                votes_count,
+            });
             Ok(())
         }
 
-        /// Sets a new treasury hotkey.
-        ///
-        /// Resets bounty amounts to 0 for all Active/Registered issues since
-        /// the new treasury has no stake to back them. Issues remain in their
-        /// current status and will be re-funded on next harvest.
+        /// Withdraws the caller's vote from an issue's open cancellation proposal.
+        /// A proposal left with no votes is discarded.
         #[ink(message)]
-        pub fn set_treasury_hotkey(&mut self, new_hotkey: AccountId) -> Result<(), Error> {
-            if self.env().caller() != self.owner {
-                return Err(Error::NotOwner);
-            }
+        pub fn revoke_cancel_vote(&mut self, issue_id: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let mut vote = self
+                .cancel_issue_votes
+                .get(issue_id)
+                .ok_or(Error::NotVoted)?;
+            let pos = vote
+                .voters
+                .iter()
+                .position(|v| v == &caller)
+                .ok_or(Error::NotVoted)?;
 
-            let old_hotkey = self.treasury_hotkey;
+            vote.voters.remove(pos);
+            vote.votes_count = u32::try_from(vote.voters.len()).unwrap_or(u32::MAX);
+            self.cancel_issue_voters.remove((issue_id, caller));
+            if vote.voters.is_empty() {
+                self.clear_cancel_issue_vote(issue_id);
+            } else {
+                self.cancel_issue_votes.insert(issue_id, &vote);
+            }
 
-            // Reset bounty amounts for all Active/Registered issues
-            let mut bounties_reset: u128 = 0;
-            let mut issues_affected: u32 = 0;
+            self.env().emit_event(VoteRevoked {
+                issue_id,
+                voter: caller,
+                kind: VoteKind::Cancel,
+                votes_count: vote.votes_count,
+            });
+            Ok(())
+        }
 
-            for issue_id in 1..self.next_issue_id {
-                if let Some(mut issue) = self.issues.get(issue_id) {
-                    if self.is_modifiable(issue.status) && issue.bounty_amount > 0 {
-                        bounties_reset = bounties_reset.saturating_add(issue.bounty_amount);
-                        issues_affected = issues_affected.saturating_add(1);
-                        issue.bounty_amount = 0;
-                        self.issues.insert(issue_id, &issue);
-                    }
+        /// Proposes a new target bounty for a Registered issue, counting the
+        /// proposer's vote. On consensus, bounty above the new target returns to
+        /// the alpha pool; a raised target is funded by later harvests from the
+        /// issue's current queue position.
+        #[ink(message)]
+        pub fn propose_bounty_adjustment(
+            &mut self,
+            issue_id: u64,
+            new_target: Balance,
+        ) -> Result<VoteTally, Error> {
+            let caller = self.validate_whitelisted_caller()?;
+            let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+            if issue.status != IssueStatus::Registered {
+                return Err(Error::IssueNotRegistered);
+            }
+            if new_target < self.min_bounty {
+                return Err(Error::BountyTooLow);
+            }
+            if new_target == issue.target_bounty {
+                return Err(Error::BountyTargetUnchanged);
+            }
+
+            self.clear_expired_bounty_adjustment(issue_id);
+            if self.bounty_adjustments.contains(issue_id) {
+                return Err(Error::BountyAdjustmentPending);
+            }
+
+            let mut adjustment = BountyAdjustment {
+                issue_id,
+                new_target,
+                proposer: caller,
+                votes_count: 1,
+                voters: Vec::new(),
+                whitelist_version: self.whitelist_version,
+                vote_started_at_block: self.env().block_number(),
+            };
+            adjustment.voters.push(caller);
+            self.touch_validator(caller);
+            self.bounty_adjustment_voters
+                .insert((issue_id, caller), &true);
+
+            self.env().emit_event(BountyAdjustmentProposed {
+                issue_id,
+                proposer: caller,
+                old_target: issue.target_bounty,
+                new_target,
+            });
+
+            self.tally_bounty_adjustment(adjustment)
+        }
+
+        /// Votes for an issue's open bounty adjustment proposal.
+        #[ink(message)]
+        pub fn vote_bounty_adjustment(&mut self, issue_id: u64) -> Result<VoteTally, Error> {
+            let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+            if issue.status != IssueStatus::Registered {
+                return Err(Error::IssueNotRegistered);
+            }
+
+            self.clear_expired_bounty_adjustment(issue_id);
+            let mut adjustment = self
+                .bounty_adjustments
+                .get(issue_id)
+                .ok_or(Error::NoBountyAdjustment)?;
+            for voter in
+                self.drop_removed_voters(&mut adjustment.voters, &mut adjustment.whitelist_version)
+            {
+                self.bounty_adjustment_voters.remove((issue_id, voter));
+            }
+
+            let caller = self.env().caller();
+            if self
+                .bounty_adjustment_voters
+                .get((issue_id, caller))
+                .unwrap_or(false)
+            {
+                return Err(Error::AlreadyVoted);
+            }
+            let caller = self.validate_whitelisted_caller()?;
+
+            self.touch_validator(caller);
+            self.bounty_adjustment_voters
+                .insert((issue_id, caller), &true);
+            adjustment.voters.push(caller);
+            adjustment.votes_count = u32::try_from(adjustment.voters.len()).unwrap_or(u32::MAX);
+
+            self.tally_bounty_adjustment(adjustment)
+        }
+
+        /// Returns the open bounty adjustment proposal for an issue, with its voters.
+        #[ink(message)]
+        pub fn get_bounty_adjustment(&self, issue_id: u64) -> Option<BountyAdjustment> {
+            self.bounty_adjustments.get(issue_id)
+        }
+
+        /// Attaches an evidence note hash (e.g., review summary, CI run) to an
+        /// Active issue, building an on-chain trail for the eventual solution vote.
+        #[ink(message)]
+        pub fn add_issue_note(&mut self, issue_id: u64, note_hash: [u8; 32]) -> Result<u32, Error> {
+            let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+            if issue.status != IssueStatus::Active {
+                return Err(Error::IssueNotActive);
+            }
+            let caller = self.validate_whitelisted_caller()?;
+
+            let index = self.issue_note_count.get(issue_id).unwrap_or(0);
+            if index >= MAX_NOTES_PER_ISSUE {
+                return Err(Error::TooManyNotes);
+            }
+
+            let note = IssueNote {
+                author: caller,
+                note_hash,
+                added_at_block: self.env().block_number(),
+            };
+            self.issue_notes.insert((issue_id, index), &note);
+            self.issue_note_count
+                .insert(issue_id, &index.saturating_add(1));
+            self.touch_validator(caller);
+
+            self.env().emit_event(IssueNoteAdded {
+                issue_id,
+                author: caller,
+                note_hash,
+                index,
+            });
+
+            Ok(index)
+        }
+
+        /// Returns up to `limit` notes for an issue starting at index `offset`.
+        #[ink(message)]
+        pub fn get_issue_notes(&self, issue_id: u64, offset: u32, limit: u32) -> Vec<IssueNote> {
+            let count = self.issue_note_count.get(issue_id).unwrap_or(0);
+            let end = offset.saturating_add(limit).min(count);
+            (offset..end)
+                .filter_map(|index| self.issue_notes.get((issue_id, index)))
+                .collect()
+        }
+
+        /// Returns the number of notes attached to an issue.
+        #[ink(message)]
+        pub fn get_issue_note_count(&self, issue_id: u64) -> u32 {
+            self.issue_note_count.get(issue_id).unwrap_or(0)
+        }
+
+        // ========================================================================
+        // Owner Recovery Functions
+        // ========================================================================
+
+        /// Votes required to recover the owner: two thirds of all whitelisted
+        /// validators. Unlike regular proposals this ignores activity, so
+        /// validators going quiet cannot shrink the quorum needed to take over
+        /// the contract.
+        fn required_recovery_votes(&self) -> u32 {
+            let n = u32::try_from(self.validators.len()).unwrap_or(u32::MAX);
+            n.saturating_mul(2)
+                .saturating_add(2)
+                .saturating_div(3)
+                .max(1)
+        }
+
+        /// Proposes replacing the owner (e.g., if the owner key was lost).
+        ///
+        /// The proposer's vote is counted. Execution requires two thirds of
+        /// whitelisted validators and owner_recovery_timelock_blocks to pass,
+        /// during which the current owner can cancel.
+        #[ink(message)]
+        pub fn propose_owner_recovery(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            let caller = self.validate_whitelisted_caller()?;
+            if self.owner_recovery.is_some() {
+                return Err(Error::OwnerRecoveryPending);
+            }
+
+            let current_block = self.env().block_number();
+            let executable_at_block =
+                current_block.saturating_add(self.owner_recovery_timelock_blocks);
+            let mut recovery = OwnerRecovery {
+                new_owner,
+                proposed_at_block: current_block,
+                executable_at_block,
+                votes_count: 1,
+                voters: Vec::new(),
+                whitelist_version: self.whitelist_version,
+            };
+            recovery.voters.push(caller);
+            self.owner_recovery = Some(recovery);
+            self.touch_validator(caller);
+
+            self.env().emit_event(OwnerRecoveryProposed {
+                new_owner,
+                proposer: caller,
+                executable_at_block,
+            });
+
+            Ok(())
+        }
+
+        /// Votes for the pending owner recovery.
+        ///
+        /// Votes from validators removed since they voted are dropped first.
+        #[ink(message)]
+        pub fn vote_owner_recovery(&mut self) -> Result<(), Error> {
+            let caller = self.validate_whitelisted_caller()?;
+            let mut recovery = self.owner_recovery.clone().ok_or(Error::NoOwnerRecovery)?;
+
+            self.reconcile_recovery_voters(&mut recovery);
+            if recovery.voters.contains(&caller) {
+                return Err(Error::AlreadyVoted);
+            }
+
+            recovery.voters.push(caller);
+            recovery.votes_count = recovery.votes_count.saturating_add(1);
+            let votes_count = recovery.votes_count;
+            self.owner_recovery = Some(recovery);
+            self.touch_validator(caller);
+
+            self.env().emit_event(OwnerRecoveryVoted {
+                voter: caller,
+                votes_count,
+                required_votes: self.required_recovery_votes(),
+            });
+
+            Ok(())
+        }
+
+        /// Executes an approved owner recovery after its timelock.
+        ///
+        /// PERMISSIONLESS - Anyone can call this function once conditions are met.
+        #[ink(message)]
+        pub fn execute_owner_recovery(&mut self) -> Result<(), Error> {
+            let mut recovery = self.owner_recovery.clone().ok_or(Error::NoOwnerRecovery)?;
+
+            if self.reconcile_recovery_voters(&mut recovery) {
+                self.owner_recovery = Some(recovery.clone());
+            }
+            if recovery.votes_count < self.required_recovery_votes() {
+                return Err(Error::OwnerRecoveryNotApproved);
+            }
+            if self.env().block_number() < recovery.executable_at_block {
+                return Err(Error::OwnerRecoveryTimelocked);
+            }
+
+            let old_owner = self.owner;
+            self.owner = recovery.new_owner;
+            self.owner_recovery = None;
+
+            self.env().emit_event(OwnerRecovered {
+                old_owner,
+                new_owner: recovery.new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Drops votes from validators removed since the recovery's voters were
+        /// last checked. Returns whether the recovery changed.
+        fn reconcile_recovery_voters(&self, recovery: &mut OwnerRecovery) -> bool {
+            if recovery.whitelist_version == self.whitelist_version {
+                return false;
+            }
+            self.drop_removed_voters(&mut recovery.voters, &mut recovery.whitelist_version);
+            recovery.votes_count = u32::try_from(recovery.voters.len()).unwrap_or(u32::MAX);
+            true
+        }
+
+        /// Cancels a pending owner recovery (owner only).
+        #[ink(message)]
+        pub fn cancel_owner_recovery(&mut self) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let recovery = self.owner_recovery.take().ok_or(Error::NoOwnerRecovery)?;
+            self.env().emit_event(OwnerRecoveryCancelled {
+                new_owner: recovery.new_owner,
+            });
+            Ok(())
+        }
+
+        /// Returns the pending owner recovery, if any
+        #[ink(message)]
+        pub fn get_owner_recovery(&self) -> Option<OwnerRecovery> {
+            self.owner_recovery.clone()
+        }
+
+        /// Schedules an owner, treasury hotkey, netuid or timelock change (owner only).
+        /// Timelocks (config or owner recovery) cannot be set to 0.
+        ///
+        /// The change can be executed after config_timelock_blocks; until then the
+        /// owner can withdraw it and validators can cancel it by consensus.
+        #[ink(message)]
+        pub fn schedule_config_change(&mut self, change: ScheduledChange) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if self.pending_config_change.is_some() {
+                return Err(Error::ConfigChangePending);
+            }
+            if change == ScheduledChange::ConfigTimelockBlocks(0)
+                || change == ScheduledChange::OwnerRecoveryTimelockBlocks(0)
+            {
+                return Err(Error::InvalidConfig);
+            }
+
+            let current_block = self.env().block_number();
+            let executable_at_block = current_block.saturating_add(self.config_timelock_blocks);
+            self.pending_config_change = Some(PendingConfigChange {
+                change,
+                scheduled_at_block: current_block,
+                executable_at_block,
+                cancel_votes: 0,
+                cancel_voters: Vec::new(),
+                whitelist_version: self.whitelist_version,
+            });
+
+            self.env().emit_event(ConfigChangeScheduled {
+                change,
+                executable_at_block,
+            });
+            Ok(())
+        }
+
+        /// Applies the scheduled config change once its timelock has passed.
+        ///
+        /// PERMISSIONLESS - Anyone can call this function once conditions are met.
+        #[ink(message)]
+        pub fn execute_config_change(&mut self) -> Result<(), Error> {
+            let pending = self
+                .pending_config_change
+                .clone()
+                .ok_or(Error::NoConfigChange)?;
+            if self.env().block_number() < pending.executable_at_block {
+                return Err(Error::ConfigChangeTimelocked);
+            }
+
+            self.pending_config_change = None;
+            self.apply_config_change(pending.change);
+            self.env().emit_event(ConfigChangeExecuted {
+                change: pending.change,
+            });
+            Ok(())
+        }
+
+        /// Withdraws the scheduled config change (owner only).
+        #[ink(message)]
+        pub fn cancel_config_change(&mut self) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let pending = self
+                .pending_config_change
+                .take()
+                .ok_or(Error::NoConfigChange)?;
+            self.env().emit_event(ConfigChangeCancelled {
+                change: pending.change,
+                by_validators: false,
+            });
+            Ok(())
+        }
+
+        /// Votes to cancel the scheduled config change (whitelisted validators only).
+        /// The change is dropped once the votes reach consensus.
+        ///
+        /// Votes from validators removed since they voted are dropped first.
+        #[ink(message)]
+        pub fn vote_cancel_config_change(&mut self) -> Result<VoteTally, Error> {
+            let caller = self.validate_whitelisted_caller()?;
+            let mut pending = self
+                .pending_config_change
+                .clone()
+                .ok_or(Error::NoConfigChange)?;
+
+            pending.cancel_votes = self.reconcile_cancel_voters(
+                &mut pending.cancel_voters,
+                &mut pending.whitelist_version,
+            );
+            if pending.cancel_voters.contains(&caller) {
+                return Err(Error::AlreadyVoted);
+            }
+
+            self.touch_validator(caller);
+            pending.cancel_voters.push(caller);
+            pending.cancel_votes = pending.cancel_votes.saturating_add(1);
+
+            let tally = self.vote_tally(pending.cancel_votes);
+            if tally.consensus_reached {
+                self.pending_config_change = None;
+                self.env().emit_event(ConfigChangeCancelled {
+                    change: pending.change,
+                    by_validators: true,
+                });
+            } else {
+                self.pending_config_change = Some(pending);
+            }
+            Ok(tally)
+        }
+
+        /// Returns the scheduled config change, if any
+        #[ink(message)]
+        pub fn get_pending_config_change(&self) -> Option<PendingConfigChange> {
+            self.pending_config_change.clone()
+        }
+
+        // ========================================================================
+        // Admin Functions
+        // ========================================================================
+
+        /// Sets a new owner.
+        ///
+        /// Only reachable through schedule_config_change, so validators get
+        /// the config timelock to cancel a hostile or mistaken transfer.
+        fn set_owner(&mut self, new_owner: AccountId) {
+            self.owner = new_owner;
+        }
+
+        /// Applies a config change whose timelock has passed.
+        fn apply_config_change(&mut self, change: ScheduledChange) {
+            match change {
+                ScheduledChange::Owner(new_owner) => self.set_owner(new_owner),
+                ScheduledChange::TreasuryHotkey(new_hotkey) => self.set_treasury_hotkey(new_hotkey),
+                ScheduledChange::Netuid(netuid) => self.set_netuid(netuid),
+                ScheduledChange::ConfigTimelockBlocks(blocks) => {
+                    let old_value = self.config_timelock_blocks;
+                    self.config_timelock_blocks = blocks;
+                    self.emit_config_updated(
+                        ConfigField::ConfigTimelockBlocks,
+                        old_value.into(),
+                        blocks.into(),
+                    );
+                }
+                ScheduledChange::OwnerRecoveryTimelockBlocks(blocks) => {
+                    let old_value = self.owner_recovery_timelock_blocks;
+                    self.owner_recovery_timelock_blocks = blocks;
+                    self.emit_config_updated(
+                        ConfigField::OwnerRecoveryTimelockBlocks,
+                        old_value.into(),
+                        blocks.into(),
+                    );
                 }
             }
+        }
 
-            // Reset alpha pool
-            self.alpha_pool = 0;
+        // ========================================================================
+        // State Repair Functions
+        // ========================================================================
+
+        /// Schedules a repair for an inconsistency reported by audit() (owner only).
+        /// Returns the repair id.
+        ///
+        /// Repairs queue independently of config changes and of each other. Each
+        /// can be executed after config_timelock_blocks; until then the owner can
+        /// withdraw it and validators can cancel it by consensus.
+        #[ink(message)]
+        pub fn schedule_repair(&mut self, action: RepairAction) -> Result<u64, Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if self.pending_repair_ids.len() >= MAX_PENDING_REPAIRS {
+                return Err(Error::TooManyPendingRepairs);
+            }
+
+            let repair_id = self.next_repair_id;
+            self.next_repair_id = self.next_repair_id.saturating_add(1);
+            let current_block = self.env().block_number();
+            let executable_at_block = current_block.saturating_add(self.config_timelock_blocks);
+            self.pending_repairs.insert(
+                repair_id,
+                &PendingRepair {
+                    action,
+                    scheduled_at_block: current_block,
+                    executable_at_block,
+                    cancel_votes: 0,
+                    cancel_voters: Vec::new(),
+                    whitelist_version: self.whitelist_version,
+                },
+            );
+            self.pending_repair_ids.push(repair_id);
+
+            self.env().emit_event(RepairScheduled {
+                repair_id,
+                action,
+                executable_at_block,
+            });
+            Ok(repair_id)
+        }
+
+        /// Applies a scheduled repair once its timelock has passed.
+        ///
+        /// PERMISSIONLESS - Anyone can call this function once conditions are met.
+        #[ink(message)]
+        pub fn execute_repair(&mut self, repair_id: u64) -> Result<(), Error> {
+            let pending = self
+                .pending_repairs
+                .get(repair_id)
+                .ok_or(Error::RepairNotFound)?;
+            if self.env().block_number() < pending.executable_at_block {
+                return Err(Error::RepairTimelocked);
+            }
+
+            self.remove_pending_repair(repair_id);
+            match pending.action {
+                RepairAction::DequeueIssue(issue_id) => self.repair_dequeue_issue(issue_id),
+                RepairAction::RequeueIssue(issue_id) => self.repair_requeue_issue(issue_id),
+                RepairAction::ReleaseExcessBounty(issue_id) => self.repair_excess_bounty(issue_id),
+                RepairAction::ReindexIssueUrl(issue_id) => self.repair_url_index(issue_id),
+                RepairAction::CanonicalizeIssueUrl(issue_id) => self.repair_url_hash(issue_id),
+            }
+            self.env().emit_event(RepairExecuted {
+                repair_id,
+                action: pending.action,
+            });
+            Ok(())
+        }
+
+        /// Withdraws a scheduled repair (owner only).
+        #[ink(message)]
+        pub fn cancel_repair(&mut self, repair_id: u64) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let pending = self
+                .pending_repairs
+                .get(repair_id)
+                .ok_or(Error::RepairNotFound)?;
+            self.remove_pending_repair(repair_id);
+            self.env().emit_event(RepairCancelled {
+                repair_id,
+                action: pending.action,
+                by_validators: false,
+            });
+            Ok(())
+        }
+
+        /// Votes to cancel a scheduled repair (whitelisted validators only).
+        /// The repair is dropped once the votes reach consensus.
+        ///
+        /// Votes from validators removed since they voted are dropped first.
+        #[ink(message)]
+        pub fn vote_cancel_repair(&mut self, repair_id: u64) -> Result<VoteTally, Error> {
+            let caller = self.validate_whitelisted_caller()?;
+            let mut pending = self
+                .pending_repairs
+                .get(repair_id)
+                .ok_or(Error::RepairNotFound)?;
+
+            pending.cancel_votes = self.reconcile_cancel_voters(
+                &mut pending.cancel_voters,
+                &mut pending.whitelist_version,
+            );
+            if pending.cancel_voters.contains(&caller) {
+                return Err(Error::AlreadyVoted);
+            }
+
+            self.touch_validator(caller);
+            pending.cancel_voters.push(caller);
+            pending.cancel_votes = pending.cancel_votes.saturating_add(1);
+
+            let tally = self.vote_tally(pending.cancel_votes);
+            if tally.consensus_reached {
+                self.remove_pending_repair(repair_id);
+                self.env().emit_event(RepairCancelled {
+                    repair_id,
+                    action: pending.action,
+                    by_validators: true,
+                });
+            } else {
+                self.pending_repairs.insert(repair_id, &pending);
+            }
+            Ok(tally)
+        }
+
+        /// Returns the scheduled repair with this id, if any
+        #[ink(message)]
+        pub fn get_pending_repair(&self, repair_id: u64) -> Option<PendingRepair> {
+            self.pending_repairs.get(repair_id)
+        }
+
+        /// Returns the ids of all scheduled repairs, oldest first
+        #[ink(message)]
+        pub fn get_pending_repair_ids(&self) -> Vec<u64> {
+            self.pending_repair_ids.clone()
+        }
+
+        /// Drops cancel votes from validators removed since the voters were last
+        /// checked. Returns the number of votes that still count.
+        fn reconcile_cancel_voters(
+            &self,
+            voters: &mut Vec<AccountId>,
+            whitelist_version: &mut u32,
+        ) -> u32 {
+            self.drop_removed_voters(voters, whitelist_version);
+            u32::try_from(voters.len()).unwrap_or(u32::MAX)
+        }
+
+        /// Drops a repair from the pending set.
+        fn remove_pending_repair(&mut self, repair_id: u64) {
+            self.pending_repairs.remove(repair_id);
+            self.pending_repair_ids.retain(|&id| id != repair_id);
+        }
+
+        /// Drops an issue's bounty_queue entries: all of them if the issue is
+        /// missing or finalized, all but the first otherwise.
+        fn repair_dequeue_issue(&mut self, issue_id: u64) {
+            let violation = match self.issues.get(issue_id) {
+                None => InvariantViolation::QueuedIssueMissing,
+                Some(issue) if !self.is_modifiable(issue.status) => {
+                    InvariantViolation::QueuedIssueFinalized
+                }
+                Some(_) => InvariantViolation::QueuedIssueDuplicate,
+            };
+            let keep = usize::from(violation == InvariantViolation::QueuedIssueDuplicate);
+            let before = self.bounty_queue.len();
+            let mut seen = 0usize;
+            self.bounty_queue.retain(|&id| {
+                if id != issue_id {
+                    return true;
+                }
+                seen = seen.saturating_add(1);
+                seen <= keep
+            });
+            if self.bounty_queue.len() != before {
+                self.env().emit_event(StateRepaired {
+                    issue_id,
+                    violation,
+                });
+            }
+        }
+
+        /// Puts a Registered issue missing from bounty_queue back at its tail.
+        fn repair_requeue_issue(&mut self, issue_id: u64) {
+            let registered = self
+                .issues
+                .get(issue_id)
+                .is_some_and(|issue| issue.status == IssueStatus::Registered);
+            if registered && !self.bounty_queue.contains(&issue_id) {
+                self.bounty_queue.push(issue_id);
+                self.env().emit_event(StateRepaired {
+                    issue_id,
+                    violation: InvariantViolation::RegisteredIssueNotQueued,
+                });
+            }
+        }
+
+        /// Returns bounty above a live issue's target to the alpha pool.
+        fn repair_excess_bounty(&mut self, issue_id: u64) {
+            let Some(mut issue) = self.issues.get(issue_id) else {
+                return;
+            };
+            if !self.is_modifiable(issue.status) || issue.bounty_amount <= issue.target_bounty {
+                return;
+            }
+            self.release_excess_bounty(issue_id, &mut issue);
+            self.save_issue(issue_id, &issue);
+            self.env().emit_event(StateRepaired {
+                issue_id,
+                violation: InvariantViolation::BountyExceedsTarget,
+            });
+        }
+
+        /// Points the issue's URL hash back at the issue.
+        fn repair_url_index(&mut self, issue_id: u64) {
+            let Some(issue) = self.issues.get(issue_id) else {
+                return;
+            };
+            if self.url_hash_to_id.get(issue.github_url_hash) != Some(issue_id) {
+                self.url_hash_to_id.insert(issue.github_url_hash, &issue_id);
+                self.env().emit_event(StateRepaired {
+                    issue_id,
+                    violation: InvariantViolation::UrlIndexMismatch,
+                });
+            }
+        }
+
+        /// Re-keys an issue registered before URL canonicalization under its
+        /// canonical URL hash. Leaves it alone if another issue already holds
+        /// that hash; the two are duplicates and one should be cancelled.
+        fn repair_url_hash(&mut self, issue_id: u64) {
+            let Some(mut issue) = self.issues.get(issue_id) else {
+                return;
+            };
+            let Some(url_hash) = self.legacy_url_rehash(&issue) else {
+                return;
+            };
+            if self.url_hash_to_id.get(url_hash).is_some() {
+                return;
+            }
+            if self.url_hash_to_id.get(issue.github_url_hash) == Some(issue_id) {
+                self.url_hash_to_id.remove(issue.github_url_hash);
+            }
+            self.url_hash_to_id.insert(url_hash, &issue_id);
+            issue.github_url_hash = url_hash;
+            self.save_issue(issue_id, &issue);
+            self.env().emit_event(StateRepaired {
+                issue_id,
+                violation: InvariantViolation::UrlHashNotCanonical,
+            });
+        }
+
+        /// Sets a new treasury hotkey.
+        ///
+        /// Resets bounty amounts to 0 for all Active/Registered issues since
+        /// the new treasury has no stake to back them. Issues remain in their
+        /// current status and will be re-funded on next harvest.
+        fn set_treasury_hotkey(&mut self, new_hotkey: AccountId) {
+            let old_hotkey = self.treasury_hotkey;
+
+            // Reset bounty amounts for all Active/Registered issues and the alpha pool
+            let (bounties_reset, issues_affected) = self.reset_funded_bounties();
 
             // Update treasury hotkey
             self.treasury_hotkey = new_hotkey;
@@ -416,7 +1773,359 @@ mod issue_bounty_manager {
                 bounties_reset,
                 issues_affected,
             });
+        }
+
+        /// Sets the minimum target bounty for newly registered issues.
+        #[ink(message)]
+        pub fn set_min_bounty(&mut self, min_bounty: Balance) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if min_bounty == 0 {
+                return Err(Error::InvalidConfig);
+            }
+            let old_value = self.min_bounty;
+            self.min_bounty = min_bounty;
+            self.emit_config_updated(ConfigField::MinBounty, old_value, min_bounty);
+            Ok(())
+        }
+
+        /// Sets the subnet ID.
+        ///
+        /// Like set_treasury_hotkey, resets bounty amounts for all Active/Registered
+        /// issues since stake on the old subnet no longer backs them.
+        fn set_netuid(&mut self, netuid: u16) {
+            let old_value = self.netuid;
+            self.reset_funded_bounties();
+            self.netuid = netuid;
+            self.emit_config_updated(ConfigField::Netuid, old_value.into(), netuid.into());
+        }
+
+        /// Sets the maximum number of whitelisted validators.
+        #[ink(message)]
+        pub fn set_max_validators(&mut self, max_validators: u32) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if max_validators == 0 || (max_validators as usize) < self.validators.len() {
+                return Err(Error::InvalidConfig);
+            }
+            let old_value = self.max_validators;
+            self.max_validators = max_validators;
+            self.emit_config_updated(
+                ConfigField::MaxValidators,
+                old_value.into(),
+                max_validators.into(),
+            );
+            Ok(())
+        }
+
+        /// Sets a fixed number of votes required for consensus.
+        /// None restores the default simple majority of active validators.
+        #[ink(message)]
+        pub fn set_required_validator_votes(&mut self, required: Option<u32>) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            self.ensure_quorum_unlocked()?;
+            if let Some(votes) = required {
+                if votes == 0 || votes > self.max_validators {
+                    return Err(Error::InvalidConfig);
+                }
+            }
+            let old_value = self.required_votes_override.unwrap_or(0);
+            self.required_votes_override = required;
+            self.emit_config_updated(
+                ConfigField::RequiredValidatorVotes,
+                old_value.into(),
+                required.unwrap_or(0).into(),
+            );
+            Ok(())
+        }
+
+        /// Sets the minimum number of votes any solution, cancel or config-cancel
+        /// proposal needs, on top of the majority or fixed requirement (owner only).
+        #[ink(message)]
+        pub fn set_min_votes_required(&mut self, votes: u32) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            self.ensure_quorum_unlocked()?;
+            if votes == 0 || votes > self.max_validators {
+                return Err(Error::InvalidConfig);
+            }
+            let old_value = self.min_votes_required;
+            self.min_votes_required = votes;
+            self.emit_config_updated(
+                ConfigField::MinVotesRequired,
+                old_value.into(),
+                votes.into(),
+            );
+            Ok(())
+        }
+
+        /// Sets the stake an applying coldkey must hold on a hotkey to whitelist
+        /// it via apply_as_validator; 0 closes self-service onboarding (owner only).
+        #[ink(message)]
+        pub fn set_min_validator_stake(&mut self, stake: Balance) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let old_value = self.min_validator_stake;
+            self.min_validator_stake = stake;
+            self.emit_config_updated(ConfigField::MinValidatorStake, old_value, stake);
+            Ok(())
+        }
+
+        /// Sets how many blocks of silence exclude a validator from the quorum.
+        #[ink(message)]
+        pub fn set_validator_inactivity_blocks(&mut self, blocks: u32) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            self.ensure_quorum_unlocked()?;
+            if blocks == 0 {
+                return Err(Error::InvalidConfig);
+            }
+            let old_value = self.validator_inactivity_blocks;
+            self.validator_inactivity_blocks = blocks;
+            self.emit_config_updated(
+                ConfigField::ValidatorInactivityBlocks,
+                old_value.into(),
+                blocks.into(),
+            );
+            Ok(())
+        }
+
+        /// Sets the treasury shortfall, in basis points of commitments, above
+        /// which harvesting pauses. 10_000 disables the check.
+        #[ink(message)]
+        pub fn set_stake_anomaly_threshold_bps(&mut self, bps: u32) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if bps > 10_000 {
+                return Err(Error::InvalidConfig);
+            }
+            let old_value = self.stake_anomaly_threshold_bps;
+            self.stake_anomaly_threshold_bps = bps;
+            self.emit_config_updated(
+                ConfigField::StakeAnomalyThresholdBps,
+                old_value.into(),
+                bps.into(),
+            );
+            Ok(())
+        }
+
+        /// Sets or clears the block by which a Registered issue must be fully
+        /// funded (owner only). Issues still unfunded at that block are cancelled
+        /// by the next harvest and their partial bounty returns to the pool.
+        #[ink(message)]
+        pub fn set_funding_deadline(
+            &mut self,
+            issue_id: u64,
+            deadline_block: Option<u32>,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+            if issue.status != IssueStatus::Registered {
+                return Err(Error::IssueNotRegistered);
+            }
+
+            match deadline_block {
+                Some(block) => {
+                    if block <= self.env().block_number() {
+                        return Err(Error::InvalidConfig);
+                    }
+                    if !self.funding_deadlines.contains(issue_id) {
+                        self.deadline_queue.push(issue_id);
+                    }
+                    self.funding_deadlines.insert(issue_id, &block);
+                }
+                None => {
+                    self.funding_deadlines.remove(issue_id);
+                    if let Some(pos) = self.deadline_queue.iter().position(|&id| id == issue_id) {
+                        self.deadline_queue.remove(pos);
+                    }
+                }
+            }
+
+            self.env().emit_event(FundingDeadlineSet {
+                issue_id,
+                deadline_block,
+            });
+            Ok(())
+        }
+
+        /// Returns the funding deadline block of an issue, if any
+        #[ink(message)]
+        pub fn get_funding_deadline(&self, issue_id: u64) -> Option<u32> {
+            self.funding_deadlines.get(issue_id)
+        }
+
+        /// Sets the subnet tempo used to convert the treasury's per-epoch emission
+        /// into a per-block rate (owner only). Must be between 1 and
+        /// MAX_EMISSION_TEMPO_BLOCKS.
+        ///
+        /// The chain extension does not expose the subnet's tempo, so this must
+        /// be kept in line with it by hand after the subnet's tempo changes.
+        #[ink(message)]
+        pub fn set_emission_tempo_blocks(&mut self, blocks: u32) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if blocks == 0 || blocks > MAX_EMISSION_TEMPO_BLOCKS {
+                return Err(Error::InvalidConfig);
+            }
+            let old_value = self.emission_tempo_blocks;
+            self.emission_tempo_blocks = blocks;
+            self.emit_config_updated(
+                ConfigField::EmissionTempoBlocks,
+                old_value.into(),
+                blocks.into(),
+            );
+            Ok(())
+        }
+
+        /// Sets the share, in basis points, of each harvest's unallocated remainder
+        /// that is recycled (owner only). The rest stays in the pool for future
+        /// bounties; later harvests apply the same share to whatever is left.
+        #[ink(message)]
+        pub fn set_recycle_bps(&mut self, bps: u32) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if bps > 10_000 {
+                return Err(Error::InvalidConfig);
+            }
+            let old_value = self.recycle_bps;
+            self.recycle_bps = bps;
+            self.emit_config_updated(ConfigField::RecycleBps, old_value.into(), bps.into());
+            Ok(())
+        }
 
+        /// Sets how many blocks after its first vote a solution or cancel proposal
+        /// stays open (owner only). Applies to proposals already open.
+        #[ink(message)]
+        pub fn set_vote_expiry_blocks(&mut self, blocks: u32) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if blocks == 0 {
+                return Err(Error::InvalidConfig);
+            }
+            let old_value = self.vote_expiry_blocks;
+            self.vote_expiry_blocks = blocks;
+            self.emit_config_updated(
+                ConfigField::VoteExpiryBlocks,
+                old_value.into(),
+                blocks.into(),
+            );
+            Ok(())
+        }
+
+        /// Turns per-allocation HarvestAllocation events on or off (owner only).
+        /// Allocations per harvest are bounded by max_fills_per_call.
+        #[ink(message)]
+        pub fn set_verbose_harvest(&mut self, enabled: bool) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let old_value = self.verbose_harvest;
+            self.verbose_harvest = enabled;
+            self.emit_config_updated(
+                ConfigField::VerboseHarvest,
+                old_value.into(),
+                enabled.into(),
+            );
+            Ok(())
+        }
+
+        /// Sets the share of each harvest, in basis points, that security-critical
+        /// issues may take ahead of FIFO order (0 disables the reservation).
+        #[ink(message)]
+        pub fn set_security_reserve_bps(&mut self, bps: u32) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if bps > 10_000 {
+                return Err(Error::InvalidConfig);
+            }
+            let old_value = self.security_reserve_bps;
+            self.security_reserve_bps = bps;
+            self.emit_config_updated(
+                ConfigField::SecurityReserveBps,
+                old_value.into(),
+                bps.into(),
+            );
+            Ok(())
+        }
+
+        /// Flags or unflags a Registered issue as security-critical (owner only).
+        /// Flagged issues are funded from the reserved share of each harvest
+        /// regardless of their FIFO position.
+        #[ink(message)]
+        pub fn set_security_critical(
+            &mut self,
+            issue_id: u64,
+            critical: bool,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+            if issue.status != IssueStatus::Registered {
+                return Err(Error::IssueNotRegistered);
+            }
+
+            if critical {
+                if !self.is_security_critical(issue_id) {
+                    self.security_critical.insert(issue_id, &true);
+                    self.security_queue.push(issue_id);
+                }
+            } else {
+                self.security_critical.remove(issue_id);
+                self.remove_from_security_queue(issue_id);
+            }
+
+            self.env()
+                .emit_event(SecurityCriticalSet { issue_id, critical });
+            Ok(())
+        }
+
+        /// Sets the maximum bounty queue entries processed per harvest.
+        #[ink(message)]
+        pub fn set_max_fills_per_call(&mut self, max_fills: u32) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if max_fills == 0 {
+                return Err(Error::InvalidConfig);
+            }
+            let old_value = self.max_fills_per_call;
+            self.max_fills_per_call = max_fills;
+            self.emit_config_updated(
+                ConfigField::MaxFillsPerCall,
+                old_value.into(),
+                max_fills.into(),
+            );
+            Ok(())
+        }
+
+        /// Acknowledges a detected stake anomaly and resumes harvesting (owner only).
+        #[ink(message)]
+        pub fn acknowledge_stake_anomaly(&mut self) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            if !self.harvest_paused {
+                return Err(Error::NoStakeAnomaly);
+            }
+            self.harvest_paused = false;
+            self.env()
+                .emit_event(StakeAnomalyAcknowledged { owner: self.owner });
             Ok(())
         }
 
@@ -428,20 +2137,64 @@ mod issue_bounty_manager {
         /// Uses chain extension to query Subtensor runtime.
         #[ink(message)]
         pub fn get_treasury_stake(&self) -> Balance {
-            let hotkey_bytes: [u8; 32] = *self.treasury_hotkey.as_ref();
-            let coldkey_bytes: [u8; 32] = *self.owner.as_ref();
-
-            let stake_info =
-                self.env()
-                    .extension()
-                    .get_stake_info(hotkey_bytes, coldkey_bytes, self.netuid);
-
-            match stake_info {
+            match self.treasury_stake_info() {
                 Some(info) => info.stake.0 as u128,
                 None => 0,
             }
         }
 
+        /// Returns treasury stake and emission from the chain alongside
+        /// harvest history, for economic monitoring in a single read.
+        #[ink(message)]
+        pub fn get_treasury_report(&self) -> TreasuryReport {
+            let (treasury_stake, chain_emission) = match self.treasury_stake_info() {
+                Some(info) => (info.stake.0 as u128, info.emission.0 as u128),
+                None => (0, 0),
+            };
+
+            TreasuryReport {
+                treasury_stake,
+                chain_emission,
+                total_harvested: self.total_harvested,
+                last_harvest_block: self.last_harvest_block,
+                recent_harvest_count: u32::try_from(self.recent_harvests.len()).unwrap_or(u32::MAX),
+                average_harvest: self.average_harvest(),
+                harvested_per_block: self.harvested_per_block(),
+            }
+        }
+
+        /// Returns the treasury hotkey's approximate emission per block, or None
+        /// if the chain reports no emission.
+        ///
+        /// The chain extension has no emission-rate query, so this is the last
+        /// epoch's StakeInfo.emission divided by the owner-set
+        /// emission_tempo_blocks. It reflects only the most recent epoch and is
+        /// off by the ratio of the two tempos if the setting is out of date.
+        #[ink(message)]
+        pub fn get_emission_rate(&self) -> Option<Balance> {
+            self.emission_per_block()
+        }
+
+        /// Returns cumulative accounting totals alongside the current
+        /// outstanding commitments and treasury stake.
+        #[ink(message)]
+        pub fn get_accounting(&self) -> AccountingTotals {
+            AccountingTotals {
+                total_harvested: self.total_harvested,
+                total_committed: self.total_committed,
+                total_paid: self.total_paid,
+                total_recycled: self.total_recycled,
+                outstanding_committed: self.get_total_committed(),
+                treasury_stake: self.get_treasury_stake(),
+            }
+        }
+
+        /// Returns true if the treasury stake covers all outstanding commitments.
+        #[ink(message)]
+        pub fn is_solvent(&self) -> bool {
+            self.get_treasury_stake() >= self.get_total_committed()
+        }
+
         /// Returns the block number of the last harvest.
         #[ink(message)]
         pub fn get_last_harvest_block(&self) -> u32 {
@@ -453,62 +2206,125 @@ mod issue_bounty_manager {
         /// PERMISSIONLESS - Anyone can call this function.
         ///
         /// Flow (Ground Truth Accounting):
-        /// 1. Query current stake on treasury hotkey (via chain extension)
-        /// 2. Calculate committed funds (sum of bounty_amount for Registered/Active issues)
-        /// 3. Available = current_stake - committed (ground truth, self-correcting)
-        /// 4. Fill pending bounties from available funds
-        /// 5. Recycle any remainder to owner's coldkey
-        /// 6. Update alpha_pool as read-only cache for UI
+        /// 1. Cancel Registered issues past their funding deadline, releasing
+        ///    their partial bounty so it counts as available below
+        /// 2. Query current stake on treasury hotkey (via chain extension)
+        /// 3. Calculate committed funds (sum of bounty_amount for Registered/Active issues)
+        /// 4. Pause if stake falls short of committed beyond the anomaly threshold
+        /// 5. Available = current_stake - committed (ground truth, self-correcting)
+        /// 6. Fill pending bounties from available funds
+        /// 7. Recycle recycle_bps of any remainder to owner's coldkey, unless the fill
+        ///    budget (max_fills_per_call) ran out; what is not recycled waits for the
+        ///    next harvest
+        /// 8. Update alpha_pool as read-only cache for UI
+        ///
+        /// Fails with HarvestPaused until the owner calls acknowledge_stake_anomaly.
         #[ink(message)]
         pub fn harvest_emissions(&mut self) -> Result<HarvestResult, Error> {
+            if self.harvest_paused {
+                return Err(Error::HarvestPaused);
+            }
+
+            let expired_issues = self.expire_funding_deadlines();
+
             // Query current total stake via chain extension
             let current_stake = self.get_treasury_stake();
 
             // Ground truth calculation: available = current_stake - committed
             let committed = self.get_total_committed();
+
+            // A large shortfall means stake left the treasury without a recorded
+            // payout. Pause rather than carry on as if nothing were available.
+            // Returns Ok so the pause and event persist.
+            if self.is_stake_anomaly(current_stake, committed) {
+                self.harvest_paused = true;
+                self.env().emit_event(StakeAnomalyDetected {
+                    current_stake,
+                    committed,
+                    shortfall: committed.saturating_sub(current_stake),
+                });
+                return Ok(HarvestResult {
+                    expired_issues,
+                    ..HarvestResult::default()
+                });
+            }
+
             let available = current_stake.saturating_sub(committed);
 
             if available == 0 {
                 // Update alpha_pool cache (should be 0 since nothing available)
                 self.alpha_pool = 0;
+                self.carried_over = 0;
+                self.returned_to_pool = 0;
                 return Ok(HarvestResult {
-                    harvested: 0,
-                    bounties_filled: 0,
-                    recycled: 0,
+                    expired_issues,
+                    ..HarvestResult::default()
                 });
             }
 
-            // Set alpha_pool to available funds for bounty filling
+            // Set alpha_pool to available funds for bounty filling. Funds carried
+            // over from the last harvest or returned to the pool since were
+            // already counted.
             self.alpha_pool = available;
-
-            // Fill bounties from available funds (returns list of fully-funded bounties)
-            let filled_bounties = self.fill_bounties();
-            let bounties_filled: u32 = u32::try_from(filled_bounties.len()).unwrap_or(u32::MAX);
+            let newly_harvested = available
+                .saturating_sub(self.carried_over)
+                .saturating_sub(self.returned_to_pool);
+            self.carried_over = 0;
+            self.returned_to_pool = 0;
+            self.total_harvested = self.total_harvested.saturating_add(newly_harvested);
+
+            // Fill bounties from available funds. The report lists exactly the
+            // allocations made in this pass, so each event is emitted once.
+            let report = self.fill_bounties();
+            let bounties_filled: u32 = u32::try_from(report.fully_filled.len()).unwrap_or(u32::MAX);
+            let bounties_partially_filled: u32 =
+                u32::try_from(report.partially_filled.len()).unwrap_or(u32::MAX);
+            let fills_pending = report.more_pending;
+
+            if self.verbose_harvest {
+                for fill in report.fully_filled.iter().chain(&report.partially_filled) {
+                    self.env().emit_event(HarvestAllocation {
+                        issue_id: fill.issue_id,
+                        amount: fill.amount,
+                    });
+                }
+            }
 
             // Emit BountyFilled event for each fully-funded bounty
-            for (issue_id, amount) in filled_bounties {
-                self.env().emit_event(BountyFilled { issue_id, amount });
+            for fill in report.fully_filled {
+                self.env().emit_event(BountyFilled {
+                    issue_id: fill.issue_id,
+                    repository_hash: fill.repository_hash,
+                    amount: fill.total,
+                    target_bounty: fill.target,
+                });
+            }
+
+            // Emit BountyPartiallyFilled so sponsors can track funding progress
+            for fill in report.partially_filled {
+                self.env().emit_event(BountyPartiallyFilled {
+                    issue_id: fill.issue_id,
+                    repository_hash: fill.repository_hash,
+                    amount: fill.amount,
+                    total: fill.total,
+                    target: fill.target,
+                });
             }
 
-            // Recycle any remaining alpha pool
-            let to_recycle = self.alpha_pool;
+            // Recycle the configured share of the remaining alpha pool, unless
+            // queued bounties still wait for it because the fill budget ran out
+            let to_recycle = if fills_pending {
+                0
+            } else {
+                self.alpha_pool.saturating_mul(u128::from(self.recycle_bps)) / 10_000
+            };
             let mut recycled: Balance = 0;
 
             if to_recycle > 0 {
-                let amount_u64: u64 = to_recycle.try_into().unwrap_or(u64::MAX);
-
-                let proxy_call = RawCall::proxied_recycle_alpha(
-                    &self.owner,
-                    &self.treasury_hotkey,
-                    amount_u64,
-                    self.netuid,
-                );
-
-                let result = self.env().call_runtime(&proxy_call);
-
-                if result.is_ok() {
+                if self.dispatch_recycle(to_recycle) {
                     recycled = to_recycle;
-                    self.alpha_pool = 0;
+                    self.alpha_pool = self.alpha_pool.saturating_sub(recycled);
+                    self.total_recycled = self.total_recycled.saturating_add(recycled);
 
                     self.env().emit_event(EmissionsRecycled {
                         amount: recycled,
@@ -522,7 +2338,16 @@ mod issue_bounty_manager {
                 }
             }
 
+            // Whatever was not allocated or recycled stays for the next harvest
+            let retained = self.alpha_pool;
+            self.carried_over = retained;
+            if retained > 0 {
+                self.env()
+                    .emit_event(EmissionsRetained { amount: retained });
+            }
+
             self.last_harvest_block = self.env().block_number();
+            self.record_harvest(newly_harvested);
 
             self.env().emit_event(EmissionsHarvested {
                 amount: available,
@@ -533,41 +2358,68 @@ mod issue_bounty_manager {
             Ok(HarvestResult {
                 harvested: available,
                 bounties_filled,
+                bounties_partially_filled,
                 recycled,
+                fills_pending,
+                expired_issues,
+                retained,
             })
         }
 
         /// Manual payout retry for cases where auto-payout failed.
         /// Uses solver determined by validator consensus, not caller-specified.
+        /// Fails without dispatching if the treasury stake cannot cover the payout.
         #[ink(message)]
         pub fn payout_bounty(&mut self, issue_id: u64) -> Result<Balance, Error> {
             if self.env().caller() != self.owner {
                 return Err(Error::NotOwner);
             }
 
-            let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+            self.settle_payout(issue_id)
+        }
 
+        /// Records the hash of the scoring config (repo multipliers, decay) used to
+        /// settle a completed issue (owner only). Write-once, so the payout can be
+        /// audited later against a frozen config.
+        #[ink(message)]
+        pub fn set_scoring_snapshot(
+            &mut self,
+            issue_id: u64,
+            snapshot_hash: [u8; 32],
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
             if issue.status != IssueStatus::Completed {
                 return Err(Error::BountyNotCompleted);
             }
-
-            if issue.bounty_amount == 0 {
-                return Err(Error::BountyAlreadyPaid);
+            if self.scoring_snapshots.contains(issue_id) {
+                return Err(Error::ScoringSnapshotAlreadySet);
             }
 
-            let solver_coldkey = issue.solver_coldkey.ok_or(Error::NoSolverSet)?;
-            let payout = issue.bounty_amount;
+            self.scoring_snapshots.insert(issue_id, &snapshot_hash);
+            self.env().emit_event(ScoringSnapshotRecorded {
+                issue_id,
+                snapshot_hash,
+            });
+            Ok(())
+        }
 
-            // Attempt payout
-            let result = self.execute_payout_internal(issue_id, solver_coldkey, payout)?;
+        /// Claims an unpaid bounty on a completed issue.
+        ///
+        /// Callable by the solver coldkey recorded at completion, or by the owner
+        /// as a fallback. Pays the recorded solver regardless of who calls.
+        #[ink(message)]
+        pub fn claim_bounty(&mut self, issue_id: u64) -> Result<Balance, Error> {
+            let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+            let caller = self.env().caller();
 
-            // Zero bounty_amount on success
-            if let Some(mut issue) = self.issues.get(issue_id) {
-                issue.bounty_amount = 0;
-                self.issues.insert(issue_id, &issue);
+            if caller != self.owner && issue.solver_coldkey != Some(caller) {
+                return Err(Error::NotSolver);
             }
 
-            Ok(result)
+            self.settle_payout(issue_id)
         }
 
         // ========================================================================
@@ -604,6 +2456,12 @@ mod issue_bounty_manager {
             self.alpha_pool
         }
 
+        /// Returns the id of the last dispatched stake movement (0 if none)
+        #[ink(message)]
+        pub fn get_stake_op_count(&self) -> u64 {
+            self.stake_op_count
+        }
+
         /// Returns an issue by ID
         #[ink(message)]
         pub fn get_issue(&self, issue_id: u64) -> Option<Issue> {
@@ -622,20 +2480,291 @@ mod issue_bounty_manager {
             self.bounty_queue.clone()
         }
 
-        /// Returns all issues with a given status
+        /// Returns the security-critical issues awaiting fill, oldest flag first
+        #[ink(message)]
+        pub fn get_security_queue(&self) -> Vec<u64> {
+            self.security_queue.clone()
+        }
+
+        /// Returns the share of the current alpha pool reserved for the security
+        /// queue. A harvest reserves this share of the pool after adding its
+        /// emissions; whatever the queued issues do not need goes to FIFO.
+        #[ink(message)]
+        pub fn get_security_reserve(&self) -> Balance {
+            self.security_reserve()
+        }
+
+        /// Returns the open solution candidates for an issue, in the order they
+        /// were first proposed. Voters removed from the whitelist are dropped at
+        /// the candidate's next vote.
+        #[ink(message)]
+        pub fn get_solution_votes(&self, issue_id: u64) -> Vec<SolutionVote> {
+            self.solution_votes.get(issue_id).unwrap_or_default()
+        }
+
+        /// Returns the open cancellation proposal for an issue, with its voters.
+        /// Voters removed from the whitelist are dropped at the next vote.
+        #[ink(message)]
+        pub fn get_cancel_vote(&self, issue_id: u64) -> Option<CancelVote> {
+            self.cancel_issue_votes.get(issue_id)
+        }
+
+        /// Returns true if `hotkey` has a vote recorded on the issue's open proposal
+        /// of the given kind, i.e. voting again would fail with AlreadyVoted.
+        #[ink(message)]
+        pub fn has_voted(&self, issue_id: u64, kind: VoteKind, hotkey: AccountId) -> bool {
+            match kind {
+                VoteKind::Solution => self.check_not_voted_solution(issue_id, hotkey).is_err(),
+                VoteKind::Cancel => self.check_not_voted_cancel_issue(issue_id, hotkey).is_err(),
+                VoteKind::BountyAdjustment => self
+                    .bounty_adjustment_voters
+                    .get((issue_id, hotkey))
+                    .unwrap_or(false),
+            }
+        }
+
+        /// Returns the cancellation reason for a cancelled issue
+        #[ink(message)]
+        pub fn get_cancellation(&self, issue_id: u64) -> Option<CancellationRecord> {
+            self.cancellations.get(issue_id)
+        }
+
+        /// Returns the scoring snapshot hash recorded for a completed issue
+        #[ink(message)]
+        pub fn get_scoring_snapshot(&self, issue_id: u64) -> Option<[u8; 32]> {
+            self.scoring_snapshots.get(issue_id)
+        }
+
+        /// Returns true if `url` hashes to the stored github_url_hash of the issue.
+        /// Lets clients display an off-chain URL without trusting its source.
+        #[ink(message)]
+        pub fn verify_url(&self, issue_id: u64, url: String) -> bool {
+            let url_hash = self.hash_string(&self.canonical_issue_url(&url));
+            self.issues
+                .get(issue_id)
+                .is_some_and(|issue| issue.github_url_hash == url_hash)
+        }
+
+        /// Returns the canonical form of an issue URL and its hash, exactly as
+        /// register_issue deduplicates it: scheme, host, owner and repo are
+        /// lowercased, and any query string, fragment and trailing slashes are
+        /// dropped.
+        #[ink(message)]
+        pub fn canonicalize_issue_url(&self, url: String) -> (String, [u8; 32]) {
+            let canonical = self.canonical_issue_url(&url);
+            let url_hash = self.hash_string(&canonical);
+            (canonical, url_hash)
+        }
+
+        /// Returns issues with a given status, scanning ids from start_id.
+        /// Examines at most max_scan ids (capped at MAX_STATUS_SCAN) and returns at
+        /// most max_items issues. Pass next_cursor as start_id to continue the scan.
         #[ink(message)]
-        pub fn get_issues_by_status(&self, status: IssueStatus) -> Vec<Issue> {
-            let mut result = Vec::new();
-            let mut issue_id = 1u64;
+        pub fn get_issues_by_status(
+            &self,
+            status: IssueStatus,
+            start_id: u64,
+            max_scan: u32,
+            max_items: u32,
+        ) -> IssuePage {
+            let mut page = IssuePage::default();
+            let mut issue_id = start_id.max(1);
+            let mut scanned = 0u32;
+            let max_scan = max_scan.min(MAX_STATUS_SCAN);
+
             while issue_id < self.next_issue_id {
+                if scanned >= max_scan || page.issues.len() >= max_items as usize {
+                    page.next_cursor = Some(issue_id);
+                    break;
+                }
+                scanned = scanned.saturating_add(1);
                 if let Some(issue) = self.issues.get(issue_id) {
                     if issue.status == status {
-                        result.push(issue);
+                        page.issues.push(issue);
+                    }
+                }
+                issue_id = issue_id.saturating_add(1);
+            }
+            page
+        }
+
+        /// Returns issues written at or after since_block, scanning ids from
+        /// start_id. Examines at most MAX_STATUS_SCAN ids and returns at most
+        /// max_items issues. Pass next_cursor as start_id to continue, and the
+        /// returned synced_at_block as since_block on the next sync; the boundary
+        /// block is included, so an issue may be returned twice but never missed.
+        ///
+        /// Open to any caller. Everything returned is public contract storage;
+        /// a caller check would not restrict it, since a dry-run can name any
+        /// caller and storage is readable directly.
+        #[ink(message)]
+        pub fn sync_state(&self, since_block: u32, start_id: u64, max_items: u32) -> StateSync {
+            let mut sync = StateSync {
+                synced_at_block: self.env().block_number(),
+                ..StateSync::default()
+            };
+            let mut issue_id = start_id.max(1);
+            let mut scanned = 0u32;
+
+            while issue_id < self.next_issue_id {
+                if scanned >= MAX_STATUS_SCAN || sync.issues.len() >= max_items as usize {
+                    sync.next_cursor = Some(issue_id);
+                    break;
+                }
+                scanned = scanned.saturating_add(1);
+                if let Some(issue) = self.issues.get(issue_id) {
+                    let updated_at = self
+                        .issue_updated_at
+                        .get(issue_id)
+                        .unwrap_or(issue.registered_at_block);
+                    if updated_at >= since_block {
+                        sync.issues.push(issue);
+                    }
+                }
+                issue_id = issue_id.saturating_add(1);
+            }
+            sync
+        }
+
+        /// Lists state inconsistencies so operators can find damage after an
+        /// incident. Scans at most MAX_STATUS_SCAN issue ids from start_id; pass
+        /// next_cursor as start_id to continue. bounty_queue entries are checked
+        /// on the first page (start_id 0 or 1) only.
+        #[ink(message)]
+        pub fn audit(&self, start_id: u64) -> AuditReport {
+            let mut report = AuditReport::default();
+            let mut queued = self.bounty_queue.clone();
+            queued.sort_unstable();
+
+            if start_id <= 1 {
+                for (i, &issue_id) in queued.iter().enumerate() {
+                    let violation = if i > 0 && queued[i - 1] == issue_id {
+                        InvariantViolation::QueuedIssueDuplicate
+                    } else {
+                        match self.issues.get(issue_id) {
+                            None => InvariantViolation::QueuedIssueMissing,
+                            Some(issue) if !self.is_modifiable(issue.status) => {
+                                InvariantViolation::QueuedIssueFinalized
+                            }
+                            Some(_) => continue,
+                        }
+                    };
+                    report.findings.push(AuditFinding {
+                        issue_id,
+                        violation,
+                    });
+                }
+            }
+
+            let mut issue_id = start_id.max(1);
+            let mut scanned = 0u32;
+            while issue_id < self.next_issue_id {
+                if scanned >= MAX_STATUS_SCAN {
+                    report.next_cursor = Some(issue_id);
+                    break;
+                }
+                scanned = scanned.saturating_add(1);
+                if let Some(issue) = self.issues.get(issue_id) {
+                    let mut flag = |violation| {
+                        report.findings.push(AuditFinding {
+                            issue_id,
+                            violation,
+                        })
+                    };
+                    if issue.status == IssueStatus::Registered
+                        && queued.binary_search(&issue_id).is_err()
+                    {
+                        flag(InvariantViolation::RegisteredIssueNotQueued);
+                    }
+                    if self.is_modifiable(issue.status) && issue.bounty_amount > issue.target_bounty
+                    {
+                        flag(InvariantViolation::BountyExceedsTarget);
+                    }
+                    if self.url_hash_to_id.get(issue.github_url_hash) != Some(issue_id) {
+                        flag(InvariantViolation::UrlIndexMismatch);
+                    }
+                    if self.legacy_url_rehash(&issue).is_some() {
+                        flag(InvariantViolation::UrlHashNotCanonical);
                     }
                 }
                 issue_id = issue_id.saturating_add(1);
             }
-            result
+            report
+        }
+
+        /// Returns true if the issue is flagged security-critical
+        #[ink(message)]
+        pub fn is_security_critical(&self, issue_id: u64) -> bool {
+            self.security_critical.get(issue_id).unwrap_or(false)
+        }
+
+        /// Returns the issue's position in the bounty queue (0 = next to be filled),
+        /// or None if the issue is not queued.
+        #[ink(message)]
+        pub fn get_queue_position(&self, issue_id: u64) -> Option<u32> {
+            self.queue_position(issue_id)
+        }
+
+        /// Returns how much alpha must be harvested before the issue receives funds
+        /// (the unfunded remainder of every issue ahead of it), or None if not queued.
+        /// Combine with get_funding_progress for an estimate in harvests.
+        #[ink(message)]
+        pub fn get_alpha_ahead(&self, issue_id: u64) -> Option<Balance> {
+            self.queue_position(issue_id)?;
+            Some(self.alpha_ahead_of(issue_id))
+        }
+
+        /// Returns funding progress for an issue, including its queue position
+        /// and an estimate of harvests needed to reach its target.
+        #[ink(message)]
+        pub fn get_funding_progress(&self, issue_id: u64) -> Option<FundingProgress> {
+            let issue = self.issues.get(issue_id)?;
+
+            let target = issue.target_bounty;
+            let funded = issue.bounty_amount;
+            let percent_bps = funded
+                .saturating_mul(10_000)
+                .checked_div(target)
+                .map_or(0, |bps| u32::try_from(bps.min(10_000)).unwrap_or(10_000));
+
+            let queue_position = self.queue_position(issue_id);
+            let (estimated_harvests_to_full, estimated_blocks_to_full) = match queue_position {
+                Some(_) => {
+                    let needed = self
+                        .alpha_ahead_of(issue_id)
+                        .saturating_add(target.saturating_sub(funded));
+                    (self.estimate_harvests(needed), self.estimate_blocks(needed))
+                }
+                None => (None, None),
+            };
+
+            Some(FundingProgress {
+                target,
+                funded,
+                percent_bps,
+                queue_position,
+                estimated_harvests_to_full,
+                estimated_blocks_to_full,
+            })
+        }
+
+        /// Returns the pallet indices, call indices, proxy types and chain
+        /// extension ids this build encodes, for comparison against the live
+        /// runtime before deployment.
+        #[ink(message)]
+        pub fn get_runtime_expectations(&self) -> RuntimeExpectations {
+            RuntimeExpectations {
+                subtensor_module_pallet_index: runtime_calls::SUBTENSOR_MODULE_PALLET_INDEX,
+                proxy_pallet_index: runtime_calls::PROXY_PALLET_INDEX,
+                proxy_call_index: runtime_calls::PROXY_CALL_INDEX,
+                transfer_stake_call_index: runtime_calls::TRANSFER_STAKE_CALL_INDEX,
+                recycle_alpha_call_index: runtime_calls::RECYCLE_ALPHA_CALL_INDEX,
+                proxy_type_transfer: runtime_calls::PROXY_TYPE_TRANSFER,
+                proxy_type_non_critical: runtime_calls::PROXY_TYPE_NON_CRITICAL,
+                chain_extension_id: crate::SUBTENSOR_EXTENSION_ID,
+                get_stake_info_func_id: crate::GET_STAKE_INFO_FUNC_ID,
+                netuid: self.netuid,
+            }
         }
 
         /// Returns all contract configuration in a single call.
@@ -643,7 +2772,25 @@ mod issue_bounty_manager {
         pub fn get_config(&self) -> ContractConfig {
             ContractConfig {
                 required_validator_votes: self.required_validator_votes(),
+                required_validator_votes_override: self.required_votes_override,
+                min_votes_required: self.min_votes_required,
+                min_validator_stake: self.min_validator_stake,
                 netuid: self.netuid,
+                min_bounty: self.min_bounty,
+                validator_count: u32::try_from(self.validators.len()).unwrap_or(u32::MAX),
+                max_validators: self.max_validators,
+                validator_inactivity_blocks: self.validator_inactivity_blocks,
+                stake_anomaly_threshold_bps: self.stake_anomaly_threshold_bps,
+                harvest_paused: self.harvest_paused,
+                max_fills_per_call: self.max_fills_per_call,
+                security_reserve_bps: self.security_reserve_bps,
+                recycle_bps: self.recycle_bps,
+                repo_allowlist_enabled: self.repo_allowlist_enabled,
+                emission_tempo_blocks: self.emission_tempo_blocks,
+                verbose_harvest: self.verbose_harvest,
+                vote_expiry_blocks: self.vote_expiry_blocks,
+                config_timelock_blocks: self.config_timelock_blocks,
+                owner_recovery_timelock_blocks: self.owner_recovery_timelock_blocks,
             }
         }
 
@@ -672,6 +2819,24 @@ mod issue_bounty_manager {
             Ok(())
         }
 
+        /// Brings a proposal's voters up to the current whitelist version, removing
+        /// validators no longer whitelisted. Returns the removed voters.
+        fn drop_removed_voters(
+            &self,
+            voters: &mut Vec<AccountId>,
+            whitelist_version: &mut u32,
+        ) -> Vec<AccountId> {
+            if *whitelist_version == self.whitelist_version {
+                return Vec::new();
+            }
+            *whitelist_version = self.whitelist_version;
+            let (kept, removed) = voters
+                .iter()
+                .partition(|voter| self.validators.contains(voter));
+            *voters = kept;
+            removed
+        }
+
         /// Checks if caller has already voted to cancel an issue.
         fn check_not_voted_cancel_issue(
             &self,
@@ -688,7 +2853,7 @@ mod issue_bounty_manager {
             Ok(())
         }
 
-        /// Gets existing solution vote or creates a new one.
+        /// Gets the existing vote for this solution candidate or creates a new one.
         fn get_or_create_solution_vote(
             &mut self,
             issue_id: u64,
@@ -696,7 +2861,13 @@ mod issue_bounty_manager {
             pr_number: u32,
             solver_coldkey: AccountId,
         ) -> SolutionVote {
-            if let Some(vote) = self.solution_votes.get(issue_id) {
+            if let Some(vote) = self
+                .solution_votes
+                .get(issue_id)
+                .unwrap_or_default()
+                .into_iter()
+                .find(|v| v.is_candidate(solver_hotkey, solver_coldkey, pr_number))
+            {
                 vote
             } else {
                 SolutionVote {
@@ -705,6 +2876,9 @@ mod issue_bounty_manager {
                     solver_coldkey,
                     pr_number,
                     votes_count: 0,
+                    voters: Vec::new(),
+                    whitelist_version: self.whitelist_version,
+                    vote_started_at_block: self.env().block_number(),
                 }
             }
         }
@@ -714,6 +2888,8 @@ mod issue_bounty_manager {
             &mut self,
             issue_id: u64,
             reason_hash: [u8; 32],
+            reason: Option<String>,
+            destination: CancelDestination,
         ) -> CancelVote {
             if let Some(vote) = self.cancel_issue_votes.get(issue_id) {
                 vote
@@ -721,9 +2897,215 @@ mod issue_bounty_manager {
                 CancelVote {
                     issue_id,
                     reason_hash,
+                    reason,
+                    destination,
                     votes_count: 0,
+                    voters: Vec::new(),
+                    whitelist_version: self.whitelist_version,
+                    vote_started_at_block: self.env().block_number(),
+                }
+            }
+        }
+
+        /// True if a proposal started at `started_at` is past vote_expiry_blocks.
+        fn is_vote_expired(&self, started_at: u32) -> bool {
+            self.env().block_number().saturating_sub(started_at) > self.vote_expiry_blocks
+        }
+
+        /// Removes expired solution candidates for an issue, releasing their
+        /// voters. Returns the number of candidates removed.
+        fn clear_expired_solution_votes(&mut self, issue_id: u64) -> u32 {
+            let Some(candidates) = self.solution_votes.get(issue_id) else {
+                return 0;
+            };
+            let (expired, open): (Vec<SolutionVote>, Vec<SolutionVote>) = candidates
+                .into_iter()
+                .partition(|vote| self.is_vote_expired(vote.vote_started_at_block));
+            if expired.is_empty() {
+                return 0;
+            }
+
+            for vote in &expired {
+                for voter in &vote.voters {
+                    self.solution_vote_voters.remove((issue_id, *voter));
                 }
+                self.env().emit_event(VoteExpired {
+                    issue_id,
+                    kind: VoteKind::Solution,
+                    votes_count: vote.votes_count,
+                });
+            }
+            if open.is_empty() {
+                self.clear_solution_vote(issue_id);
+            } else {
+                self.solution_votes.insert(issue_id, &open);
+            }
+            u32::try_from(expired.len()).unwrap_or(u32::MAX)
+        }
+
+        /// Removes an issue's cancel proposal if it has expired, releasing its
+        /// voters. Returns true if a proposal was removed.
+        fn clear_expired_cancel_vote(&mut self, issue_id: u64) -> bool {
+            let Some(vote) = self.cancel_issue_votes.get(issue_id) else {
+                return false;
+            };
+            if !self.is_vote_expired(vote.vote_started_at_block) {
+                return false;
+            }
+
+            for voter in &vote.voters {
+                self.cancel_issue_voters.remove((issue_id, *voter));
+            }
+            self.clear_cancel_issue_vote(issue_id);
+            self.env().emit_event(VoteExpired {
+                issue_id,
+                kind: VoteKind::Cancel,
+                votes_count: vote.votes_count,
+            });
+            true
+        }
+
+        /// Removes an issue proposal if it has expired.
+        fn clear_expired_issue_proposal(&mut self, url_hash: [u8; 32]) {
+            if self
+                .issue_proposals
+                .get(url_hash)
+                .is_some_and(|proposal| self.is_vote_expired(proposal.vote_started_at_block))
+            {
+                self.issue_proposals.remove(url_hash);
+            }
+        }
+
+        /// Stores an issue proposal after a vote and registers the issue once
+        /// consensus is reached, crediting the proposer as registrar.
+        fn tally_issue_proposal(&mut self, proposal: IssueProposal) -> VoteTally {
+            let tally = self.vote_tally(proposal.votes_count);
+            if tally.consensus_reached {
+                self.issue_proposals.remove(proposal.github_url_hash);
+                self.create_issue(
+                    proposal.github_url_hash,
+                    proposal.repository_full_name,
+                    proposal.issue_number,
+                    proposal.target_bounty,
+                    None,
+                    None,
+                    proposal.proposer,
+                );
+            } else {
+                self.issue_proposals
+                    .insert(proposal.github_url_hash, &proposal);
+            }
+            tally
+        }
+
+        /// Removes an issue's bounty adjustment proposal if it has expired,
+        /// releasing its voters. Returns true if a proposal was removed.
+        fn clear_expired_bounty_adjustment(&mut self, issue_id: u64) -> bool {
+            let Some(adjustment) = self.bounty_adjustments.get(issue_id) else {
+                return false;
+            };
+            if !self.is_vote_expired(adjustment.vote_started_at_block) {
+                return false;
+            }
+
+            self.clear_bounty_adjustment(issue_id, &adjustment);
+            self.env().emit_event(VoteExpired {
+                issue_id,
+                kind: VoteKind::BountyAdjustment,
+                votes_count: adjustment.votes_count,
+            });
+            true
+        }
+
+        /// Removes a bounty adjustment proposal and its voter flags, so the
+        /// issue can take a fresh proposal.
+        fn clear_bounty_adjustment(&mut self, issue_id: u64, adjustment: &BountyAdjustment) {
+            for voter in &adjustment.voters {
+                self.bounty_adjustment_voters.remove((issue_id, *voter));
+            }
+            self.bounty_adjustments.remove(issue_id);
+        }
+
+        /// Stores a bounty adjustment after a vote and applies it once consensus
+        /// is reached.
+        fn tally_bounty_adjustment(
+            &mut self,
+            adjustment: BountyAdjustment,
+        ) -> Result<VoteTally, Error> {
+            let issue_id = adjustment.issue_id;
+            let tally = self.vote_tally(adjustment.votes_count);
+            if tally.consensus_reached {
+                self.clear_bounty_adjustment(issue_id, &adjustment);
+                self.apply_bounty_adjustment(issue_id, adjustment.new_target)?;
+            } else {
+                self.bounty_adjustments.insert(issue_id, &adjustment);
             }
+            Ok(tally)
+        }
+
+        /// Sets a Registered issue's target bounty. Bounty above the new target
+        /// returns to the alpha pool; an issue left fully funded becomes Active.
+        fn apply_bounty_adjustment(
+            &mut self,
+            issue_id: u64,
+            new_target: Balance,
+        ) -> Result<(), Error> {
+            let mut issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+            let old_target = issue.target_bounty;
+            issue.target_bounty = new_target;
+            let (released, activated) = self.release_excess_bounty(issue_id, &mut issue);
+            self.save_issue(issue_id, &issue);
+
+            self.env().emit_event(BountyAdjusted {
+                issue_id,
+                old_target,
+                new_target,
+                released,
+                activated,
+            });
+            Ok(())
+        }
+
+        /// Returns bounty above the issue's target to the alpha pool and activates
+        /// a Registered issue that is now fully funded. The caller saves the
+        /// issue. Returns (released, activated).
+        fn release_excess_bounty(&mut self, issue_id: u64, issue: &mut Issue) -> (Balance, bool) {
+            let released = issue.bounty_amount.saturating_sub(issue.target_bounty);
+            issue.bounty_amount = issue.bounty_amount.saturating_sub(released);
+            self.return_to_pool(released);
+
+            let activated = issue.status == IssueStatus::Registered
+                && issue.bounty_amount >= issue.target_bounty;
+            if activated {
+                issue.status = IssueStatus::Active;
+                self.dequeue_issue(issue_id);
+            }
+            (released, activated)
+        }
+
+        /// Rejects cancellation reasons longer than MAX_CANCEL_REASON_LEN bytes.
+        fn validate_cancel_reason(&self, reason: &Option<String>) -> Result<(), Error> {
+            match reason {
+                Some(r) if r.len() > MAX_CANCEL_REASON_LEN => Err(Error::ReasonTooLong),
+                _ => Ok(()),
+            }
+        }
+
+        /// Stores the cancellation reason for a cancelled issue.
+        fn record_cancellation(
+            &mut self,
+            issue_id: u64,
+            reason_hash: [u8; 32],
+            reason: Option<String>,
+        ) {
+            self.cancellations.insert(
+                issue_id,
+                &CancellationRecord {
+                    reason_hash,
+                    reason,
+                    cancelled_at_block: self.env().block_number(),
+                },
+            );
         }
 
         /// Clears issue cancel vote data
@@ -757,9 +3139,60 @@ mod issue_bounty_manager {
             }
         }
 
-        /// Checks if an issue status allows modification
-        fn is_modifiable(&self, status: IssueStatus) -> bool {
-            matches!(status, IssueStatus::Registered | IssueStatus::Active)
+        /// Checks if an issue status allows modification
+        fn is_modifiable(&self, status: IssueStatus) -> bool {
+            matches!(status, IssueStatus::Registered | IssueStatus::Active)
+        }
+
+        /// Allow-list key for a repository: hash of its lowercased name, since
+        /// GitHub repository names are case-insensitive.
+        fn repo_allowlist_key(&self, repository_full_name: &str) -> [u8; 32] {
+            self.hash_string(&repository_full_name.to_ascii_lowercase())
+        }
+
+        /// True if the repository is on the allow-list
+        fn repo_allowed(&self, repository_full_name: &str) -> bool {
+            self.allowed_repositories
+                .get(self.repo_allowlist_key(repository_full_name))
+                .unwrap_or(false)
+        }
+
+        /// Canonical issue URL used for deduplication (see canonicalize_issue_url).
+        fn canonical_issue_url(&self, url: &str) -> String {
+            let end = url.find(['?', '#']).unwrap_or(url.len());
+            let url = url[..end].trim_end_matches('/');
+
+            // Lowercase up to the separator after the repo segment:
+            // "scheme://host/owner/repo" | "/issues/N"
+            let path_start = url.find("://").map_or(0, |i| i.saturating_add(3));
+            let case_insensitive_end = url
+                .bytes()
+                .enumerate()
+                .skip(path_start)
+                .filter(|&(_, b)| b == b'/')
+                .nth(2)
+                .map_or(url.len(), |(i, _)| i);
+
+            let mut canonical = url[..case_insensitive_end].to_ascii_lowercase();
+            canonical.push_str(&url[case_insensitive_end..]);
+            canonical
+        }
+
+        /// Returns the canonical URL hash for an issue still keyed by the hash
+        /// of the URL the CLI registered it under before canonicalization
+        /// (https://github.com/{repository_full_name}/issues/{issue_number}),
+        /// or None if the issue needs no migration.
+        fn legacy_url_rehash(&self, issue: &Issue) -> Option<[u8; 32]> {
+            let url = ink::prelude::format!(
+                "https://github.com/{}/issues/{}",
+                issue.repository_full_name,
+                issue.issue_number
+            );
+            if self.hash_string(&url) != issue.github_url_hash {
+                return None;
+            }
+            let url_hash = self.hash_string(&self.canonical_issue_url(&url));
+            (url_hash != issue.github_url_hash).then_some(url_hash)
         }
 
         /// Hashes a string to [u8; 32] using keccak256
@@ -771,13 +3204,31 @@ mod issue_bounty_manager {
         }
 
         /// Fills bounties from the alpha pool using FIFO order.
-        /// Issues are filled in registration order (first registered = first filled).
-        /// Returns a list of (issue_id, bounty_amount) for each fully-funded bounty.
-        fn fill_bounties(&mut self) -> Vec<(u64, Balance)> {
+        /// Issues are filled in registration order (first registered = first filled),
+        /// after security-critical issues take up to security_reserve_bps of the pool.
+        /// Returns a report of every allocation made, split into fully and partially filled.
+        ///
+        /// Processes at most max_fills_per_call queue entries across both queues.
+        /// Processed entries are removed from the front of the queue (a partial
+        /// fill exhausts the pool), so the queue head is the resume cursor for
+        /// the next pass.
+        fn fill_bounties(&mut self) -> FillReport {
             let mut i = 0usize;
-            let mut filled: Vec<(u64, Balance)> = Vec::new();
+            let mut steps = 0u32;
+            let mut report = FillReport::default();
+
+            self.fill_security_reserve(&mut steps, &mut report);
+            if report.more_pending {
+                return report;
+            }
 
             while i < self.bounty_queue.len() && self.alpha_pool > 0 {
+                if steps >= self.max_fills_per_call {
+                    report.more_pending = true;
+                    break;
+                }
+                steps = steps.saturating_add(1);
+
                 let issue_id = self.bounty_queue[i];
 
                 if let Some(mut issue) = self.issues.get(issue_id) {
@@ -792,24 +3243,11 @@ mod issue_bounty_manager {
                         continue;
                     }
 
-                    let fill_amount = if remaining < self.alpha_pool {
-                        remaining
-                    } else {
-                        self.alpha_pool
-                    };
-
-                    issue.bounty_amount = issue.bounty_amount.saturating_add(fill_amount);
-                    self.alpha_pool = self.alpha_pool.saturating_sub(fill_amount);
-
-                    let is_fully_funded = issue.bounty_amount >= issue.target_bounty;
-
-                    if is_fully_funded {
-                        issue.status = IssueStatus::Active;
-                        self.issues.insert(issue_id, &issue);
-                        filled.push((issue_id, issue.bounty_amount));
+                    let fill_amount = remaining.min(self.alpha_pool);
+                    if self.allocate_fill(issue_id, &mut issue, fill_amount, &mut report) {
                         self.remove_at(i);
+                        self.remove_from_security_queue(issue_id);
                     } else {
-                        self.issues.insert(issue_id, &issue);
                         i = i.saturating_add(1);
                     }
                 } else {
@@ -817,11 +3255,253 @@ mod issue_bounty_manager {
                 }
             }
 
-            filled
+            report
+        }
+
+        /// Cancels Registered issues whose funding deadline has passed, sending any
+        /// partial bounty to the pool. Returns the number cancelled.
+        fn expire_funding_deadlines(&mut self) -> u32 {
+            let current_block = self.env().block_number();
+            let reason = String::from(FUNDING_DEADLINE_REASON);
+            let reason_hash = self.hash_string(&reason);
+            let mut expired = 0u32;
+            let mut j = 0usize;
+
+            while j < self.deadline_queue.len() {
+                let issue_id = self.deadline_queue[j];
+                let registered = self
+                    .issues
+                    .get(issue_id)
+                    .is_some_and(|issue| issue.status == IssueStatus::Registered);
+                let deadline = self.funding_deadlines.get(issue_id).unwrap_or(0);
+
+                if registered && current_block < deadline {
+                    j = j.saturating_add(1);
+                    continue;
+                }
+
+                self.deadline_queue.remove(j);
+                self.funding_deadlines.remove(issue_id);
+                if registered
+                    && self
+                        .execute_cancel_issue(
+                            issue_id,
+                            reason_hash,
+                            Some(reason.clone()),
+                            CancelDestination::Pool,
+                        )
+                        .is_ok()
+                {
+                    expired = expired.saturating_add(1);
+                }
+            }
+
+            expired
+        }
+
+        /// Funds security-critical issues, oldest flag first, from at most
+        /// security_reserve_bps of the alpha pool. Fully funded issues leave both
+        /// queues; finalized or unknown issues are dropped from security_queue.
+        fn fill_security_reserve(&mut self, steps: &mut u32, report: &mut FillReport) {
+            let mut reserve = self.security_reserve();
+            let mut j = 0usize;
+
+            while j < self.security_queue.len() && reserve > 0 {
+                if *steps >= self.max_fills_per_call {
+                    report.more_pending = true;
+                    return;
+                }
+                *steps = steps.saturating_add(1);
+
+                let issue_id = self.security_queue[j];
+                let Some(mut issue) = self.issues.get(issue_id) else {
+                    self.security_queue.remove(j);
+                    continue;
+                };
+                let remaining = issue.target_bounty.saturating_sub(issue.bounty_amount);
+                if !self.is_modifiable(issue.status) || remaining == 0 {
+                    self.security_queue.remove(j);
+                    continue;
+                }
+
+                let fill_amount = remaining.min(reserve);
+                reserve = reserve.saturating_sub(fill_amount);
+                if self.allocate_fill(issue_id, &mut issue, fill_amount, report) {
+                    self.dequeue_issue(issue_id);
+                } else {
+                    j = j.saturating_add(1);
+                }
+            }
+        }
+
+        /// Moves fill_amount from the alpha pool into the issue's bounty and records
+        /// the allocation. Activates the issue and returns true once fully funded.
+        fn allocate_fill(
+            &mut self,
+            issue_id: u64,
+            issue: &mut Issue,
+            fill_amount: Balance,
+            report: &mut FillReport,
+        ) -> bool {
+            issue.bounty_amount = issue.bounty_amount.saturating_add(fill_amount);
+            self.alpha_pool = self.alpha_pool.saturating_sub(fill_amount);
+            self.total_committed = self.total_committed.saturating_add(fill_amount);
+
+            let is_fully_funded = issue.bounty_amount >= issue.target_bounty;
+            let fill = BountyFill {
+                issue_id,
+                repository_hash: self.hash_string(&issue.repository_full_name),
+                amount: fill_amount,
+                total: issue.bounty_amount,
+                target: issue.target_bounty,
+            };
+
+            if is_fully_funded {
+                issue.status = IssueStatus::Active;
+                report.fully_filled.push(fill);
+            } else {
+                report.partially_filled.push(fill);
+            }
+            self.save_issue(issue_id, issue);
+            is_fully_funded
+        }
+
+        /// Zeroes bounty_amount on every Registered/Active issue and resets the
+        /// alpha pool. Returns (total reset, issues affected).
+        fn reset_funded_bounties(&mut self) -> (Balance, u32) {
+            let mut bounties_reset: u128 = 0;
+            let mut issues_affected: u32 = 0;
+
+            for issue_id in 1..self.next_issue_id {
+                if let Some(mut issue) = self.issues.get(issue_id) {
+                    if self.is_modifiable(issue.status) && issue.bounty_amount > 0 {
+                        bounties_reset = bounties_reset.saturating_add(issue.bounty_amount);
+                        issues_affected = issues_affected.saturating_add(1);
+                        issue.bounty_amount = 0;
+                        self.save_issue(issue_id, &issue);
+                    }
+                }
+            }
+
+            self.alpha_pool = 0;
+            self.carried_over = 0;
+            self.returned_to_pool = 0;
+            (bounties_reset, issues_affected)
+        }
+
+        /// Emits ConfigUpdated for an owner configuration change.
+        fn emit_config_updated(&self, field: ConfigField, old_value: u128, new_value: u128) {
+            self.env().emit_event(ConfigUpdated {
+                field,
+                old_value,
+                new_value,
+            });
+        }
+
+        /// Returns the index of an issue in the bounty queue.
+        fn queue_position(&self, issue_id: u64) -> Option<u32> {
+            self.bounty_queue
+                .iter()
+                .position(|&id| id == issue_id)
+                .map(|pos| u32::try_from(pos).unwrap_or(u32::MAX))
+        }
+
+        /// Sums the unfunded remainder of every modifiable issue queued ahead of `issue_id`.
+        /// This is how much alpha must be harvested before the issue receives funds.
+        fn alpha_ahead_of(&self, issue_id: u64) -> Balance {
+            let mut ahead: Balance = 0;
+            for &id in self.bounty_queue.iter() {
+                if id == issue_id {
+                    break;
+                }
+                if let Some(issue) = self.issues.get(id) {
+                    if self.is_modifiable(issue.status) {
+                        ahead = ahead.saturating_add(
+                            issue.target_bounty.saturating_sub(issue.bounty_amount),
+                        );
+                    }
+                }
+            }
+            ahead
+        }
+
+        /// Records a harvest amount, keeping only the last HARVEST_HISTORY_LEN entries.
+        fn record_harvest(&mut self, amount: Balance) {
+            if self.recent_harvests.len() >= HARVEST_HISTORY_LEN {
+                self.recent_harvests.remove(0);
+            }
+            self.recent_harvests.push(HarvestRecord {
+                block: self.env().block_number(),
+                amount,
+            });
+        }
+
+        /// Average of the recorded harvests, 0 if there is no history.
+        fn average_harvest(&self) -> Balance {
+            let count = self.recent_harvests.len() as u128;
+            let total = self
+                .recent_harvests
+                .iter()
+                .fold(0u128, |acc, record| acc.saturating_add(record.amount));
+            total.checked_div(count).unwrap_or(0)
+        }
+
+        /// Alpha harvested per block across the recent history. The oldest
+        /// record only marks the window start, since its amount accrued before it.
+        fn harvested_per_block(&self) -> Option<Balance> {
+            let first = self.recent_harvests.first()?;
+            let last = self.recent_harvests.last()?;
+            let blocks = u128::from(last.block.saturating_sub(first.block));
+            let harvested = self
+                .recent_harvests
+                .iter()
+                .skip(1)
+                .fold(0u128, |acc, record| acc.saturating_add(record.amount));
+            harvested.checked_div(blocks)
+        }
+
+        /// Treasury emission per block: the chain's per-epoch emission for the
+        /// treasury hotkey spread over emission_tempo_blocks.
+        fn emission_per_block(&self) -> Option<Balance> {
+            let emission = u128::from(self.treasury_stake_info()?.emission.0);
+            if emission == 0 {
+                return None;
+            }
+            emission.checked_div(u128::from(self.emission_tempo_blocks))
+        }
+
+        /// Blocks of emission needed to cover `needed` (rounded up), using the
+        /// chain emission rate or, failing that, the observed harvest rate.
+        fn estimate_blocks(&self, needed: Balance) -> Option<u32> {
+            if needed == 0 {
+                return Some(0);
+            }
+            let rate = self
+                .emission_per_block()
+                .or_else(|| self.harvested_per_block())
+                .filter(|rate| *rate > 0)?;
+            let blocks = needed.saturating_add(rate - 1) / rate;
+            Some(u32::try_from(blocks).unwrap_or(u32::MAX))
+        }
+
+        /// Number of average-sized harvests needed to cover `needed` (rounded up).
+        fn estimate_harvests(&self, needed: Balance) -> Option<u32> {
+            if needed == 0 {
+                return Some(0);
+            }
+            let average = self.average_harvest();
+            if average == 0 {
+                return None;
+            }
+            let harvests = needed.saturating_add(average - 1) / average;
+            Some(u32::try_from(harvests).unwrap_or(u32::MAX))
         }
 
         /// Helper to remove from bounty queue at index, preserving FIFO order.
-        /// Uses Vec::remove which shifts remaining elements left.
+        /// Uses Vec::remove which shifts remaining elements left: O(n) in queue
+        /// length, where swap_remove would be O(1) but let a later issue jump
+        /// ahead of earlier ones. fill_bounties bounds removals per call by
+        /// max_fills_per_call, so fairness is kept at a bounded cost.
         fn remove_at(&mut self, idx: usize) {
             if idx < self.bounty_queue.len() {
                 self.bounty_queue.remove(idx);
@@ -835,6 +3515,127 @@ mod issue_bounty_manager {
             }
         }
 
+        /// Removes an issue from the security queue, preserving flag order.
+        fn remove_from_security_queue(&mut self, issue_id: u64) {
+            if let Some(pos) = self.security_queue.iter().position(|&id| id == issue_id) {
+                self.security_queue.remove(pos);
+            }
+        }
+
+        /// Removes a finalized or fully funded issue from both funding queues.
+        fn dequeue_issue(&mut self, issue_id: u64) {
+            self.remove_from_bounty_queue(issue_id);
+            self.remove_from_security_queue(issue_id);
+        }
+
+        /// Share of the alpha pool reserved for security_queue
+        fn security_reserve(&self) -> Balance {
+            self.alpha_pool
+                .saturating_mul(u128::from(self.security_reserve_bps))
+                / 10_000
+        }
+
+        /// True if the treasury stake falls short of commitments by more than
+        /// stake_anomaly_threshold_bps of the commitments.
+        fn is_stake_anomaly(&self, current_stake: Balance, committed: Balance) -> bool {
+            let shortfall = committed.saturating_sub(current_stake);
+            shortfall > 0
+                && shortfall.saturating_mul(10_000)
+                    > committed.saturating_mul(u128::from(self.stake_anomaly_threshold_bps))
+        }
+
+        /// Queries the treasury hotkey's stake info via chain extension.
+        fn treasury_stake_info(&self) -> Option<StakeInfo> {
+            let hotkey_bytes: [u8; 32] = *self.treasury_hotkey.as_ref();
+            let coldkey_bytes: [u8; 32] = *self.owner.as_ref();
+
+            self.env()
+                .extension()
+                .get_stake_info(hotkey_bytes, coldkey_bytes, self.netuid)
+        }
+
+        /// Queries the chain extension for whether hotkey is registered on netuid.
+        fn is_registered_on_subnet(&self, hotkey: AccountId, coldkey: AccountId) -> bool {
+            self.env()
+                .extension()
+                .get_stake_info(*hotkey.as_ref(), *coldkey.as_ref(), self.netuid)
+                .is_some_and(|info| info.is_registered)
+        }
+
+        /// Records the caller's solution vote; shared by vote_solution and
+        /// vote_solutions_batch. `registered` memoizes solver registration
+        /// lookups for the duration of one message.
+        fn cast_solution_vote(
+            &mut self,
+            issue_id: u64,
+            solver_hotkey: AccountId,
+            solver_coldkey: AccountId,
+            pr_number: u32,
+            registered: &mut Vec<(AccountId, AccountId, bool)>,
+        ) -> Result<VoteTally, Error> {
+            let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+
+            if issue.status != IssueStatus::Active {
+                return Err(Error::IssueNotActive);
+            }
+
+            // Get or create the vote for this candidate, dropping expired candidates
+            // and voters removed from the whitelist since it was last checked
+            self.clear_expired_solution_votes(issue_id);
+            let mut vote = self.get_or_create_solution_vote(
+                issue_id,
+                solver_hotkey,
+                pr_number,
+                solver_coldkey,
+            );
+            for voter in self.drop_removed_voters(&mut vote.voters, &mut vote.whitelist_version) {
+                self.solution_vote_voters.remove((issue_id, voter));
+            }
+
+            // Check not already voted
+            self.check_not_voted_solution(issue_id, self.env().caller())?;
+            let caller = self.validate_whitelisted_caller()?;
+
+            // The proposed solver must still be a registered miner on the subnet
+            if !self.is_registered_cached(vote.solver_hotkey, vote.solver_coldkey, registered) {
+                return Err(Error::SolverNotRegistered);
+            }
+
+            self.touch_validator(caller);
+            self.solution_vote_voters.insert((issue_id, caller), &true);
+            vote.voters.push(caller);
+            vote.votes_count = u32::try_from(vote.voters.len()).unwrap_or(u32::MAX);
+            self.store_solution_vote(&vote);
+
+            // Check consensus and execute (includes auto-payout); the first
+            // candidate to reach quorum wins
+            let tally = self.vote_tally(vote.votes_count);
+            if tally.consensus_reached {
+                self.complete_issue(issue_id, solver_hotkey, pr_number, solver_coldkey)?;
+                self.clear_solution_vote(issue_id);
+            }
+
+            Ok(tally)
+        }
+
+        /// Like is_registered_on_subnet, but consults `cache` first so the chain
+        /// extension is hit at most once per (hotkey, coldkey).
+        fn is_registered_cached(
+            &self,
+            hotkey: AccountId,
+            coldkey: AccountId,
+            cache: &mut Vec<(AccountId, AccountId, bool)>,
+        ) -> bool {
+            if let Some(&(_, _, registered)) =
+                cache.iter().find(|(h, c, _)| *h == hotkey && *c == coldkey)
+            {
+                return registered;
+            }
+            let registered = self.is_registered_on_subnet(hotkey, coldkey);
+            cache.push((hotkey, coldkey, registered));
+            registered
+        }
+
         /// Calculate total funds committed to issues that still need those funds (ground truth).
         /// Sums bounty_amount for Registered/Active issues, plus Completed issues
         /// with bounty_amount > 0 (failed payouts awaiting retry via payout_bounty).
@@ -860,13 +3661,36 @@ mod issue_bounty_manager {
 
         /// Checks if vote count meets minimum consensus threshold.
         fn check_consensus(&self, votes_count: u32) -> bool {
-            let n = u32::try_from(self.validators.len()).unwrap_or(0);
+            let n = self.active_validator_count();
             if n == 0 {
                 return false;
             }
             votes_count >= self.required_validator_votes()
         }
 
+        /// Builds the tally reported to a voter for a proposal with votes_count votes.
+        fn vote_tally(&self, votes_count: u32) -> VoteTally {
+            VoteTally {
+                votes: votes_count,
+                required: self.required_validator_votes(),
+                active_validators: self.active_validator_count(),
+                consensus_reached: self.check_consensus(votes_count),
+            }
+        }
+
+        /// Returns IssueAlreadyFinalized if the issue is Completed or Cancelled.
+        /// Guards completion/cancellation so a second execution can never pay out
+        /// or recycle the same bounty twice.
+        fn ensure_not_finalized(&self, issue: &Issue) -> Result<(), Error> {
+            if matches!(
+                issue.status,
+                IssueStatus::Completed | IssueStatus::Cancelled
+            ) {
+                return Err(Error::IssueAlreadyFinalized);
+            }
+            Ok(())
+        }
+
         /// Completes an issue with a solution and triggers auto-payout
         fn complete_issue(
             &mut self,
@@ -874,66 +3698,148 @@ mod issue_bounty_manager {
             solver_hotkey: AccountId,
             pr_number: u32,
             solver_coldkey: AccountId,
-        ) {
-            if let Some(mut issue) = self.issues.get(issue_id) {
-                let payout = issue.bounty_amount;
+        ) -> Result<(), Error> {
+            let mut issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+            self.ensure_not_finalized(&issue)?;
 
-                // Mark issue as completed and store solver info
-                issue.status = IssueStatus::Completed;
-                issue.solver_coldkey = Some(solver_coldkey);
-                issue.solver_hotkey = Some(solver_hotkey);
-                issue.winning_pr_number = Some(pr_number);
-                self.issues.insert(issue_id, &issue);
+            let payout = issue.bounty_amount;
 
-                // Explicitly remove from bounty queue (don't rely on lazy cleanup)
-                self.remove_from_bounty_queue(issue_id);
+            // Mark issue as completed and store solver info
+            issue.status = IssueStatus::Completed;
+            issue.solver_coldkey = Some(solver_coldkey);
+            issue.solver_hotkey = Some(solver_hotkey);
+            issue.winning_pr_number = Some(pr_number);
+            self.save_issue(issue_id, &issue);
 
-                // Attempt payout - only zero bounty_amount on success
-                // If payout fails, bounty_amount remains non-zero for retry via payout_bounty
-                if payout > 0
-                    && self
-                        .execute_payout_internal(issue_id, solver_coldkey, payout)
-                        .is_ok()
-                {
-                    // Zero bounty_amount only after successful payout
-                    if let Some(mut issue) = self.issues.get(issue_id) {
+            // Explicitly remove from the funding queues (don't rely on lazy cleanup)
+            self.dequeue_issue(issue_id);
+
+            self.env().emit_event(IssueCompleted {
+                issue_id,
+                solver_hotkey,
+                repository_hash: self.hash_string(&issue.repository_full_name),
+                solver_coldkey,
+                repository_full_name: issue.repository_full_name.clone(),
+                pr_number,
+                bounty_amount: payout,
+            });
+
+            // Attempt payout - only zero bounty_amount on success
+            // If payout fails, bounty_amount remains non-zero for retry via payout_bounty
+            let mut paid_out: Balance = 0;
+            if payout > 0
+                && self
+                    .execute_payout_internal(issue_id, solver_coldkey, payout)
+                    .is_ok()
+            {
+                paid_out = payout;
+                // Zero bounty_amount only after successful payout
+                if let Some(mut issue) = self.issues.get(issue_id) {
                     issue.bounty_amount = 0;
-                        self.issues.insert(issue_id, &issue);
-                    }
+                    self.save_issue(issue_id, &issue);
                 }
             }
+
+            let completed_at_block = self.env().block_number();
+            self.env().emit_event(IssueLifecycleSummary {
+                issue_id,
+                solver_hotkey,
+                registered_at_block: issue.registered_at_block,
+                completed_at_block,
+                blocks_open: completed_at_block.saturating_sub(issue.registered_at_block),
+                target_bounty: issue.target_bounty,
+                total_funding: payout,
+                paid_out,
+                total_paid: self.total_paid,
+            });
+
+            Ok(())
         }
 
-        /// Executes issue cancellation
-        fn execute_cancel_issue(&mut self, issue_id: u64, _reason_hash: [u8; 32]) {
-            let mut issue = match self.issues.get(issue_id) {
-                Some(i) => i,
-                None => return,
-            };
+        /// Executes issue cancellation, sending the bounty to `destination`
+        fn execute_cancel_issue(
+            &mut self,
+            issue_id: u64,
+            reason_hash: [u8; 32],
+            reason: Option<String>,
+            destination: CancelDestination,
+        ) -> Result<(), Error> {
+            let mut issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+            self.ensure_not_finalized(&issue)?;
 
             let returned_bounty = issue.bounty_amount;
 
-            self.remove_from_bounty_queue(issue_id);
-            let _ = self.recycle(returned_bounty);
+            self.dequeue_issue(issue_id);
+            match destination {
+                CancelDestination::Recycle => {
+                    let _ = self.recycle(returned_bounty);
+                }
+                CancelDestination::Pool => {
+                    self.return_to_pool(returned_bounty);
+                }
+            }
 
             issue.status = IssueStatus::Cancelled;
             issue.bounty_amount = 0;
-            self.issues.insert(issue_id, &issue);
+            self.save_issue(issue_id, &issue);
+            self.record_cancellation(issue_id, reason_hash, reason.clone());
 
             self.env().emit_event(IssueCancelled {
                 issue_id,
+                repository_hash: self.hash_string(&issue.repository_full_name),
                 returned_bounty,
+                destination,
+                reason_hash,
+                reason,
             });
+
+            Ok(())
+        }
+
+        /// Pays the outstanding bounty of a completed issue to its recorded solver,
+        /// zeroing bounty_amount on success.
+        fn settle_payout(&mut self, issue_id: u64) -> Result<Balance, Error> {
+            let issue = self.issues.get(issue_id).ok_or(Error::IssueNotFound)?;
+
+            if issue.status != IssueStatus::Completed {
+                return Err(Error::BountyNotCompleted);
+            }
+
+            if issue.bounty_amount == 0 {
+                return Err(Error::BountyAlreadyPaid);
+            }
+
+            let solver_coldkey = issue.solver_coldkey.ok_or(Error::NoSolverSet)?;
+            let payout = issue.bounty_amount;
+
+            // Attempt payout
+            let result = self.execute_payout_internal(issue_id, solver_coldkey, payout)?;
+
+            // Zero bounty_amount on success
+            if let Some(mut issue) = self.issues.get(issue_id) {
+                issue.bounty_amount = 0;
+                self.save_issue(issue_id, &issue);
+            }
+
+            Ok(result)
         }
 
-        /// Internal payout helper - transfers stake from treasury_hotkey to solver
+        /// Internal payout helper - transfers stake from treasury_hotkey to solver.
+        /// Refuses to dispatch a transfer the treasury stake cannot cover.
         fn execute_payout_internal(
             &mut self,
             issue_id: u64,
             solver_coldkey: AccountId,
             payout_amount: Balance,
         ) -> Result<Balance, Error> {
-            let amount_u64: u64 = payout_amount.try_into().unwrap_or(u64::MAX);
+            let alpha = AlphaAmount::try_from(payout_amount)?;
+            let available = self.get_treasury_stake();
+            if available < payout_amount {
+                return Err(Error::InsufficientTreasuryBalance {
+                    available,
+                    requested: payout_amount,
+                });
+            }
 
             let proxy_call = RawCall::proxied_transfer_stake(
                 &self.owner,
@@ -941,15 +3847,16 @@ mod issue_bounty_manager {
                 &self.treasury_hotkey,
                 self.netuid,
                 self.netuid,
-                amount_u64,
+                alpha,
             );
 
-            let result = self.env().call_runtime(&proxy_call);
-
-            if result.is_ok() {
+            if self.dispatch_stake_op(StakeOpKind::Transfer, &proxy_call, payout_amount) {
+                self.total_paid = self.total_paid.saturating_add(payout_amount);
+                let solver_hotkey = self.issues.get(issue_id).and_then(|i| i.solver_hotkey);
                 self.env().emit_event(BountyPaidOut {
                     issue_id,
                     miner: solver_coldkey,
+                    solver_hotkey,
                     amount: payout_amount,
                 });
                 Ok(payout_amount)
@@ -958,37 +3865,96 @@ mod issue_bounty_manager {
             }
         }
 
-        /// Recycles (destroys) alpha tokens via runtime call.
-        fn recycle(&mut self, amount: Balance) -> bool {
-            if amount == 0 {
-                return true;
-            }
-
-            let amount_u64: u64 = amount.try_into().unwrap_or(u64::MAX);
-
+        /// Dispatches a proxied recycle_alpha call for `amount`. Returns false
+        /// without dispatching if the amount does not fit the runtime's u64 alpha type.
+        fn dispatch_recycle(&mut self, amount: Balance) -> bool {
+            let Ok(alpha) = AlphaAmount::try_from(amount) else {
+                return false;
+            };
             let proxy_call = RawCall::proxied_recycle_alpha(
                 &self.owner,
                 &self.treasury_hotkey,
-                amount_u64,
+                alpha,
                 self.netuid,
             );
+            self.dispatch_stake_op(StakeOpKind::Recycle, &proxy_call, amount)
+        }
+
+        /// Dispatches a proxied stake movement and emits a StakeOperation event
+        /// with the next op id, the call hash and the outcome.
+        fn dispatch_stake_op(
+            &mut self,
+            kind: StakeOpKind,
+            call: &RawCall,
+            amount: Balance,
+        ) -> bool {
+            self.stake_op_count = self.stake_op_count.saturating_add(1);
+            let success = self.env().call_runtime(call).is_ok();
+            self.env().emit_event(StakeOperation {
+                op_id: self.stake_op_count,
+                kind,
+                call_hash: Self::call_hash(call),
+                amount,
+                success,
+            });
+            success
+        }
 
-            let result = self.env().call_runtime(&proxy_call);
+        /// blake2_256 of an encoded runtime call, as Substrate tooling computes it.
+        fn call_hash(call: &RawCall) -> [u8; 32] {
+            use ink::env::hash::{Blake2x256, HashOutput};
+            let mut output = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Blake2x256>(&call.0, &mut output);
+            output
+        }
+
+        /// Recycles (destroys) alpha tokens via runtime call.
+        fn recycle(&mut self, amount: Balance) -> bool {
+            if amount == 0 {
+                return true;
+            }
 
-            if result.is_ok() {
+            if self.dispatch_recycle(amount) {
+                self.total_recycled = self.total_recycled.saturating_add(amount);
                 self.env().emit_event(EmissionsRecycled {
                     amount,
                     destination: self.treasury_hotkey,
                 });
                 true
             } else {
-                self.alpha_pool = self.alpha_pool.saturating_add(amount);
+                self.return_to_pool(amount);
                 self.env().emit_event(RecycleFailed { amount });
                 false
             }
         }
 
-        /// Clears solution vote data
+        /// Credits already-harvested alpha back to the pool.
+        fn return_to_pool(&mut self, amount: Balance) {
+            self.alpha_pool = self.alpha_pool.saturating_add(amount);
+            self.returned_to_pool = self.returned_to_pool.saturating_add(amount);
+        }
+
+        /// Writes back a solution candidate's vote, appending it if new.
+        fn store_solution_vote(&mut self, vote: &SolutionVote) {
+            let mut candidates = self.solution_votes.get(vote.issue_id).unwrap_or_default();
+            match candidates
+                .iter_mut()
+                .find(|c| c.is_candidate(vote.solver_hotkey, vote.solver_coldkey, vote.pr_number))
+            {
+                Some(existing) => *existing = vote.clone(),
+                None => candidates.push(vote.clone()),
+            }
+            self.solution_votes.insert(vote.issue_id, &candidates);
+        }
+
+        /// Writes an issue and records the block for sync_state.
+        fn save_issue(&mut self, issue_id: u64, issue: &Issue) {
+            self.issues.insert(issue_id, issue);
+            self.issue_updated_at
+                .insert(issue_id, &self.env().block_number());
+        }
+
+        /// Clears all solution candidates for an issue
         fn clear_solution_vote(&mut self, issue_id: u64) {
             self.solution_votes.remove(issue_id);
         }
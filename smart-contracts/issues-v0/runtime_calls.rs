@@ -1,3 +1,4 @@
+use crate::Error;
 use ink::prelude::vec::Vec;
 use ink::primitives::AccountId;
 use scale::{Encode, Output};
@@ -12,6 +13,9 @@ pub const SUBTENSOR_MODULE_PALLET_INDEX: u8 = 7;
 /// Proxy pallet index in the runtime
 pub const PROXY_PALLET_INDEX: u8 = 16;
 
+/// proxy() call variant index within the Proxy pallet (first call variant)
+pub const PROXY_CALL_INDEX: u8 = 0;
+
 /// transfer_stake call variant index within SubtensorModule
 /// NOTE: This MUST match the order in the pallet's Call enum.
 /// Verify with: subtensor/pallets/subtensor/src/macros/dispatches.rs
@@ -32,6 +36,45 @@ pub const PROXY_TYPE_TRANSFER: u8 = 10;
 /// NonCritical allows all calls EXCEPT: dissolve_network, root_register, burned_register, Sudo
 pub const PROXY_TYPE_NON_CRITICAL: u8 = 2;
 
+// =============================================================================
+// Alpha Amount
+// =============================================================================
+
+/// Alpha amount as encoded in runtime calls (AlphaCurrency is a u64).
+/// Contract balances are u128; converting is checked so an oversized amount
+/// fails instead of being clamped to u64::MAX.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlphaAmount(u64);
+
+impl AlphaAmount {
+    /// Raw u64 value written into the call
+    pub fn get(self) -> u64 {
+        self.0
+    }
+}
+
+impl TryFrom<u128> for AlphaAmount {
+    type Error = Error;
+
+    fn try_from(amount: u128) -> Result<Self, Error> {
+        u64::try_from(amount)
+            .map(Self)
+            .map_err(|_| Error::AmountOverflow)
+    }
+}
+
+impl From<u64> for AlphaAmount {
+    fn from(amount: u64) -> Self {
+        Self(amount)
+    }
+}
+
+impl From<AlphaAmount> for u128 {
+    fn from(amount: AlphaAmount) -> Self {
+        u128::from(amount.0)
+    }
+}
+
 // =============================================================================
 // Raw Call Wrapper for call_runtime
 // =============================================================================
@@ -69,14 +112,14 @@ impl RawCall {
     /// * `hotkey` - The hotkey the stake is on
     /// * `origin_netuid` - Source subnet ID
     /// * `destination_netuid` - Target subnet ID
-    /// * `amount` - Amount of alpha to transfer (u64)
+    /// * `amount` - Amount of alpha to transfer
     pub fn proxied_transfer_stake(
         real: &AccountId,
         destination_coldkey: &AccountId,
         hotkey: &AccountId,
         origin_netuid: u16,
         destination_netuid: u16,
-        amount: u64,
+        amount: AlphaAmount,
     ) -> Self {
         let mut call_bytes = Vec::with_capacity(128);
 
@@ -84,7 +127,7 @@ impl RawCall {
         call_bytes.push(PROXY_PALLET_INDEX);
 
         // proxy() is the first call variant (index 0)
-        call_bytes.push(0);
+        call_bytes.push(PROXY_CALL_INDEX);
 
         // real: MultiAddress<AccountId, ()>
         // MultiAddress::Id variant = 0, then 32 bytes of AccountId
@@ -117,7 +160,7 @@ impl RawCall {
         call_bytes.extend_from_slice(&destination_netuid.to_le_bytes());
 
         // alpha_amount: u64 (8 bytes, little-endian)
-        call_bytes.extend_from_slice(&amount.to_le_bytes());
+        call_bytes.extend_from_slice(&amount.get().to_le_bytes());
 
         Self(call_bytes)
     }
@@ -137,12 +180,12 @@ impl RawCall {
     /// # Arguments
     /// * `real` - The account to execute as (owner/treasury coldkey)
     /// * `hotkey` - The hotkey to recycle alpha from
-    /// * `amount` - Amount of alpha to recycle (u64)
+    /// * `amount` - Amount of alpha to recycle
     /// * `netuid` - Subnet ID
     pub fn proxied_recycle_alpha(
         real: &AccountId,
         hotkey: &AccountId,
-        amount: u64,
+        amount: AlphaAmount,
         netuid: u16,
     ) -> Self {
         let mut call_bytes = Vec::with_capacity(128);
@@ -151,7 +194,7 @@ impl RawCall {
         call_bytes.push(PROXY_PALLET_INDEX);
 
         // proxy() is the first call variant (index 0)
-        call_bytes.push(0);
+        call_bytes.push(PROXY_CALL_INDEX);
 
         // real: MultiAddress<AccountId, ()>
         // MultiAddress::Id variant = 0, then 32 bytes of AccountId
@@ -175,7 +218,7 @@ impl RawCall {
         call_bytes.extend_from_slice(hotkey.as_ref());
 
         // amount: u64 (8 bytes, little-endian)
-        call_bytes.extend_from_slice(&amount.to_le_bytes());
+        call_bytes.extend_from_slice(&amount.get().to_le_bytes());
 
         // netuid: u16 (2 bytes, little-endian)
         call_bytes.extend_from_slice(&netuid.to_le_bytes());
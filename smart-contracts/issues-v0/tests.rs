@@ -14,6 +14,18 @@ fn account(byte: u8) -> AccountId {
     AccountId::from([byte; 32])
 }
 
+/// Sets the current block number in the off-chain env.
+fn set_block(block: u32) {
+    test::set_block_number::<crate::CustomEnvironment>(block);
+}
+
+/// Advances the current block number by `blocks` and returns the new block.
+fn advance_blocks(blocks: u32) -> u32 {
+    let block = ink::env::block_number::<crate::CustomEnvironment>().saturating_add(blocks);
+    set_block(block);
+    block
+}
+
 /// Standard set of test accounts. Use these by convention:
 ///   - account(1) = owner
 ///   - account(2) = treasury_hotkey
@@ -49,31 +61,58 @@ fn set_caller(caller: AccountId) {
 /// Intercepts get_stake_info (func 0) and transfer_stake (func 6).
 struct MockSubtensorExtension {
     stake_amount: u64,
+    is_registered: bool,
+    emission: u64,
+    /// Number of get_stake_info calls served
+    stake_info_calls: std::rc::Rc<core::cell::Cell<u32>>,
+    /// Per-coldkey stake; when non-empty, replaces stake_amount and unlisted
+    /// coldkeys have no stake
+    coldkey_stakes: Vec<(AccountId, u64)>,
 }
 
 impl ink::env::test::ChainExtension for MockSubtensorExtension {
     fn ext_id(&self) -> u16 {
-        5001
+        crate::SUBTENSOR_EXTENSION_ID
     }
 
     /// Handles chain extension calls:
     ///   func 0 (get_stake_info) -> returns Some(StakeInfo) with self.stake_amount
     ///   func 6 (transfer_stake) -> returns 0 (success)
-    fn call(&mut self, func_id: u16, _input: &[u8], output: &mut Vec<u8>) -> u32 {
+    fn call(&mut self, func_id: u16, input: &[u8], output: &mut Vec<u8>) -> u32 {
         match func_id {
-            0 => {
+            crate::GET_STAKE_INFO_FUNC_ID => {
+                self.stake_info_calls.set(self.stake_info_calls.get() + 1);
+                let mut stake_amount = self.stake_amount;
+                if !self.coldkey_stakes.is_empty() {
+                    // Input is the SCALE-encoded (hotkey, coldkey, netuid), wrapped in a Vec<u8>
+                    let args = <Vec<u8> as scale::Decode>::decode(&mut &input[..]).unwrap();
+                    let (_, coldkey, _) =
+                        <([u8; 32], [u8; 32], u16) as scale::Decode>::decode(&mut &args[..])
+                            .unwrap();
+                    match self
+                        .coldkey_stakes
+                        .iter()
+                        .find(|(c, _)| c == &AccountId::from(coldkey))
+                    {
+                        Some((_, stake)) => stake_amount = *stake,
+                        None => {
+                            None::<crate::StakeInfo>.encode_to(output);
+                            return 0;
+                        }
+                    }
+                }
                 // Build a StakeInfo with the configured stake amount.
                 // All other fields are zeroed/defaults -- only stake matters for tests.
                 let stake_info = crate::StakeInfo {
                     hotkey: AccountId::from([0u8; 32]),
                     coldkey: AccountId::from([0u8; 32]),
                     netuid: scale::Compact(TEST_NETUID),
-                    stake: scale::Compact(self.stake_amount),
+                    stake: scale::Compact(stake_amount),
                     locked: scale::Compact(0u64),
-                    emission: scale::Compact(0u64),
+                    emission: scale::Compact(self.emission),
                     tao_emission: scale::Compact(0u64),
                     drain: scale::Compact(0u64),
-                    is_registered: true,
+                    is_registered: self.is_registered,
                 };
                 // Encode as Option<StakeInfo> = Some(stake_info)
                 let result: Option<crate::StakeInfo> = Some(stake_info);
@@ -100,6 +139,57 @@ fn register_mock_extension() {
 fn register_mock_extension_with_stake(stake: u64) {
     ink::env::test::register_chain_extension(MockSubtensorExtension {
         stake_amount: stake,
+        is_registered: true,
+        emission: 0,
+        stake_info_calls: Default::default(),
+        coldkey_stakes: Vec::new(),
+    });
+}
+
+/// Registers mock chain extension reporting `emission` per epoch for the treasury.
+fn register_mock_extension_with_emission(emission: u64) {
+    ink::env::test::register_chain_extension(MockSubtensorExtension {
+        stake_amount: 0,
+        is_registered: true,
+        emission,
+        stake_info_calls: Default::default(),
+        coldkey_stakes: Vec::new(),
+    });
+}
+
+/// Registers the default mock chain extension and returns its get_stake_info
+/// call counter.
+fn register_mock_extension_counting() -> std::rc::Rc<core::cell::Cell<u32>> {
+    let calls = std::rc::Rc::new(core::cell::Cell::new(0));
+    ink::env::test::register_chain_extension(MockSubtensorExtension {
+        stake_amount: MOCK_STAKE,
+        is_registered: true,
+        emission: 0,
+        stake_info_calls: calls.clone(),
+        coldkey_stakes: Vec::new(),
+    });
+    calls
+}
+
+/// Registers mock chain extension reporting every hotkey as unregistered.
+fn register_mock_extension_unregistered() {
+    ink::env::test::register_chain_extension(MockSubtensorExtension {
+        stake_amount: MOCK_STAKE,
+        is_registered: false,
+        emission: 0,
+        stake_info_calls: Default::default(),
+        coldkey_stakes: Vec::new(),
+    });
+}
+
+/// Registers mock chain extension reporting stake only for the listed coldkeys.
+fn register_mock_extension_with_coldkey_stakes(stakes: &[(AccountId, u64)]) {
+    ink::env::test::register_chain_extension(MockSubtensorExtension {
+        stake_amount: 0,
+        is_registered: true,
+        emission: 0,
+        stake_info_calls: Default::default(),
+        coldkey_stakes: stakes.to_vec(),
     });
 }
 
@@ -130,21 +220,23 @@ fn get_issue_by_url_hash_returns_zero_for_unknown() {
     assert_eq!(contract.get_issue_by_url_hash(unknown_hash), 0);
 }
 
+/// Returns every issue with the given status, asserting it fits in one page.
+fn issues_with_status(
+    contract: &IssueBountyManager,
+    status: crate::IssueStatus,
+) -> Vec<crate::types::Issue> {
+    let page = contract.get_issues_by_status(status, 1, MAX_STATUS_SCAN, u32::MAX);
+    assert_eq!(page.next_cursor, None);
+    page.issues
+}
+
 #[ink::test]
 fn get_issues_by_status_returns_empty_initially() {
     let contract = create_default_contract();
-    assert!(contract
-        .get_issues_by_status(crate::IssueStatus::Registered)
-        .is_empty());
-    assert!(contract
-        .get_issues_by_status(crate::IssueStatus::Active)
-        .is_empty());
-    assert!(contract
-        .get_issues_by_status(crate::IssueStatus::Completed)
-        .is_empty());
-    assert!(contract
-        .get_issues_by_status(crate::IssueStatus::Cancelled)
-        .is_empty());
+    assert!(issues_with_status(&contract, crate::IssueStatus::Registered).is_empty());
+    assert!(issues_with_status(&contract, crate::IssueStatus::Active).is_empty());
+    assert!(issues_with_status(&contract, crate::IssueStatus::Completed).is_empty());
+    assert!(issues_with_status(&contract, crate::IssueStatus::Cancelled).is_empty());
 }
 
 #[ink::test]
@@ -169,7 +261,19 @@ fn get_bounty_queue_returns_empty_initially() {
 fn set_owner_works_for_owner() {
     let mut contract = create_default_contract();
     set_caller(account(1));
-    assert!(contract.set_owner(account(4)).is_ok());
+    contract
+        .schedule_config_change(crate::ScheduledChange::Owner(account(4)))
+        .unwrap();
+
+    // Ownership only moves once the timelock has passed
+    assert_eq!(
+        contract.execute_config_change(),
+        Err(crate::Error::ConfigChangeTimelocked)
+    );
+    assert_eq!(contract.owner(), account(1));
+
+    advance_blocks(contract.get_config().config_timelock_blocks);
+    contract.execute_config_change().unwrap();
     assert_eq!(contract.owner(), account(4));
 }
 
@@ -177,27 +281,98 @@ fn set_owner_works_for_owner() {
 fn set_owner_fails_for_non_owner() {
     let mut contract = create_default_contract();
     set_caller(account(4)); // not the owner
-    assert_eq!(contract.set_owner(account(4)), Err(crate::Error::NotOwner));
+    assert_eq!(
+        contract.schedule_config_change(crate::ScheduledChange::Owner(account(4))),
+        Err(crate::Error::NotOwner)
+    );
+}
+
+#[ink::test]
+fn set_owner_cancelled_by_validators() {
+    let mut contract = setup_3_validators();
+    set_caller(account(1));
+    contract
+        .schedule_config_change(crate::ScheduledChange::Owner(account(9)))
+        .unwrap();
+
+    for v in [account(3), account(4)] {
+        set_caller(v);
+        contract.vote_cancel_config_change().unwrap();
+    }
+    assert!(contract.get_pending_config_change().is_none());
+
+    advance_blocks(contract.get_config().config_timelock_blocks);
+    assert_eq!(
+        contract.execute_config_change(),
+        Err(crate::Error::NoConfigChange)
+    );
+    assert_eq!(contract.owner(), account(1));
+}
+
+/// Schedules a config change as the owner and executes it once the timelock passes.
+fn apply_config_change(contract: &mut IssueBountyManager, change: crate::ScheduledChange) {
+    set_caller(account(1));
+    contract.schedule_config_change(change).unwrap();
+    advance_blocks(contract.get_config().config_timelock_blocks);
+    contract.execute_config_change().unwrap();
 }
 
 #[ink::test]
-fn set_treasury_hotkey_works_for_owner() {
+fn treasury_hotkey_change_applies_after_timelock() {
     let mut contract = create_default_contract();
     set_caller(account(1));
-    assert!(contract.set_treasury_hotkey(account(7)).is_ok());
+    contract
+        .schedule_config_change(crate::ScheduledChange::TreasuryHotkey(account(7)))
+        .unwrap();
+
+    // Nothing changes until the timelock passes
+    advance_blocks(CONFIG_TIMELOCK_BLOCKS - 1);
+    assert_eq!(
+        contract.execute_config_change(),
+        Err(crate::Error::ConfigChangeTimelocked)
+    );
+    assert_eq!(contract.treasury_hotkey(), account(2));
+
+    advance_blocks(1);
+    set_caller(account(9)); // execution is permissionless
+    contract.execute_config_change().unwrap();
     assert_eq!(contract.treasury_hotkey(), account(7));
+    assert!(contract.get_pending_config_change().is_none());
 }
 
 #[ink::test]
-fn set_treasury_hotkey_fails_for_non_owner() {
+fn schedule_config_change_fails_for_non_owner() {
     let mut contract = create_default_contract();
     set_caller(account(4));
     assert_eq!(
-        contract.set_treasury_hotkey(account(7)),
+        contract.schedule_config_change(crate::ScheduledChange::TreasuryHotkey(account(7))),
         Err(crate::Error::NotOwner),
     );
 }
 
+#[ink::test]
+fn quorum_settings_frozen_while_config_change_pending() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.add_validator(account(3)).unwrap();
+    contract.add_validator(account(4)).unwrap();
+    contract
+        .schedule_config_change(crate::ScheduledChange::TreasuryHotkey(account(7)))
+        .unwrap();
+
+    let locked = Err(crate::Error::QuorumLocked);
+    assert_eq!(contract.remove_validator(account(3)), locked);
+    assert_eq!(contract.add_validator(account(5)), locked);
+    assert_eq!(contract.set_required_validator_votes(Some(1)), locked);
+    assert_eq!(contract.set_min_votes_required(1), locked);
+    assert_eq!(contract.set_validator_inactivity_blocks(1), locked);
+
+    // The lock lifts once the change is executed
+    advance_blocks(CONFIG_TIMELOCK_BLOCKS);
+    contract.execute_config_change().unwrap();
+    contract.remove_validator(account(3)).unwrap();
+}
+
 // ============================================================================
 // Internal Helper Tests
 // ============================================================================
@@ -280,6 +455,8 @@ fn register_test_issue(contract: &mut IssueBountyManager) -> u64 {
             String::from("org/repo"),
             1,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .expect("register_issue should succeed")
 }
@@ -303,6 +480,58 @@ fn register_issue_succeeds_with_valid_inputs() {
     assert_eq!(issue.solver_coldkey, None);
 }
 
+#[ink::test]
+fn register_issue_stores_difficulty_and_label_hash() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    let id = contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/1"),
+            String::from("org/repo"),
+            1,
+            MIN_BOUNTY,
+            Some(3),
+            Some([0x1B; 32]),
+        )
+        .unwrap();
+
+    let issue = contract.get_issue(id).unwrap();
+    assert_eq!(issue.difficulty, Some(3));
+    assert_eq!(issue.label_hash, Some([0x1B; 32]));
+
+    // Metadata is optional
+    let id = contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/2"),
+            String::from("org/repo"),
+            2,
+            MIN_BOUNTY,
+            None,
+            None,
+        )
+        .unwrap();
+    let issue = contract.get_issue(id).unwrap();
+    assert_eq!(issue.difficulty, None);
+    assert_eq!(issue.label_hash, None);
+}
+
+#[ink::test]
+fn register_issue_records_registrar_and_block() {
+    let mut contract = create_default_contract();
+    set_block(12);
+    let id = register_test_issue(&mut contract);
+
+    let issue = contract.get_issue(id).unwrap();
+    assert_eq!(issue.registered_by, account(1));
+    assert_eq!(issue.registered_at_block, 12);
+
+    let last = test::recorded_events().last().expect("registration event");
+    let event = <crate::events::IssueRegistered as scale::Decode>::decode(&mut &last.data[..])
+        .expect("last event should be IssueRegistered");
+    assert_eq!(event.registered_by, account(1));
+    assert_eq!(event.registered_at_block, 12);
+}
+
 #[ink::test]
 fn register_issue_adds_to_bounty_queue() {
     let mut contract = create_default_contract();
@@ -325,14 +554,12 @@ fn register_issue_appears_in_status_query() {
     let mut contract = create_default_contract();
     register_test_issue(&mut contract);
 
-    let registered = contract.get_issues_by_status(crate::IssueStatus::Registered);
+    let registered = issues_with_status(&contract, crate::IssueStatus::Registered);
     assert_eq!(registered.len(), 1);
     assert_eq!(registered[0].issue_number, 1);
 
     // Other statuses should still be empty
-    assert!(contract
-        .get_issues_by_status(crate::IssueStatus::Active)
-        .is_empty());
+    assert!(issues_with_status(&contract, crate::IssueStatus::Active).is_empty());
 }
 
 #[ink::test]
@@ -346,6 +573,8 @@ fn register_issue_increments_id_for_multiple_issues() {
             String::from("org/repo"),
             1,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
@@ -355,6 +584,8 @@ fn register_issue_increments_id_for_multiple_issues() {
             String::from("org/repo"),
             2,
             MIN_BOUNTY * 2,
+            None,
+            None,
         )
         .unwrap();
 
@@ -373,6 +604,8 @@ fn register_issue_fails_for_non_owner() {
         String::from("org/repo"),
         1,
         MIN_BOUNTY,
+        None,
+        None,
     );
     assert_eq!(result, Err(crate::Error::NotOwner));
 }
@@ -386,6 +619,8 @@ fn register_issue_fails_bounty_too_low() {
         String::from("org/repo"),
         1,
         MIN_BOUNTY - 1, // one below minimum
+        None,
+        None,
     );
     assert_eq!(result, Err(crate::Error::BountyTooLow));
 }
@@ -399,6 +634,8 @@ fn register_issue_fails_bounty_zero() {
         String::from("org/repo"),
         1,
         0,
+        None,
+        None,
     );
     assert_eq!(result, Err(crate::Error::BountyTooLow));
 }
@@ -412,6 +649,8 @@ fn register_issue_fails_issue_number_zero() {
         String::from("org/repo"),
         0, // invalid
         MIN_BOUNTY,
+        None,
+        None,
     );
     assert_eq!(result, Err(crate::Error::InvalidIssueNumber));
 }
@@ -427,6 +666,8 @@ fn register_issue_fails_invalid_repo_name() {
         String::from("noslash"),
         1,
         MIN_BOUNTY,
+        None,
+        None,
     );
     assert_eq!(result, Err(crate::Error::InvalidRepositoryName));
 }
@@ -443,6 +684,8 @@ fn register_issue_fails_duplicate_url() {
             String::from("org/repo"),
             1,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
@@ -452,6 +695,8 @@ fn register_issue_fails_duplicate_url() {
         String::from("org/repo"),
         1,
         MIN_BOUNTY,
+        None,
+        None,
     );
     assert_eq!(result, Err(crate::Error::IssueAlreadyExists));
 }
@@ -465,6 +710,8 @@ fn register_issue_at_exact_min_bounty_succeeds() {
         String::from("org/repo"),
         1,
         MIN_BOUNTY, // exactly at the boundary
+        None,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -479,7 +726,7 @@ fn cancel_issue_succeeds_on_registered_issue() {
     let id = register_test_issue(&mut contract);
 
     set_caller(account(1));
-    assert!(contract.cancel_issue(id).is_ok());
+    assert!(contract.cancel_issue(id, None).is_ok());
 
     let issue = contract.get_issue(id).expect("issue should still exist");
     assert_eq!(issue.status, crate::IssueStatus::Cancelled);
@@ -493,7 +740,7 @@ fn cancel_issue_removes_from_bounty_queue() {
     assert_eq!(contract.get_bounty_queue(), vec![id]);
 
     set_caller(account(1));
-    contract.cancel_issue(id).unwrap();
+    contract.cancel_issue(id, None).unwrap();
     assert!(contract.get_bounty_queue().is_empty());
 }
 
@@ -511,26 +758,46 @@ fn cancel_issue_returns_bounty_to_alpha_pool() {
 
     assert_eq!(contract.get_alpha_pool(), 0);
     set_caller(account(1));
-    contract.cancel_issue(id).unwrap();
+    contract.cancel_issue(id, None).unwrap();
 
     // Bounty should have been returned to the pool
     assert_eq!(contract.get_alpha_pool(), 5_000_000_000);
 }
 
+#[ink::test]
+fn cancelled_bounty_is_not_harvested_twice() {
+    register_mock_extension_with_stake(MIN_BOUNTY as u64);
+    let mut contract = create_default_contract();
+    let cancelled = register_issue_n(&mut contract, 1);
+    contract.harvest_emissions().unwrap();
+    contract.cancel_issue(cancelled, None).unwrap();
+
+    // The returned bounty funds the next issue but was harvested only once
+    let next = register_issue_n(&mut contract, 2);
+    contract.harvest_emissions().unwrap();
+    assert_eq!(contract.get_issue(next).unwrap().bounty_amount, MIN_BOUNTY);
+    let accounting = contract.get_accounting();
+    assert_eq!(accounting.total_harvested, MIN_BOUNTY);
+    assert_eq!(accounting.total_committed, MIN_BOUNTY * 2);
+}
+
 #[ink::test]
 fn cancel_issue_fails_for_non_owner() {
     let mut contract = create_default_contract();
     let id = register_test_issue(&mut contract);
 
     set_caller(account(4));
-    assert_eq!(contract.cancel_issue(id), Err(crate::Error::NotOwner));
+    assert_eq!(contract.cancel_issue(id, None), Err(crate::Error::NotOwner));
 }
 
 #[ink::test]
 fn cancel_issue_fails_for_nonexistent_issue() {
     let mut contract = create_default_contract();
     set_caller(account(1));
-    assert_eq!(contract.cancel_issue(999), Err(crate::Error::IssueNotFound));
+    assert_eq!(
+        contract.cancel_issue(999, None),
+        Err(crate::Error::IssueNotFound)
+    );
 }
 
 #[ink::test]
@@ -539,10 +806,10 @@ fn cancel_issue_fails_on_already_cancelled() {
     let id = register_test_issue(&mut contract);
 
     set_caller(account(1));
-    contract.cancel_issue(id).unwrap();
+    contract.cancel_issue(id, None).unwrap();
 
     // Second cancel should fail -- status is now Cancelled, not modifiable
-    let result = contract.cancel_issue(id);
+    let result = contract.cancel_issue(id, None);
     assert_eq!(result, Err(crate::Error::CannotCancel));
 }
 
@@ -552,12 +819,10 @@ fn cancel_issue_shows_in_status_query() {
     let id = register_test_issue(&mut contract);
 
     set_caller(account(1));
-    contract.cancel_issue(id).unwrap();
+    contract.cancel_issue(id, None).unwrap();
 
-    assert!(contract
-        .get_issues_by_status(crate::IssueStatus::Registered)
-        .is_empty());
-    let cancelled = contract.get_issues_by_status(crate::IssueStatus::Cancelled);
+    assert!(issues_with_status(&contract, crate::IssueStatus::Registered).is_empty());
+    let cancelled = issues_with_status(&contract, crate::IssueStatus::Cancelled);
     assert_eq!(cancelled.len(), 1);
     assert_eq!(cancelled[0].id, id);
 }
@@ -573,6 +838,8 @@ fn cancel_middle_issue_preserves_other_queue_entries() {
             String::from("org/repo"),
             1,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
@@ -582,6 +849,8 @@ fn cancel_middle_issue_preserves_other_queue_entries() {
             String::from("org/repo"),
             2,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
@@ -591,18 +860,16 @@ fn cancel_middle_issue_preserves_other_queue_entries() {
             String::from("org/repo"),
             3,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
     // Cancel the middle one
-    contract.cancel_issue(id2).unwrap();
+    contract.cancel_issue(id2, None).unwrap();
 
-    // Queue should have id1 and id3 (swap_remove puts last in middle's spot)
-    let queue = contract.get_bounty_queue();
-    assert_eq!(queue.len(), 2);
-    assert!(queue.contains(&id1));
-    assert!(queue.contains(&id3));
-    assert!(!queue.contains(&id2));
+    // Queue keeps id1 ahead of id3 (order-preserving removal)
+    assert_eq!(contract.get_bounty_queue(), vec![id1, id3]);
 }
 
 // ============================================================================
@@ -652,6 +919,8 @@ fn fill_bounties_fills_fifo_order() {
             String::from("org/repo"),
             1,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
@@ -661,6 +930,8 @@ fn fill_bounties_fills_fifo_order() {
             String::from("org/repo"),
             2,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
@@ -688,6 +959,8 @@ fn fill_bounties_fills_multiple_when_pool_sufficient() {
             String::from("org/repo"),
             1,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
@@ -697,6 +970,8 @@ fn fill_bounties_fills_multiple_when_pool_sufficient() {
             String::from("org/repo"),
             2,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
@@ -722,6 +997,8 @@ fn fill_bounties_skips_cancelled_issues() {
             String::from("org/repo"),
             1,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
@@ -731,11 +1008,13 @@ fn fill_bounties_skips_cancelled_issues() {
             String::from("org/repo"),
             2,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
     // Cancel the first issue
-    contract.cancel_issue(id1).unwrap();
+    contract.cancel_issue(id1, None).unwrap();
 
     // Give enough for one issue
     contract.alpha_pool = MIN_BOUNTY;
@@ -790,7 +1069,7 @@ fn get_total_committed_ignores_cancelled() {
     let id = register_test_issue(&mut contract);
 
     set_caller(account(1));
-    contract.cancel_issue(id).unwrap();
+    contract.cancel_issue(id, None).unwrap();
 
     assert_eq!(contract.get_total_committed(), 0);
 }
@@ -849,7 +1128,7 @@ fn cancel_issue_fails_on_completed_issue() {
         contract.issues.insert(id, &issue);
     }
 
-    let result = contract.cancel_issue(id);
+    let result = contract.cancel_issue(id, None);
 
     assert_eq!(result, Err(crate::Error::CannotCancel));
 }
@@ -875,6 +1154,41 @@ fn payout_bounty_fails_no_solver_set() {
     assert_eq!(result, Err(crate::Error::NoSolverSet));
 }
 
+#[ink::test]
+fn payout_bounty_fails_when_treasury_stake_insufficient() {
+    register_mock_extension_with_stake(MOCK_STAKE);
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+
+    // Completed with a bounty larger than the treasury stake
+    let requested = MOCK_STAKE as u128 + 1;
+    let mut issue = contract.issues.get(id).unwrap();
+    issue.status = crate::IssueStatus::Completed;
+    issue.bounty_amount = requested;
+    issue.solver_coldkey = Some(account(5));
+    contract.issues.insert(id, &issue);
+
+    set_caller(account(1));
+    let result = contract.payout_bounty(id);
+    assert_eq!(
+        result,
+        Err(crate::Error::InsufficientTreasuryBalance {
+            available: MOCK_STAKE as u128,
+            requested,
+        })
+    );
+
+    // Bounty stays reserved for a later retry
+    assert_eq!(contract.get_issue(id).unwrap().bounty_amount, requested);
+}
+
+#[ink::test]
+fn execute_payout_internal_rejects_amount_above_u64() {
+    let mut contract = create_default_contract();
+    let result = contract.execute_payout_internal(1, account(5), u64::MAX as u128 + 1);
+    assert_eq!(result, Err(crate::Error::AmountOverflow));
+}
+
 // ============================================================================
 // Vote Solution Tests (validation paths -- chain extension blocks full flow)
 // ============================================================================
@@ -923,7 +1237,7 @@ fn vote_solution_fails_on_cancelled_issue() {
     let id = register_test_issue(&mut contract);
 
     set_caller(account(1));
-    contract.cancel_issue(id).unwrap();
+    contract.cancel_issue(id, None).unwrap();
 
     set_caller(account(4));
     let result = contract.vote_solution(id, account(6), account(5), 42);
@@ -959,7 +1273,8 @@ fn vote_solution_fails_already_voted() {
 fn vote_cancel_issue_fails_issue_not_found() {
     let mut contract = create_default_contract();
     set_caller(account(4));
-    let result = contract.vote_cancel_issue(999, [0xCC; 32]);
+    let result =
+        contract.vote_cancel_issue(999, [0xCC; 32], crate::CancelDestination::Recycle, None);
     assert_eq!(result, Err(crate::Error::IssueNotFound));
 }
 
@@ -973,7 +1288,8 @@ fn vote_cancel_issue_fails_on_completed_issue() {
     contract.issues.insert(id, &issue);
 
     set_caller(account(4));
-    let result = contract.vote_cancel_issue(id, [0xCC; 32]);
+    let result =
+        contract.vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None);
     assert_eq!(result, Err(crate::Error::IssueAlreadyFinalized));
 }
 
@@ -983,10 +1299,11 @@ fn vote_cancel_issue_fails_on_cancelled_issue() {
     let id = register_test_issue(&mut contract);
 
     set_caller(account(1));
-    contract.cancel_issue(id).unwrap();
+    contract.cancel_issue(id, None).unwrap();
 
     set_caller(account(4));
-    let result = contract.vote_cancel_issue(id, [0xCC; 32]);
+    let result =
+        contract.vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None);
     assert_eq!(result, Err(crate::Error::IssueAlreadyFinalized));
 }
 
@@ -999,7 +1316,8 @@ fn vote_cancel_issue_fails_already_voted() {
     contract.cancel_issue_voters.insert((id, account(4)), &true);
 
     set_caller(account(4));
-    let result = contract.vote_cancel_issue(id, [0xCC; 32]);
+    let result =
+        contract.vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None);
     assert_eq!(result, Err(crate::Error::AlreadyVoted));
 }
 
@@ -1082,25 +1400,33 @@ fn get_or_create_solution_vote_returns_existing() {
         solver_coldkey: account(5),
         pr_number: 42,
         votes_count: 3,
+        voters: Vec::new(),
+        whitelist_version: 0,
+        vote_started_at_block: 0,
     };
-    contract.solution_votes.insert(1, &existing);
+    contract.solution_votes.insert(1, &vec![existing]);
 
-    let vote = contract.get_or_create_solution_vote(
-        1,
-        account(7), // different solver -- should be ignored
-        99,         // different pr -- should be ignored
-        account(8),
-    );
+    let vote = contract.get_or_create_solution_vote(1, account(6), 42, account(5));
 
     // Should return the stored vote, not create a new one
     assert_eq!(vote.solver_hotkey, account(6));
     assert_eq!(vote.votes_count, 3);
+
+    // A different candidate starts its own tally
+    let other = contract.get_or_create_solution_vote(1, account(7), 99, account(8));
+    assert_eq!(other.solver_hotkey, account(7));
+    assert_eq!(other.votes_count, 0);
 }
 
 #[ink::test]
 fn get_or_create_cancel_issue_vote_creates_new() {
     let mut contract = create_default_contract();
-    let vote = contract.get_or_create_cancel_issue_vote(1, [0xCC; 32]);
+    let vote = contract.get_or_create_cancel_issue_vote(
+        1,
+        [0xCC; 32],
+        None,
+        crate::CancelDestination::Recycle,
+    );
 
     assert_eq!(vote.issue_id, 1);
     assert_eq!(vote.reason_hash, [0xCC; 32]);
@@ -1114,15 +1440,24 @@ fn get_or_create_cancel_issue_vote_returns_existing() {
     let existing = crate::CancelVote {
         issue_id: 1,
         reason_hash: [0xCC; 32],
+        reason: None,
+        destination: crate::CancelDestination::Recycle,
         votes_count: 2,
+        voters: Vec::new(),
+        whitelist_version: 0,
+        vote_started_at_block: 0,
     };
     contract.cancel_issue_votes.insert(1, &existing);
 
     let vote = contract.get_or_create_cancel_issue_vote(
-        1, [0xFF; 32], // different hash -- should be ignored
+        1,
+        [0xFF; 32], // different hash -- should be ignored
+        None,
+        crate::CancelDestination::Pool,
     );
 
     assert_eq!(vote.reason_hash, [0xCC; 32]);
+    assert_eq!(vote.destination, crate::CancelDestination::Recycle);
     assert_eq!(vote.votes_count, 2);
 }
 
@@ -1139,8 +1474,11 @@ fn clear_solution_vote_removes_record() {
         solver_coldkey: account(5),
         pr_number: 42,
         votes_count: 1,
+        voters: Vec::new(),
+        whitelist_version: 0,
+        vote_started_at_block: 0,
     };
-    contract.solution_votes.insert(1, &vote);
+    contract.solution_votes.insert(1, &vec![vote]);
 
     contract.clear_solution_vote(1);
     assert!(contract.solution_votes.get(1).is_none());
@@ -1152,7 +1490,12 @@ fn clear_cancel_issue_vote_removes_record() {
     let vote = crate::CancelVote {
         issue_id: 1,
         reason_hash: [0xCC; 32],
+        reason: None,
+        destination: crate::CancelDestination::Recycle,
         votes_count: 1,
+        voters: Vec::new(),
+        whitelist_version: 0,
+        vote_started_at_block: 0,
     };
     contract.cancel_issue_votes.insert(1, &vote);
 
@@ -1169,24 +1512,27 @@ fn set_owner_transfers_authority() {
     let mut contract = create_default_contract();
 
     // Transfer ownership to account(4)
-    set_caller(account(1));
-    contract.set_owner(account(4)).unwrap();
+    apply_config_change(&mut contract, crate::ScheduledChange::Owner(account(4)));
 
     // Old owner can no longer act
     set_caller(account(1));
-    assert_eq!(contract.set_owner(account(1)), Err(crate::Error::NotOwner));
+    assert_eq!(
+        contract.schedule_config_change(crate::ScheduledChange::Owner(account(1))),
+        Err(crate::Error::NotOwner)
+    );
 
     // New owner can act
     set_caller(account(4));
-    assert!(contract.set_owner(account(4)).is_ok());
+    assert!(contract
+        .schedule_config_change(crate::ScheduledChange::Owner(account(5)))
+        .is_ok());
 }
 
 #[ink::test]
 fn new_owner_can_register_issues() {
     let mut contract = create_default_contract();
 
-    set_caller(account(1));
-    contract.set_owner(account(4)).unwrap();
+    apply_config_change(&mut contract, crate::ScheduledChange::Owner(account(4)));
 
     // New owner registers an issue
     set_caller(account(4));
@@ -1195,6 +1541,8 @@ fn new_owner_can_register_issues() {
         String::from("org/repo"),
         1,
         MIN_BOUNTY,
+        None,
+        None,
     );
     assert!(result.is_ok());
 
@@ -1205,6 +1553,8 @@ fn new_owner_can_register_issues() {
         String::from("org/repo"),
         2,
         MIN_BOUNTY,
+        None,
+        None,
     );
     assert_eq!(result, Err(crate::Error::NotOwner));
 }
@@ -1224,6 +1574,8 @@ fn get_total_committed_sums_multiple_issues() {
             String::from("org/repo"),
             1,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1233,6 +1585,8 @@ fn get_total_committed_sums_multiple_issues() {
             String::from("org/repo"),
             2,
             MIN_BOUNTY * 2,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1329,6 +1683,8 @@ fn fill_bounties_with_different_target_amounts() {
             String::from("org/repo"),
             1,
             MIN_BOUNTY,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1339,6 +1695,8 @@ fn fill_bounties_with_different_target_amounts() {
             String::from("org/repo"),
             2,
             MIN_BOUNTY * 5,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1356,6 +1714,58 @@ fn fill_bounties_with_different_target_amounts() {
     assert_eq!(contract.get_alpha_pool(), 0);
 }
 
+#[ink::test]
+fn fill_bounties_reports_full_and_partial_fills() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+
+    contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/1"),
+            String::from("org/repo"),
+            1,
+            MIN_BOUNTY,
+            None,
+            None,
+        )
+        .unwrap();
+
+    contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/2"),
+            String::from("org/repo"),
+            2,
+            MIN_BOUNTY * 2,
+            None,
+            None,
+        )
+        .unwrap();
+
+    contract.alpha_pool = MIN_BOUNTY + MIN_BOUNTY / 2;
+    let report = contract.fill_bounties();
+
+    assert_eq!(
+        report.fully_filled,
+        vec![crate::BountyFill {
+            issue_id: 1,
+            repository_hash: contract.hash_string("org/repo"),
+            amount: MIN_BOUNTY,
+            total: MIN_BOUNTY,
+            target: MIN_BOUNTY,
+        }]
+    );
+    assert_eq!(
+        report.partially_filled,
+        vec![crate::BountyFill {
+            issue_id: 2,
+            repository_hash: contract.hash_string("org/repo"),
+            amount: MIN_BOUNTY / 2,
+            total: MIN_BOUNTY / 2,
+            target: MIN_BOUNTY * 2,
+        }]
+    );
+}
+
 #[ink::test]
 fn fill_bounties_fully_funded_removed_from_queue() {
     let mut contract = create_default_contract();
@@ -1384,7 +1794,7 @@ fn cancel_issue_succeeds_on_active_issue() {
     contract.issues.insert(id, &issue);
 
     set_caller(account(1));
-    assert!(contract.cancel_issue(id).is_ok());
+    assert!(contract.cancel_issue(id, None).is_ok());
 
     let issue = contract.get_issue(id).unwrap();
     assert_eq!(issue.status, crate::IssueStatus::Cancelled);
@@ -1459,6 +1869,52 @@ fn vote_solution_succeeds_and_completes_issue() {
     assert_eq!(issue.solver_coldkey, Some(account(5)));
 }
 
+#[ink::test]
+fn vote_solution_emits_issue_completed_with_pr_metadata() {
+    let (mut contract, id) = setup_active_issue_with_mock();
+
+    set_caller(account(4));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+
+    // IssueCompleted is followed by IssueLifecycleSummary
+    let events = test::recorded_events().collect::<Vec<_>>();
+    let last = &events[events.len() - 2];
+    let decoded = <crate::events::IssueCompleted as scale::Decode>::decode(&mut &last.data[..])
+        .expect("last event should be IssueCompleted");
+    assert_eq!(decoded.issue_id, id);
+    assert_eq!(decoded.solver_hotkey, account(6));
+    assert_eq!(decoded.solver_coldkey, account(5));
+    assert_eq!(decoded.repository_full_name, "org/repo");
+    assert_eq!(decoded.pr_number, 42);
+}
+
+#[ink::test]
+fn vote_solution_emits_lifecycle_summary() {
+    set_block(10);
+    let (mut contract, id) = setup_active_issue_with_mock();
+
+    set_caller(account(4));
+    set_block(250);
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+
+    let last = test::recorded_events().last().expect("summary event");
+    let summary =
+        <crate::events::IssueLifecycleSummary as scale::Decode>::decode(&mut &last.data[..])
+            .expect("last event should be IssueLifecycleSummary");
+    assert_eq!(summary.issue_id, id);
+    assert_eq!(summary.solver_hotkey, account(6));
+    assert_eq!(summary.registered_at_block, 10);
+    assert_eq!(summary.completed_at_block, 250);
+    assert_eq!(summary.blocks_open, 240);
+    assert_eq!(summary.target_bounty, MIN_BOUNTY);
+    assert_eq!(summary.total_funding, 0);
+    assert_eq!(summary.paid_out, 0);
+}
+
 #[ink::test]
 fn vote_solution_removes_issue_from_bounty_queue() {
     let (mut contract, id) = setup_active_issue_with_mock();
@@ -1534,7 +1990,8 @@ fn vote_cancel_issue_succeeds_on_registered_issue() {
     contract.add_validator(account(4)).unwrap();
 
     set_caller(account(4));
-    let result = contract.vote_cancel_issue(id, [0xCC; 32]);
+    let result =
+        contract.vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None);
     assert!(result.is_ok());
 
     // With 1 whitelisted validator, one vote cancels
@@ -1550,13 +2007,41 @@ fn vote_cancel_issue_succeeds_on_active_issue() {
     // without calling call_runtime
 
     set_caller(account(4));
-    let result = contract.vote_cancel_issue(id, [0xCC; 32]);
+    let result =
+        contract.vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None);
     assert!(result.is_ok());
 
     let issue = contract.get_issue(id).unwrap();
     assert_eq!(issue.status, crate::IssueStatus::Cancelled);
 }
 
+#[ink::test]
+fn vote_cancel_issue_to_pool_releases_bounty() {
+    let (mut contract, id) = setup_active_issue_with_mock();
+
+    // A funded issue: releasing to the pool needs no runtime call
+    let mut issue = contract.issues.get(id).unwrap();
+    issue.bounty_amount = MIN_BOUNTY;
+    contract.issues.insert(id, &issue);
+
+    set_caller(account(4));
+    contract
+        .vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Pool, None)
+        .unwrap();
+
+    let issue = contract.get_issue(id).unwrap();
+    assert_eq!(issue.status, crate::IssueStatus::Cancelled);
+    assert_eq!(issue.bounty_amount, 0);
+    assert_eq!(contract.get_alpha_pool(), MIN_BOUNTY);
+    assert_eq!(contract.get_total_committed(), 0);
+
+    let last = test::recorded_events().last().unwrap();
+    let decoded = <crate::events::IssueCancelled as scale::Decode>::decode(&mut &last.data[..])
+        .expect("last event should be IssueCancelled");
+    assert_eq!(decoded.returned_bounty, MIN_BOUNTY);
+    assert_eq!(decoded.destination, crate::CancelDestination::Pool);
+}
+
 #[ink::test]
 fn vote_cancel_issue_removes_from_bounty_queue() {
     register_mock_extension();
@@ -1570,7 +2055,9 @@ fn vote_cancel_issue_removes_from_bounty_queue() {
     assert!(contract.get_bounty_queue().contains(&id));
 
     set_caller(account(4));
-    contract.vote_cancel_issue(id, [0xCC; 32]).unwrap();
+    contract
+        .vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None)
+        .unwrap();
 
     assert!(!contract.get_bounty_queue().contains(&id));
 }
@@ -1586,7 +2073,9 @@ fn vote_cancel_issue_clears_vote_record_after_consensus() {
     contract.add_validator(account(4)).unwrap();
 
     set_caller(account(4));
-    contract.vote_cancel_issue(id, [0xCC; 32]).unwrap();
+    contract
+        .vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None)
+        .unwrap();
 
     assert!(contract.cancel_issue_votes.get(id).is_none());
 }
@@ -1602,7 +2091,9 @@ fn vote_cancel_issue_records_voter() {
     contract.add_validator(account(4)).unwrap();
 
     set_caller(account(4));
-    contract.vote_cancel_issue(id, [0xCC; 32]).unwrap();
+    contract
+        .vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None)
+        .unwrap();
 
     assert!(contract
         .cancel_issue_voters
@@ -1617,7 +2108,8 @@ fn vote_cancel_issue_fails_for_non_whitelisted_caller() {
 
     // account(4) is not whitelisted
     set_caller(account(4));
-    let result = contract.vote_cancel_issue(id, [0xCC; 32]);
+    let result =
+        contract.vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None);
     assert_eq!(result, Err(crate::Error::NotWhitelistedValidator));
 }
 
@@ -1681,37 +2173,121 @@ fn remove_validator_fails_not_whitelisted() {
 }
 
 #[ink::test]
-fn required_votes_scales_with_validator_count() {
+fn apply_as_validator_whitelists_staked_hotkey() {
+    register_mock_extension_with_stake(1_000);
     let mut contract = create_default_contract();
-    set_caller(account(1));
 
-    // 0 validators: (0/2)+1 = 1 (but consensus blocked by n==0 guard)
-    assert_eq!(contract.required_validator_votes(), 1);
+    set_caller(account(5));
+    assert_eq!(
+        contract.apply_as_validator(account(3)),
+        Err(crate::Error::ValidatorApplicationsClosed)
+    );
 
-    // 1 validator: (1/2)+1 = 1
-    contract.add_validator(account(3)).unwrap();
-    assert_eq!(contract.required_validator_votes(), 1);
+    set_caller(account(1));
+    contract.set_min_validator_stake(1_000).unwrap();
+    assert_eq!(contract.get_config().min_validator_stake, 1_000);
 
-    // 2 validators: (2/2)+1 = 2 (unanimity)
-    contract.add_validator(account(4)).unwrap();
-    assert_eq!(contract.required_validator_votes(), 2);
+    set_caller(account(5));
+    assert!(contract.apply_as_validator(account(3)).is_ok());
+    assert_eq!(contract.get_validators(), vec![account(3)]);
+    assert_eq!(
+        contract.apply_as_validator(account(3)),
+        Err(crate::Error::ValidatorAlreadyWhitelisted)
+    );
 
-    // 3 validators: (3/2)+1 = 2 (simple majority)
-    contract.add_validator(account(5)).unwrap();
-    assert_eq!(contract.required_validator_votes(), 2);
+    // The owner keeps removal rights over self-onboarded validators
+    set_caller(account(1));
+    contract.remove_validator(account(3)).unwrap();
+    assert!(contract.get_validators().is_empty());
+}
 
-    // 4 validators: (4/2)+1 = 3
-    contract.add_validator(account(6)).unwrap();
-    assert_eq!(contract.required_validator_votes(), 3);
+#[ink::test]
+fn apply_as_validator_fails_below_stake_bar() {
+    register_mock_extension_with_stake(999);
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.set_min_validator_stake(1_000).unwrap();
 
-    // 5 validators: (5/2)+1 = 3
-    contract.add_validator(account(7)).unwrap();
-    assert_eq!(contract.required_validator_votes(), 3);
+    set_caller(account(5));
+    assert_eq!(
+        contract.apply_as_validator(account(3)),
+        Err(crate::Error::InsufficientValidatorStake)
+    );
+    assert!(contract.get_validators().is_empty());
 }
 
-// ============================================================================
-// 3-Validator Majority Tests (2 of 3 required)
-// ============================================================================
+#[ink::test]
+fn apply_as_validator_counts_only_callers_stake() {
+    // The owner has plenty staked on the hotkey, the applicant does not
+    register_mock_extension_with_coldkey_stakes(&[
+        (account(1), 5_000),
+        (account(5), 999),
+        (account(6), 1_000),
+    ]);
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.set_min_validator_stake(1_000).unwrap();
+
+    set_caller(account(5));
+    assert_eq!(
+        contract.apply_as_validator(account(3)),
+        Err(crate::Error::InsufficientValidatorStake)
+    );
+
+    // A coldkey with no stake at all is refused too
+    set_caller(account(7));
+    assert_eq!(
+        contract.apply_as_validator(account(3)),
+        Err(crate::Error::InsufficientValidatorStake)
+    );
+
+    set_caller(account(6));
+    assert!(contract.apply_as_validator(account(3)).is_ok());
+    assert_eq!(contract.get_validators(), vec![account(3)]);
+}
+
+#[ink::test]
+fn set_min_validator_stake_fails_for_non_owner() {
+    let mut contract = create_default_contract();
+    set_caller(account(3));
+    assert_eq!(
+        contract.set_min_validator_stake(1_000),
+        Err(crate::Error::NotOwner)
+    );
+}
+
+#[ink::test]
+fn required_votes_scales_with_validator_count() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+
+    // 0 validators: (0/2)+1 = 1 (but consensus blocked by n==0 guard)
+    assert_eq!(contract.required_validator_votes(), 1);
+
+    // 1 validator: (1/2)+1 = 1
+    contract.add_validator(account(3)).unwrap();
+    assert_eq!(contract.required_validator_votes(), 1);
+
+    // 2 validators: (2/2)+1 = 2 (unanimity)
+    contract.add_validator(account(4)).unwrap();
+    assert_eq!(contract.required_validator_votes(), 2);
+
+    // 3 validators: (3/2)+1 = 2 (simple majority)
+    contract.add_validator(account(5)).unwrap();
+    assert_eq!(contract.required_validator_votes(), 2);
+
+    // 4 validators: (4/2)+1 = 3
+    contract.add_validator(account(6)).unwrap();
+    assert_eq!(contract.required_validator_votes(), 3);
+
+    // 5 validators: (5/2)+1 = 3
+    contract.add_validator(account(7)).unwrap();
+    assert_eq!(contract.required_validator_votes(), 3);
+}
+
+// ============================================================================
+// 3-Validator Majority Tests (2 of 3 required)
+// ============================================================================
 
 /// Helper: creates contract with 3 whitelisted validators and an Active issue.
 /// Uses accounts 3, 4, 5 as validators. bounty_amount = 0 to avoid call_runtime.
@@ -1748,7 +2324,7 @@ fn three_validators_one_vote_does_not_complete() {
 
     // Vote record should still exist (not cleared)
     assert!(contract.solution_votes.get(id).is_some());
-    let vote = contract.solution_votes.get(id).unwrap();
+    let vote = &contract.solution_votes.get(id).unwrap()[0];
     assert_eq!(vote.votes_count, 1);
 }
 
@@ -1774,20 +2350,81 @@ fn three_validators_two_votes_completes() {
     assert!(contract.solution_votes.get(id).is_none());
 }
 
+#[ink::test]
+fn competing_solution_candidates_tally_separately() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+
+    // Validators disagree on the winning PR: neither candidate has quorum
+    set_caller(account(3));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    set_caller(account(4));
+    let tally = contract
+        .vote_solution(id, account(7), account(8), 43)
+        .unwrap();
+    assert_eq!(tally.votes, 1);
+    assert!(!tally.consensus_reached);
+
+    let candidates = contract.get_solution_votes(id);
+    assert_eq!(candidates.len(), 2);
+    assert_eq!(candidates[0].voters, vec![account(3)]);
+    assert_eq!(candidates[1].voters, vec![account(4)]);
+
+    // Third validator backs the second candidate, which wins
+    set_caller(account(5));
+    contract
+        .vote_solution(id, account(7), account(8), 43)
+        .unwrap();
+
+    let issue = contract.get_issue(id).unwrap();
+    assert_eq!(issue.status, crate::IssueStatus::Completed);
+    assert_eq!(issue.solver_hotkey, Some(account(7)));
+    assert_eq!(issue.winning_pr_number, Some(43));
+    assert!(contract.get_solution_votes(id).is_empty());
+}
+
+#[ink::test]
+fn validator_cannot_back_two_solution_candidates() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+
+    set_caller(account(3));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert_eq!(
+        contract.vote_solution(id, account(7), account(8), 43),
+        Err(crate::Error::AlreadyVoted)
+    );
+
+    // Revoking frees the validator to back another candidate
+    contract.revoke_solution_vote(id).unwrap();
+    contract
+        .vote_solution(id, account(7), account(8), 43)
+        .unwrap();
+    let candidates = contract.get_solution_votes(id);
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].pr_number, 43);
+}
+
 #[ink::test]
 fn three_validators_cancel_needs_two_votes() {
     let (mut contract, id) = setup_3_validator_active_issue();
 
     // First cancel vote: not enough
     set_caller(account(3));
-    contract.vote_cancel_issue(id, [0xCC; 32]).unwrap();
+    contract
+        .vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None)
+        .unwrap();
 
     let issue = contract.get_issue(id).unwrap();
     assert_eq!(issue.status, crate::IssueStatus::Active);
 
     // Second cancel vote: majority reached
     set_caller(account(4));
-    contract.vote_cancel_issue(id, [0xCC; 32]).unwrap();
+    contract
+        .vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None)
+        .unwrap();
 
     let issue = contract.get_issue(id).unwrap();
     assert_eq!(issue.status, crate::IssueStatus::Cancelled);
@@ -1809,6 +2446,67 @@ fn three_validators_third_vote_still_blocked_after_consensus() {
     assert_eq!(result, Err(crate::Error::IssueNotActive));
 }
 
+// ============================================================================
+// Idempotent Completion / Cancellation
+// ============================================================================
+
+#[ink::test]
+fn complete_issue_twice_fails_with_already_finalized() {
+    let (mut contract, id) = setup_active_issue_with_mock();
+
+    assert!(contract
+        .complete_issue(id, account(6), 42, account(5))
+        .is_ok());
+
+    // Second execution must not touch the completed issue
+    let result = contract.complete_issue(id, account(7), 99, account(8));
+    assert_eq!(result, Err(crate::Error::IssueAlreadyFinalized));
+
+    let issue = contract.get_issue(id).unwrap();
+    assert_eq!(issue.solver_hotkey, Some(account(6)));
+    assert_eq!(issue.winning_pr_number, Some(42));
+}
+
+#[ink::test]
+fn complete_issue_fails_on_cancelled_issue() {
+    let (mut contract, id) = setup_active_issue_with_mock();
+
+    contract
+        .execute_cancel_issue(id, [0xCC; 32], None, crate::CancelDestination::Recycle)
+        .unwrap();
+
+    let result = contract.complete_issue(id, account(6), 42, account(5));
+    assert_eq!(result, Err(crate::Error::IssueAlreadyFinalized));
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Cancelled
+    );
+}
+
+#[ink::test]
+fn execute_cancel_issue_fails_on_finalized_issue() {
+    let (mut contract, id) = setup_active_issue_with_mock();
+
+    contract
+        .complete_issue(id, account(6), 42, account(5))
+        .unwrap();
+
+    let result =
+        contract.execute_cancel_issue(id, [0xCC; 32], None, crate::CancelDestination::Recycle);
+    assert_eq!(result, Err(crate::Error::IssueAlreadyFinalized));
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Completed
+    );
+}
+
+#[ink::test]
+fn complete_issue_fails_for_nonexistent_issue() {
+    let mut contract = create_default_contract();
+    let result = contract.complete_issue(999, account(6), 42, account(5));
+    assert_eq!(result, Err(crate::Error::IssueNotFound));
+}
+
 // ============================================================================
 // Failed Payout → Harvest Recycling Protection
 // ============================================================================
@@ -1861,3 +2559,3950 @@ fn failed_payout_funds_not_recycled_by_harvest() {
         "bounty_amount must survive harvest for retry via payout_bounty"
     );
 }
+
+// ============================================================================
+// Harvest Fill Reporting
+// ============================================================================
+
+#[ink::test]
+fn harvest_counts_fills_from_report_and_emits_once() {
+    // 100 ALPHA stake split exactly between a 60 ALPHA and a 50 ALPHA target,
+    // so nothing is left over for recycling (call_runtime is unavailable off-chain).
+    register_mock_extension_with_stake(MOCK_STAKE);
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+
+    contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/1"),
+            String::from("org/repo"),
+            1,
+            60_000_000_000,
+            None,
+            None,
+        )
+        .unwrap();
+    contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/2"),
+            String::from("org/repo"),
+            2,
+            50_000_000_000,
+            None,
+            None,
+        )
+        .unwrap();
+
+    let events_before = test::recorded_events().count();
+    let result = contract.harvest_emissions().unwrap();
+    assert_eq!(result.harvested, MOCK_STAKE as u128);
+    assert_eq!(result.bounties_filled, 1);
+    assert_eq!(result.bounties_partially_filled, 1);
+    assert_eq!(result.recycled, 0);
+    let events_after_first = test::recorded_events().count();
+    assert!(events_after_first > events_before);
+
+    // Everything is now committed: a second harvest must report nothing new
+    // and must not re-emit BountyFilled for the already-active issue.
+    let result = contract.harvest_emissions().unwrap();
+    assert_eq!(result.bounties_filled, 0);
+    assert_eq!(result.bounties_partially_filled, 0);
+    assert_eq!(test::recorded_events().count(), events_after_first);
+}
+
+#[ink::test]
+fn harvest_emits_partial_fill_event() {
+    register_mock_extension_with_stake(MOCK_STAKE);
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+
+    // Target above the available stake: the whole harvest is a partial fill
+    contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/1"),
+            String::from("org/repo"),
+            1,
+            MOCK_STAKE as u128 * 2,
+            None,
+            None,
+        )
+        .unwrap();
+
+    let events_before = test::recorded_events().count();
+    let result = contract.harvest_emissions().unwrap();
+    assert_eq!(result.bounties_filled, 0);
+    assert_eq!(result.bounties_partially_filled, 1);
+
+    // BountyPartiallyFilled + EmissionsHarvested
+    let events: Vec<_> = test::recorded_events().skip(events_before).collect();
+    assert_eq!(events.len(), 2);
+
+    let decoded =
+        <crate::events::BountyPartiallyFilled as scale::Decode>::decode(&mut &events[0].data[..])
+            .expect("first event should be BountyPartiallyFilled");
+    assert_eq!(decoded.issue_id, 1);
+    assert_eq!(decoded.amount, MOCK_STAKE as u128);
+    assert_eq!(decoded.total, MOCK_STAKE as u128);
+    assert_eq!(decoded.target, MOCK_STAKE as u128 * 2);
+}
+
+// ============================================================================
+// Funding Progress
+// ============================================================================
+
+#[ink::test]
+fn get_funding_progress_none_for_unknown_issue() {
+    let contract = create_default_contract();
+    assert_eq!(contract.get_funding_progress(999), None);
+}
+
+#[ink::test]
+fn get_funding_progress_without_history_has_no_estimate() {
+    register_mock_extension_with_stake(0);
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+
+    let progress = contract.get_funding_progress(id).unwrap();
+    assert_eq!(progress.target, MIN_BOUNTY);
+    assert_eq!(progress.funded, 0);
+    assert_eq!(progress.percent_bps, 0);
+    assert_eq!(progress.queue_position, Some(0));
+    assert_eq!(progress.estimated_harvests_to_full, None);
+}
+
+#[ink::test]
+fn get_funding_progress_estimates_from_recent_harvests() {
+    register_mock_extension_with_stake(0);
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+
+    let id1 = contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/1"),
+            String::from("org/repo"),
+            1,
+            MIN_BOUNTY,
+            None,
+            None,
+        )
+        .unwrap();
+    let id2 = contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/2"),
+            String::from("org/repo"),
+            2,
+            MIN_BOUNTY,
+            None,
+            None,
+        )
+        .unwrap();
+
+    // First issue is a quarter funded
+    contract.alpha_pool = MIN_BOUNTY / 4;
+    contract.fill_bounties();
+
+    // Average harvest = MIN_BOUNTY / 4
+    contract.record_harvest(MIN_BOUNTY / 8);
+    contract.record_harvest(MIN_BOUNTY * 3 / 8);
+
+    let progress1 = contract.get_funding_progress(id1).unwrap();
+    assert_eq!(progress1.percent_bps, 2_500);
+    assert_eq!(progress1.queue_position, Some(0));
+    // 3/4 remaining at 1/4 per harvest
+    assert_eq!(progress1.estimated_harvests_to_full, Some(3));
+
+    let progress2 = contract.get_funding_progress(id2).unwrap();
+    assert_eq!(progress2.queue_position, Some(1));
+    // 3/4 ahead + its own full target at 1/4 per harvest
+    assert_eq!(progress2.estimated_harvests_to_full, Some(7));
+}
+
+#[ink::test]
+fn get_funding_progress_for_active_issue_is_complete() {
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+
+    contract.alpha_pool = MIN_BOUNTY;
+    contract.fill_bounties();
+
+    let progress = contract.get_funding_progress(id).unwrap();
+    assert_eq!(progress.percent_bps, 10_000);
+    assert_eq!(progress.queue_position, None);
+    assert_eq!(progress.estimated_harvests_to_full, None);
+}
+
+#[ink::test]
+fn record_harvest_keeps_bounded_history() {
+    let mut contract = create_default_contract();
+    for i in 0..(HARVEST_HISTORY_LEN as u128 + 3) {
+        contract.record_harvest(i);
+    }
+    assert_eq!(contract.recent_harvests.len(), HARVEST_HISTORY_LEN);
+    // Oldest entries are dropped first
+    assert_eq!(contract.recent_harvests[0].amount, 3);
+}
+
+#[ink::test]
+fn harvest_records_amount_in_history() {
+    register_mock_extension_with_stake(MOCK_STAKE);
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/1"),
+            String::from("org/repo"),
+            1,
+            MOCK_STAKE as u128,
+            None,
+            None,
+        )
+        .unwrap();
+
+    contract.harvest_emissions().unwrap();
+    assert_eq!(
+        contract.recent_harvests,
+        vec![crate::types::HarvestRecord {
+            block: 0,
+            amount: MOCK_STAKE as u128,
+        }]
+    );
+}
+
+#[ink::test]
+fn get_queue_position_and_alpha_ahead() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+
+    let id1 = contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/1"),
+            String::from("org/repo"),
+            1,
+            MIN_BOUNTY * 2,
+            None,
+            None,
+        )
+        .unwrap();
+    let id2 = contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/2"),
+            String::from("org/repo"),
+            2,
+            MIN_BOUNTY,
+            None,
+            None,
+        )
+        .unwrap();
+    let id3 = contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/3"),
+            String::from("org/repo"),
+            3,
+            MIN_BOUNTY,
+            None,
+            None,
+        )
+        .unwrap();
+
+    // Partially fund the head of the queue
+    contract.alpha_pool = MIN_BOUNTY / 2;
+    contract.fill_bounties();
+
+    assert_eq!(contract.get_queue_position(id1), Some(0));
+    assert_eq!(contract.get_queue_position(id2), Some(1));
+    assert_eq!(contract.get_queue_position(id3), Some(2));
+
+    assert_eq!(contract.get_alpha_ahead(id1), Some(0));
+    assert_eq!(contract.get_alpha_ahead(id2), Some(MIN_BOUNTY * 3 / 2));
+    assert_eq!(
+        contract.get_alpha_ahead(id3),
+        Some(MIN_BOUNTY * 3 / 2 + MIN_BOUNTY)
+    );
+
+    // Cancelling an issue ahead shrinks the wait for everyone behind it
+    contract.cancel_issue(id1, None).unwrap();
+    assert_eq!(contract.get_queue_position(id3), Some(1));
+    assert_eq!(contract.get_alpha_ahead(id3), Some(MIN_BOUNTY));
+}
+
+#[ink::test]
+fn get_queue_position_none_when_not_queued() {
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+
+    contract.alpha_pool = MIN_BOUNTY;
+    contract.fill_bounties();
+
+    assert_eq!(contract.get_queue_position(id), None);
+    assert_eq!(contract.get_alpha_ahead(id), None);
+    assert_eq!(contract.get_queue_position(999), None);
+}
+
+// ============================================================================
+// Validator Heartbeat / Inactivity
+// ============================================================================
+
+#[ink::test]
+fn heartbeat_fails_for_non_whitelisted_caller() {
+    let mut contract = create_default_contract();
+    set_caller(account(4));
+    assert_eq!(
+        contract.heartbeat(),
+        Err(crate::Error::NotWhitelistedValidator)
+    );
+}
+
+#[ink::test]
+fn heartbeat_updates_last_seen() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.add_validator(account(3)).unwrap();
+    assert_eq!(contract.get_validator_last_seen(account(3)), Some(0));
+
+    set_block(100);
+    set_caller(account(3));
+    contract.heartbeat().unwrap();
+    assert_eq!(contract.get_validator_last_seen(account(3)), Some(100));
+}
+
+#[ink::test]
+fn inactive_validators_excluded_from_quorum() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+    assert_eq!(contract.required_validator_votes(), 2);
+
+    // All three go silent past the inactivity window
+    set_block(VALIDATOR_INACTIVITY_BLOCKS + 1);
+    assert!(!contract.is_validator_active(account(3)));
+    assert_eq!(contract.active_validator_count(), 0);
+
+    // The first validator back is alone in the quorum, but the vote floor
+    // keeps a single vote from completing the issue
+    set_caller(account(3));
+    contract.heartbeat().unwrap();
+    assert!(contract.is_validator_active(account(3)));
+    assert_eq!(contract.required_validator_votes(), MIN_VOTES_REQUIRED);
+    let tally = contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert_eq!(tally.active_validators, 1);
+    assert!(!tally.consensus_reached);
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Active
+    );
+
+    // A second returning validator completes it without the silent third
+    set_caller(account(4));
+    assert!(
+        contract
+            .vote_solution(id, account(6), account(5), 42)
+            .unwrap()
+            .consensus_reached
+    );
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Completed
+    );
+}
+
+#[ink::test]
+fn vote_floor_is_capped_at_whitelist_size() {
+    let (mut contract, id) = setup_active_issue_with_mock();
+    assert_eq!(contract.required_validator_votes(), 1);
+
+    set_caller(account(4));
+    assert!(
+        contract
+            .vote_solution(id, account(6), account(5), 42)
+            .unwrap()
+            .consensus_reached
+    );
+}
+
+#[ink::test]
+fn voting_counts_as_activity() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+
+    set_block(500);
+    set_caller(account(4));
+    contract
+        .vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Recycle, None)
+        .unwrap();
+    assert_eq!(contract.get_validator_last_seen(account(4)), Some(500));
+}
+
+#[ink::test]
+fn remove_validator_clears_activity() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.add_validator(account(3)).unwrap();
+    contract.remove_validator(account(3)).unwrap();
+    assert_eq!(contract.get_validator_last_seen(account(3)), None);
+    assert!(!contract.is_validator_active(account(3)));
+}
+
+// ============================================================================
+// Owner Recovery
+// ============================================================================
+
+/// Helper: contract with validators 3, 4, 5 whitelisted (recovery needs 2 votes).
+fn setup_3_validators() -> IssueBountyManager {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.add_validator(account(3)).unwrap();
+    contract.add_validator(account(4)).unwrap();
+    contract.add_validator(account(5)).unwrap();
+    contract
+}
+
+#[ink::test]
+fn propose_owner_recovery_fails_for_non_validator() {
+    let mut contract = setup_3_validators();
+    set_caller(account(7));
+    assert_eq!(
+        contract.propose_owner_recovery(account(7)),
+        Err(crate::Error::NotWhitelistedValidator)
+    );
+}
+
+#[ink::test]
+fn propose_owner_recovery_fails_when_pending() {
+    let mut contract = setup_3_validators();
+    set_caller(account(3));
+    contract.propose_owner_recovery(account(8)).unwrap();
+
+    set_caller(account(4));
+    assert_eq!(
+        contract.propose_owner_recovery(account(9)),
+        Err(crate::Error::OwnerRecoveryPending)
+    );
+}
+
+#[ink::test]
+fn owner_recovery_requires_votes_and_timelock() {
+    let mut contract = setup_3_validators();
+    assert_eq!(contract.required_recovery_votes(), 2);
+
+    set_caller(account(3));
+    contract.propose_owner_recovery(account(8)).unwrap();
+    let recovery = contract.get_owner_recovery().unwrap();
+    assert_eq!(recovery.new_owner, account(8));
+    assert_eq!(recovery.votes_count, 1);
+
+    // One vote is not enough, even after the timelock
+    set_block(OWNER_RECOVERY_TIMELOCK_BLOCKS);
+    assert_eq!(
+        contract.execute_owner_recovery(),
+        Err(crate::Error::OwnerRecoveryNotApproved)
+    );
+
+    set_caller(account(4));
+    contract.vote_owner_recovery().unwrap();
+    assert_eq!(
+        contract.vote_owner_recovery(),
+        Err(crate::Error::AlreadyVoted)
+    );
+
+    // Approved and timelock elapsed: anyone can execute
+    set_caller(account(7));
+    contract.execute_owner_recovery().unwrap();
+    assert_eq!(contract.owner(), account(8));
+    assert_eq!(contract.get_owner_recovery(), None);
+}
+
+#[ink::test]
+fn owner_recovery_blocked_before_timelock() {
+    let mut contract = setup_3_validators();
+
+    set_caller(account(3));
+    contract.propose_owner_recovery(account(8)).unwrap();
+    set_caller(account(4));
+    contract.vote_owner_recovery().unwrap();
+
+    set_block(OWNER_RECOVERY_TIMELOCK_BLOCKS - 1);
+    assert_eq!(
+        contract.execute_owner_recovery(),
+        Err(crate::Error::OwnerRecoveryTimelocked)
+    );
+    assert_eq!(contract.owner(), account(1));
+}
+
+#[ink::test]
+fn owner_can_cancel_recovery() {
+    let mut contract = setup_3_validators();
+
+    set_caller(account(3));
+    contract.propose_owner_recovery(account(8)).unwrap();
+
+    set_caller(account(4));
+    assert_eq!(
+        contract.cancel_owner_recovery(),
+        Err(crate::Error::NotOwner)
+    );
+
+    set_caller(account(1));
+    contract.cancel_owner_recovery().unwrap();
+    assert_eq!(contract.get_owner_recovery(), None);
+    assert_eq!(
+        contract.execute_owner_recovery(),
+        Err(crate::Error::NoOwnerRecovery)
+    );
+
+    // Voter flags do not carry over to a new proposal
+    set_caller(account(4));
+    contract.propose_owner_recovery(account(9)).unwrap();
+    set_caller(account(3));
+    assert!(contract.vote_owner_recovery().is_ok());
+}
+
+#[ink::test]
+fn owner_recovery_drops_votes_of_removed_validators() {
+    let mut contract = setup_3_validators();
+
+    set_caller(account(3));
+    contract.propose_owner_recovery(account(8)).unwrap();
+    set_caller(account(4));
+    contract.vote_owner_recovery().unwrap();
+
+    // account(4)'s vote no longer counts once it leaves the whitelist
+    set_caller(account(1));
+    contract.remove_validator(account(4)).unwrap();
+    set_block(OWNER_RECOVERY_TIMELOCK_BLOCKS);
+    assert_eq!(
+        contract.execute_owner_recovery(),
+        Err(crate::Error::OwnerRecoveryNotApproved)
+    );
+    let recovery = contract.get_owner_recovery().unwrap();
+    assert_eq!(recovery.voters, vec![account(3)]);
+    assert_eq!(recovery.votes_count, 1);
+
+    set_caller(account(5));
+    contract.vote_owner_recovery().unwrap();
+    contract.execute_owner_recovery().unwrap();
+    assert_eq!(contract.owner(), account(8));
+}
+
+#[ink::test]
+fn owner_recovery_quorum_counts_inactive_validators() {
+    let mut contract = setup_3_validators();
+
+    // Every validator has gone quiet, but recovery still needs two of three
+    set_block(VALIDATOR_INACTIVITY_BLOCKS + 1);
+    assert_eq!(contract.active_validator_count(), 0);
+    assert_eq!(contract.required_recovery_votes(), 2);
+
+    set_caller(account(3));
+    contract.propose_owner_recovery(account(8)).unwrap();
+    advance_blocks(OWNER_RECOVERY_TIMELOCK_BLOCKS);
+    assert_eq!(
+        contract.execute_owner_recovery(),
+        Err(crate::Error::OwnerRecoveryNotApproved)
+    );
+}
+
+#[ink::test]
+fn owner_recovery_timelock_is_governed() {
+    let mut contract = setup_3_validators();
+    set_caller(account(1));
+    assert_eq!(
+        contract.schedule_config_change(crate::ScheduledChange::OwnerRecoveryTimelockBlocks(0)),
+        Err(crate::Error::InvalidConfig)
+    );
+    apply_config_change(
+        &mut contract,
+        crate::ScheduledChange::OwnerRecoveryTimelockBlocks(500),
+    );
+    assert_eq!(contract.get_config().owner_recovery_timelock_blocks, 500);
+
+    set_caller(account(3));
+    contract.propose_owner_recovery(account(8)).unwrap();
+    let recovery = contract.get_owner_recovery().unwrap();
+    assert_eq!(
+        recovery.executable_at_block,
+        recovery.proposed_at_block + 500
+    );
+
+    set_caller(account(4));
+    contract.vote_owner_recovery().unwrap();
+    advance_blocks(499);
+    assert_eq!(
+        contract.execute_owner_recovery(),
+        Err(crate::Error::OwnerRecoveryTimelocked)
+    );
+    advance_blocks(1);
+    contract.execute_owner_recovery().unwrap();
+    assert_eq!(contract.owner(), account(8));
+}
+
+#[ink::test]
+fn vote_owner_recovery_fails_without_proposal() {
+    let mut contract = setup_3_validators();
+    set_caller(account(3));
+    assert_eq!(
+        contract.vote_owner_recovery(),
+        Err(crate::Error::NoOwnerRecovery)
+    );
+}
+
+// ============================================================================
+// Issue Notes
+// ============================================================================
+
+#[ink::test]
+fn add_issue_note_stores_and_paginates() {
+    let (mut contract, id) = setup_active_issue_with_mock();
+
+    set_caller(account(4));
+    for i in 0..5u8 {
+        assert_eq!(contract.add_issue_note(id, [i; 32]), Ok(u32::from(i)));
+    }
+    assert_eq!(contract.get_issue_note_count(id), 5);
+
+    let page = contract.get_issue_notes(id, 1, 2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].note_hash, [1; 32]);
+    assert_eq!(page[1].note_hash, [2; 32]);
+    assert_eq!(page[0].author, account(4));
+
+    // Page running past the end is truncated
+    assert_eq!(contract.get_issue_notes(id, 4, 10).len(), 1);
+    assert!(contract.get_issue_notes(id, 5, 10).is_empty());
+}
+
+#[ink::test]
+fn add_issue_note_fails_for_non_validator() {
+    let (mut contract, id) = setup_active_issue_with_mock();
+    set_caller(account(7));
+    assert_eq!(
+        contract.add_issue_note(id, [0xAB; 32]),
+        Err(crate::Error::NotWhitelistedValidator)
+    );
+}
+
+#[ink::test]
+fn add_issue_note_fails_on_non_active_issue() {
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+    contract.add_validator(account(4)).unwrap();
+
+    set_caller(account(4));
+    assert_eq!(
+        contract.add_issue_note(id, [0xAB; 32]),
+        Err(crate::Error::IssueNotActive)
+    );
+}
+
+#[ink::test]
+fn add_issue_note_is_bounded() {
+    let (mut contract, id) = setup_active_issue_with_mock();
+
+    set_caller(account(4));
+    for _ in 0..MAX_NOTES_PER_ISSUE {
+        contract.add_issue_note(id, [0xAB; 32]).unwrap();
+    }
+    assert_eq!(
+        contract.add_issue_note(id, [0xAB; 32]),
+        Err(crate::Error::TooManyNotes)
+    );
+}
+
+// ============================================================================
+// Configuration Setter Tests
+// ============================================================================
+
+#[ink::test]
+fn get_config_reports_defaults() {
+    let contract = create_default_contract();
+    let config = contract.get_config();
+    assert_eq!(config.min_bounty, MIN_BOUNTY);
+    assert_eq!(config.max_validators, MAX_VALIDATORS);
+    assert_eq!(config.validator_count, 0);
+    assert_eq!(config.required_validator_votes_override, None);
+    assert_eq!(
+        config.validator_inactivity_blocks,
+        VALIDATOR_INACTIVITY_BLOCKS
+    );
+}
+
+#[ink::test]
+fn config_setters_require_owner() {
+    let mut contract = create_default_contract();
+    set_caller(account(7));
+    assert_eq!(contract.set_min_bounty(1), Err(crate::Error::NotOwner));
+    assert_eq!(contract.set_max_validators(8), Err(crate::Error::NotOwner));
+    assert_eq!(
+        contract.set_required_validator_votes(Some(1)),
+        Err(crate::Error::NotOwner)
+    );
+    assert_eq!(
+        contract.set_validator_inactivity_blocks(100),
+        Err(crate::Error::NotOwner)
+    );
+}
+
+#[ink::test]
+fn set_min_bounty_applies_to_registration() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.set_min_bounty(MIN_BOUNTY * 2).unwrap();
+
+    let result = contract.register_issue(
+        String::from("https://github.com/org/repo/issues/1"),
+        String::from("org/repo"),
+        1,
+        MIN_BOUNTY,
+        None,
+        None,
+    );
+    assert_eq!(result, Err(crate::Error::BountyTooLow));
+    assert_eq!(contract.set_min_bounty(0), Err(crate::Error::InvalidConfig));
+
+    let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+    let event = <crate::events::ConfigUpdated as scale::Decode>::decode(
+        &mut &events.last().unwrap().data[..],
+    )
+    .unwrap();
+    assert_eq!(event.field, crate::types::ConfigField::MinBounty);
+    assert_eq!(event.old_value, MIN_BOUNTY);
+    assert_eq!(event.new_value, MIN_BOUNTY * 2);
+}
+
+#[ink::test]
+fn set_max_validators_caps_whitelist() {
+    let mut contract = setup_3_validators();
+    set_caller(account(1));
+    assert_eq!(
+        contract.set_max_validators(2),
+        Err(crate::Error::InvalidConfig)
+    );
+    contract.set_max_validators(3).unwrap();
+    assert_eq!(
+        contract.add_validator(account(6)),
+        Err(crate::Error::TooManyValidators)
+    );
+    assert_eq!(contract.get_config().validator_count, 3);
+}
+
+#[ink::test]
+fn set_required_validator_votes_overrides_majority() {
+    let mut contract = setup_3_validators();
+    set_caller(account(1));
+    assert_eq!(contract.get_config().required_validator_votes, 2);
+
+    contract.set_required_validator_votes(Some(3)).unwrap();
+    assert_eq!(contract.get_config().required_validator_votes, 3);
+    assert_eq!(
+        contract.set_required_validator_votes(Some(0)),
+        Err(crate::Error::InvalidConfig)
+    );
+
+    contract.set_required_validator_votes(None).unwrap();
+    assert_eq!(contract.get_config().required_validator_votes, 2);
+}
+
+#[ink::test]
+fn min_votes_required_floors_every_threshold() {
+    let mut contract = setup_3_validators();
+    set_caller(account(1));
+    contract.set_min_votes_required(3).unwrap();
+    assert_eq!(contract.get_config().min_votes_required, 3);
+    assert_eq!(contract.get_config().required_validator_votes, 3);
+
+    // A lower fixed requirement is raised to the floor too
+    contract.set_required_validator_votes(Some(1)).unwrap();
+    assert_eq!(contract.get_config().required_validator_votes, 3);
+
+    assert_eq!(
+        contract.set_min_votes_required(0),
+        Err(crate::Error::InvalidConfig)
+    );
+    assert_eq!(
+        contract.set_min_votes_required(MAX_VALIDATORS + 1),
+        Err(crate::Error::InvalidConfig)
+    );
+    set_caller(account(3));
+    assert_eq!(
+        contract.set_min_votes_required(1),
+        Err(crate::Error::NotOwner)
+    );
+}
+
+#[ink::test]
+fn min_votes_required_stops_single_active_validator() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+    set_caller(account(1));
+    contract.set_min_votes_required(2).unwrap();
+
+    // Only account(3) is still active, but one vote is no longer enough
+    set_block(VALIDATOR_INACTIVITY_BLOCKS + 1);
+    set_caller(account(3));
+    let tally = contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert_eq!(tally.active_validators, 1);
+    assert_eq!(tally.required, 2);
+    assert!(!tally.consensus_reached);
+
+    set_caller(account(4));
+    assert!(
+        contract
+            .vote_solution(id, account(6), account(5), 42)
+            .unwrap()
+            .consensus_reached
+    );
+}
+
+#[ink::test]
+fn set_validator_inactivity_blocks_changes_activity_window() {
+    let mut contract = setup_3_validators();
+    set_caller(account(1));
+    contract.set_validator_inactivity_blocks(10).unwrap();
+
+    set_block(11);
+    assert!(!contract.is_validator_active(account(3)));
+    assert_eq!(
+        contract.set_validator_inactivity_blocks(0),
+        Err(crate::Error::InvalidConfig)
+    );
+}
+
+#[ink::test]
+fn netuid_change_updates_config() {
+    let mut contract = create_default_contract();
+    apply_config_change(
+        &mut contract,
+        crate::ScheduledChange::Netuid(TEST_NETUID + 1),
+    );
+    assert_eq!(contract.get_config().netuid, TEST_NETUID + 1);
+    assert_eq!(contract.get_alpha_pool(), 0);
+}
+
+// ============================================================================
+// Accounting Tests
+// ============================================================================
+
+#[ink::test]
+fn accounting_starts_at_zero() {
+    register_mock_extension_with_stake(0);
+    let contract = create_default_contract();
+    assert_eq!(
+        contract.get_accounting(),
+        crate::types::AccountingTotals::default()
+    );
+    assert!(contract.is_solvent());
+}
+
+#[ink::test]
+fn harvest_updates_accounting_totals() {
+    register_mock_extension_with_stake(MIN_BOUNTY as u64);
+    let mut contract = create_default_contract();
+    register_test_issue(&mut contract);
+
+    contract.harvest_emissions().unwrap();
+
+    let accounting = contract.get_accounting();
+    assert_eq!(accounting.total_harvested, MIN_BOUNTY);
+    assert_eq!(accounting.total_committed, MIN_BOUNTY);
+    assert_eq!(accounting.total_paid, 0);
+    assert_eq!(accounting.total_recycled, 0);
+    assert_eq!(accounting.outstanding_committed, MIN_BOUNTY);
+    assert_eq!(accounting.treasury_stake, MIN_BOUNTY);
+    assert!(contract.is_solvent());
+}
+
+#[ink::test]
+fn is_solvent_false_when_stake_below_commitments() {
+    register_mock_extension_with_stake(MIN_BOUNTY as u64);
+    let mut contract = create_default_contract();
+    register_test_issue(&mut contract);
+    contract.harvest_emissions().unwrap();
+
+    register_mock_extension_with_stake((MIN_BOUNTY - 1) as u64);
+    assert!(!contract.is_solvent());
+}
+
+// ============================================================================
+// Cancellation Reason Tests
+// ============================================================================
+
+#[ink::test]
+fn owner_cancel_records_reason() {
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+
+    set_caller(account(1));
+    contract
+        .cancel_issue(id, Some(String::from("duplicate of #2")))
+        .unwrap();
+
+    let record = contract.get_cancellation(id).unwrap();
+    assert_eq!(record.reason, Some(String::from("duplicate of #2")));
+    assert_eq!(record.reason_hash, contract.hash_string("duplicate of #2"));
+
+    let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+    let event = <crate::events::IssueCancelled as scale::Decode>::decode(
+        &mut &events.last().unwrap().data[..],
+    )
+    .unwrap();
+    assert_eq!(event.reason, Some(String::from("duplicate of #2")));
+}
+
+#[ink::test]
+fn vote_cancel_rejects_mismatched_proposal() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+    let reason_hash = contract.hash_string("out of scope");
+
+    set_caller(account(3));
+    assert_eq!(
+        contract.vote_cancel_issue(
+            id,
+            [0xCC; 32],
+            crate::CancelDestination::Pool,
+            Some(String::from("out of scope")),
+        ),
+        Err(crate::Error::ReasonHashMismatch)
+    );
+    contract
+        .vote_cancel_issue(
+            id,
+            reason_hash,
+            crate::CancelDestination::Pool,
+            Some(String::from("out of scope")),
+        )
+        .unwrap();
+    set_caller(account(4));
+    assert_eq!(
+        contract.vote_cancel_issue(id, [0xDD; 32], crate::CancelDestination::Pool, None),
+        Err(crate::Error::CancelProposalMismatch)
+    );
+    assert_eq!(
+        contract.vote_cancel_issue(id, reason_hash, crate::CancelDestination::Recycle, None),
+        Err(crate::Error::CancelProposalMismatch)
+    );
+    assert_eq!(contract.get_cancel_vote(id).unwrap().votes_count, 1);
+
+    // A matching vote reaches consensus with the first voter's reason text
+    contract
+        .vote_cancel_issue(id, reason_hash, crate::CancelDestination::Pool, None)
+        .unwrap();
+    let record = contract.get_cancellation(id).unwrap();
+    assert_eq!(record.reason_hash, reason_hash);
+    assert_eq!(record.reason, Some(String::from("out of scope")));
+}
+
+#[ink::test]
+fn cancel_reason_is_bounded() {
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+    let too_long = "x".repeat(MAX_CANCEL_REASON_LEN + 1);
+
+    set_caller(account(1));
+    assert_eq!(
+        contract.cancel_issue(id, Some(too_long)),
+        Err(crate::Error::ReasonTooLong)
+    );
+    assert!(contract.get_cancellation(id).is_none());
+}
+
+// ============================================================================
+// Claim Bounty Tests
+// ============================================================================
+
+/// Marks an issue Completed with an unpaid bounty and account(6) as solver coldkey.
+fn setup_unpaid_completed_issue(stake: u64) -> (IssueBountyManager, u64) {
+    register_mock_extension_with_stake(stake);
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+
+    let mut issue = contract.issues.get(id).unwrap();
+    issue.status = crate::IssueStatus::Completed;
+    issue.bounty_amount = MIN_BOUNTY;
+    issue.solver_coldkey = Some(account(6));
+    issue.solver_hotkey = Some(account(5));
+    contract.issues.insert(id, &issue);
+
+    (contract, id)
+}
+
+#[ink::test]
+fn complete_issue_skips_auto_payout_when_treasury_stake_insufficient() {
+    register_mock_extension_with_stake(0);
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+    let mut issue = contract.issues.get(id).unwrap();
+    issue.status = crate::IssueStatus::Active;
+    issue.bounty_amount = MIN_BOUNTY;
+    contract.issues.insert(id, &issue);
+
+    // The transfer is never dispatched, so the bounty stays for payout_bounty
+    contract
+        .complete_issue(id, account(5), 42, account(6))
+        .unwrap();
+    let issue = contract.get_issue(id).unwrap();
+    assert_eq!(issue.status, crate::IssueStatus::Completed);
+    assert_eq!(issue.bounty_amount, MIN_BOUNTY);
+    assert_eq!(contract.stake_op_count, 0);
+    assert_eq!(contract.total_paid, 0);
+}
+
+#[ink::test]
+fn claim_bounty_fails_for_other_callers() {
+    let (mut contract, id) = setup_unpaid_completed_issue(MOCK_STAKE);
+    set_caller(account(5));
+    assert_eq!(contract.claim_bounty(id), Err(crate::Error::NotSolver));
+}
+
+#[ink::test]
+fn claim_bounty_by_solver_checks_treasury() {
+    let (mut contract, id) = setup_unpaid_completed_issue(0);
+    set_caller(account(6));
+    assert_eq!(
+        contract.claim_bounty(id),
+        Err(crate::Error::InsufficientTreasuryBalance {
+            available: 0,
+            requested: MIN_BOUNTY,
+        })
+    );
+    assert_eq!(contract.get_issue(id).unwrap().bounty_amount, MIN_BOUNTY);
+}
+
+#[ink::test]
+fn claim_bounty_owner_fallback_is_authorized() {
+    let (mut contract, id) = setup_unpaid_completed_issue(0);
+    set_caller(account(1));
+    assert!(matches!(
+        contract.claim_bounty(id),
+        Err(crate::Error::InsufficientTreasuryBalance { .. })
+    ));
+}
+
+#[ink::test]
+fn claim_bounty_fails_when_already_paid() {
+    let (mut contract, id) = setup_unpaid_completed_issue(MOCK_STAKE);
+    let mut issue = contract.issues.get(id).unwrap();
+    issue.bounty_amount = 0;
+    contract.issues.insert(id, &issue);
+
+    set_caller(account(6));
+    assert_eq!(
+        contract.claim_bounty(id),
+        Err(crate::Error::BountyAlreadyPaid)
+    );
+}
+
+#[ink::test]
+fn claim_bounty_fails_for_nonexistent_issue() {
+    let mut contract = create_default_contract();
+    set_caller(account(6));
+    assert_eq!(contract.claim_bounty(99), Err(crate::Error::IssueNotFound));
+}
+
+// ============================================================================
+// Batch Tests
+// ============================================================================
+
+fn issue_input(number: u32) -> crate::types::IssueInput {
+    crate::types::IssueInput {
+        github_url: format!("https://github.com/org/repo/issues/{}", number),
+        repository_full_name: String::from("org/repo"),
+        issue_number: number,
+        target_bounty: MIN_BOUNTY,
+        difficulty: None,
+        label_hash: None,
+    }
+}
+
+#[ink::test]
+fn register_issues_returns_per_item_results() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+
+    let results = contract
+        .register_issues(vec![issue_input(1), issue_input(1), issue_input(2)])
+        .unwrap();
+
+    assert_eq!(
+        results,
+        vec![Ok(1), Err(crate::Error::IssueAlreadyExists), Ok(2)]
+    );
+    assert_eq!(contract.get_bounty_queue(), vec![1, 2]);
+}
+
+#[ink::test]
+fn register_issues_requires_owner() {
+    let mut contract = create_default_contract();
+    set_caller(account(7));
+    assert_eq!(
+        contract.register_issues(vec![issue_input(1)]),
+        Err(crate::Error::NotOwner)
+    );
+}
+
+#[ink::test]
+fn register_issues_rejects_oversized_batch() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    let inputs = (1..=MAX_BATCH_SIZE as u32 + 1).map(issue_input).collect();
+    assert_eq!(
+        contract.register_issues(inputs),
+        Err(crate::Error::BatchTooLarge)
+    );
+    assert_eq!(contract.next_issue_id(), 1);
+}
+
+#[ink::test]
+fn vote_solutions_batch_returns_per_item_results() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+    let vote = crate::types::SolutionVoteInput {
+        issue_id: id,
+        solver_hotkey: account(6),
+        solver_coldkey: account(5),
+        pr_number: 42,
+    };
+    let missing = crate::types::SolutionVoteInput {
+        issue_id: 99,
+        ..vote
+    };
+
+    set_caller(account(3));
+    let results = contract
+        .vote_solutions_batch(vec![vote, vote, missing])
+        .unwrap();
+
+    assert_eq!(
+        results,
+        vec![
+            Ok(crate::types::VoteTally {
+                votes: 1,
+                required: 2,
+                active_validators: 3,
+                consensus_reached: false,
+            }),
+            Err(crate::Error::AlreadyVoted),
+            Err(crate::Error::IssueNotFound),
+        ]
+    );
+    assert_eq!(contract.solution_votes.get(id).unwrap()[0].votes_count, 1);
+}
+
+#[ink::test]
+fn vote_solutions_batch_requires_validator() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+    set_caller(account(7));
+    let vote = crate::types::SolutionVoteInput {
+        issue_id: id,
+        solver_hotkey: account(6),
+        solver_coldkey: account(5),
+        pr_number: 42,
+    };
+    assert_eq!(
+        contract.vote_solutions_batch(vec![vote]),
+        Err(crate::Error::NotWhitelistedValidator)
+    );
+}
+
+#[ink::test]
+fn vote_solutions_batch_checks_solver_registration_once() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+    let ids: Vec<u64> = [id]
+        .into_iter()
+        .chain((2..=3).map(|n| register_issue_n(&mut contract, n)))
+        .collect();
+    for &issue_id in &ids {
+        let mut issue = contract.issues.get(issue_id).unwrap();
+        issue.status = crate::IssueStatus::Active;
+        contract.issues.insert(issue_id, &issue);
+    }
+    let calls = register_mock_extension_counting();
+
+    set_caller(account(3));
+    let votes = ids
+        .iter()
+        .map(|&issue_id| crate::types::SolutionVoteInput {
+            issue_id,
+            solver_hotkey: account(6),
+            solver_coldkey: account(5),
+            pr_number: 42,
+        })
+        .collect();
+    let results = contract.vote_solutions_batch(votes).unwrap();
+
+    assert!(results.iter().all(|r| r.is_ok()));
+    assert_eq!(calls.get(), 1);
+}
+
+// ============================================================================
+// Metadata Snapshot Tests
+// ============================================================================
+
+/// Checked-in ABI snapshot. Regenerate with
+/// `UPDATE_METADATA_SNAPSHOT=1 cargo test metadata_matches_snapshot`
+/// after an intentional change to messages, events, types or storage layout.
+const METADATA_SNAPSHOT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/metadata_snapshot.json");
+
+extern "Rust" {
+    fn __ink_generate_metadata() -> ink::metadata::InkProject;
+}
+
+/// Removes doc comments so wording changes do not count as ABI changes.
+fn strip_docs(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.remove("docs");
+            map.values_mut().for_each(strip_docs);
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(strip_docs),
+        _ => {}
+    }
+}
+
+#[test]
+fn metadata_matches_snapshot() {
+    // SAFETY: generated by #[ink::contract] in this crate under the std feature.
+    let project = unsafe { __ink_generate_metadata() };
+    let mut metadata = serde_json::to_value(project).unwrap();
+    strip_docs(&mut metadata);
+    let actual = serde_json::to_string_pretty(&metadata).unwrap() + "\n";
+
+    if std::env::var_os("UPDATE_METADATA_SNAPSHOT").is_some() {
+        std::fs::write(METADATA_SNAPSHOT_PATH, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(METADATA_SNAPSHOT_PATH).unwrap_or_default();
+    assert!(
+        actual == expected,
+        "contract metadata differs from metadata_snapshot.json; \
+         if the ABI change is intentional, rerun with UPDATE_METADATA_SNAPSHOT=1"
+    );
+}
+
+// ============================================================================
+// Scenario DSL
+// ============================================================================
+//
+// Scripts multi-step flows against the off-chain env and checks contract
+// invariants after every step:
+//
+//     Scenario::new(&[3, 4, 5])
+//         .register(3, MIN_BOUNTY)
+//         .stake(15 * ALPHA)
+//         .harvest()
+//         .vote_cancel(3, 1)
+//         .advance(100)
+//         .expect_status(1, crate::IssueStatus::Cancelled);
+//
+// The off-chain env cannot dispatch runtime calls, so scenarios must not
+// trigger a funded payout or a recycle (keep harvests fully allocated and
+// cancel funded issues to the pool).
+
+/// One ALPHA in raw units (9 decimals)
+const ALPHA: u128 = 1_000_000_000;
+
+struct Scenario {
+    contract: IssueBountyManager,
+    stake: u64,
+}
+
+impl Scenario {
+    /// Creates a contract with the given validators whitelisted and no stake.
+    fn new(validators: &[u8]) -> Self {
+        register_mock_extension_with_stake(0);
+        let mut contract = create_default_contract();
+        set_caller(account(1));
+        for &v in validators {
+            contract.add_validator(account(v)).unwrap();
+        }
+        let scenario = Self { contract, stake: 0 };
+        scenario.check_invariants();
+        scenario
+    }
+
+    /// Registers `count` issues with sequential issue numbers.
+    fn register(mut self, count: u32, target: Balance) -> Self {
+        set_caller(account(1));
+        for _ in 0..count {
+            let number = u32::try_from(self.contract.next_issue_id()).unwrap();
+            self.contract
+                .register_issue(
+                    format!("https://github.com/org/repo/issues/{}", number),
+                    String::from("org/repo"),
+                    number,
+                    target,
+                    None,
+                    None,
+                )
+                .unwrap();
+        }
+        self.checked()
+    }
+
+    /// Sets the treasury stake reported by the chain extension.
+    fn stake(mut self, amount: Balance) -> Self {
+        self.stake = u64::try_from(amount).unwrap();
+        register_mock_extension_with_stake(self.stake);
+        self.checked()
+    }
+
+    fn harvest(mut self) -> Self {
+        set_caller(account(9));
+        self.contract.harvest_emissions().unwrap();
+        self.checked()
+    }
+
+    fn vote_solution(mut self, validator: u8, issue_id: u64, pr_number: u32) -> Self {
+        set_caller(account(validator));
+        self.contract
+            .vote_solution(issue_id, account(6), account(5), pr_number)
+            .unwrap();
+        self.checked()
+    }
+
+    /// Votes to cancel, releasing any bounty back to the pool.
+    fn vote_cancel(mut self, validator: u8, issue_id: u64) -> Self {
+        set_caller(account(validator));
+        self.contract
+            .vote_cancel_issue(issue_id, [0xCC; 32], crate::CancelDestination::Pool, None)
+            .unwrap();
+        self.checked()
+    }
+
+    /// Moves the treasury to a new hotkey, which resets all funded bounties.
+    fn rotate_treasury(mut self, hotkey: u8) -> Self {
+        apply_config_change(
+            &mut self.contract,
+            crate::ScheduledChange::TreasuryHotkey(account(hotkey)),
+        );
+        self.checked()
+    }
+
+    fn heartbeat(mut self, validator: u8) -> Self {
+        set_caller(account(validator));
+        self.contract.heartbeat().unwrap();
+        self.checked()
+    }
+
+    fn advance(self, blocks: u32) -> Self {
+        advance_blocks(blocks);
+        self.checked()
+    }
+
+    fn expect_status(self, issue_id: u64, status: crate::IssueStatus) -> Self {
+        assert_eq!(self.issue(issue_id).status, status, "issue {}", issue_id);
+        self
+    }
+
+    fn expect_bounty(self, issue_id: u64, amount: Balance) -> Self {
+        assert_eq!(
+            self.issue(issue_id).bounty_amount,
+            amount,
+            "issue {}",
+            issue_id
+        );
+        self
+    }
+
+    fn issue(&self, issue_id: u64) -> Issue {
+        self.contract.get_issue(issue_id).unwrap()
+    }
+
+    fn checked(self) -> Self {
+        self.check_invariants();
+        self
+    }
+
+    /// Invariants that must hold after every step.
+    fn check_invariants(&self) {
+        let queue = self.contract.get_bounty_queue();
+        for (i, id) in queue.iter().enumerate() {
+            assert!(!queue[..i].contains(id), "issue {} queued twice", id);
+            assert!(
+                self.contract.is_modifiable(self.issue(*id).status),
+                "finalized issue {} left in queue",
+                id
+            );
+        }
+
+        for id in 1..self.contract.next_issue_id() {
+            let issue = self.issue(id);
+            assert!(
+                issue.bounty_amount <= issue.target_bounty,
+                "issue {} overfunded",
+                id
+            );
+            if issue.status == crate::IssueStatus::Cancelled {
+                assert_eq!(issue.bounty_amount, 0, "cancelled issue {} holds funds", id);
+            }
+        }
+
+        assert!(
+            self.contract.get_total_committed() <= u128::from(self.stake),
+            "commitments exceed treasury stake"
+        );
+    }
+}
+
+#[ink::test]
+fn scenario_cancelled_bounty_refunds_queue() {
+    Scenario::new(&[3, 4, 5])
+        .register(3, 10 * ALPHA)
+        .stake(15 * ALPHA)
+        .harvest()
+        .expect_status(1, crate::IssueStatus::Active)
+        .expect_bounty(2, 5 * ALPHA)
+        .vote_cancel(3, 1)
+        .vote_cancel(4, 1)
+        .expect_status(1, crate::IssueStatus::Cancelled)
+        .harvest()
+        .expect_status(2, crate::IssueStatus::Active)
+        .expect_bounty(3, 5 * ALPHA);
+}
+
+#[ink::test]
+fn scenario_inactive_validators_leave_quorum() {
+    Scenario::new(&[3, 4, 5])
+        .register(1, 10 * ALPHA)
+        .stake(10 * ALPHA)
+        .harvest()
+        .advance(VALIDATOR_INACTIVITY_BLOCKS + 1)
+        .heartbeat(3)
+        .heartbeat(4)
+        .vote_cancel(3, 1)
+        .expect_status(1, crate::IssueStatus::Active)
+        .vote_cancel(4, 1)
+        .expect_status(1, crate::IssueStatus::Cancelled);
+}
+
+#[ink::test]
+fn scenario_treasury_rotation_then_completion() {
+    Scenario::new(&[3, 4, 5])
+        .register(2, 10 * ALPHA)
+        .stake(10 * ALPHA)
+        .harvest()
+        .expect_status(1, crate::IssueStatus::Active)
+        .rotate_treasury(7)
+        .expect_bounty(1, 0)
+        .vote_solution(3, 1, 42)
+        .vote_solution(4, 1, 42)
+        .expect_status(1, crate::IssueStatus::Completed)
+        .expect_status(2, crate::IssueStatus::Registered);
+}
+
+// ============================================================================
+// Time-Dependent Tests
+// ============================================================================
+
+#[ink::test]
+fn advance_blocks_accumulates() {
+    set_block(10);
+    assert_eq!(advance_blocks(5), 15);
+    assert_eq!(advance_blocks(0), 15);
+}
+
+#[ink::test]
+fn block_numbers_are_recorded_on_issue_lifecycle() {
+    let mut contract = create_default_contract();
+    set_block(40);
+    let id = register_test_issue(&mut contract);
+    assert_eq!(contract.get_issue(id).unwrap().registered_at_block, 40);
+
+    advance_blocks(25);
+    set_caller(account(1));
+    contract.cancel_issue(id, None).unwrap();
+    assert_eq!(
+        contract.get_cancellation(id).unwrap().cancelled_at_block,
+        65
+    );
+}
+
+#[ink::test]
+fn harvest_records_block() {
+    register_mock_extension_with_stake(MIN_BOUNTY as u64);
+    let mut contract = create_default_contract();
+    register_test_issue(&mut contract);
+
+    set_block(1_000);
+    contract.harvest_emissions().unwrap();
+    assert_eq!(contract.get_last_harvest_block(), 1_000);
+}
+
+#[ink::test]
+fn validator_active_until_end_of_inactivity_window() {
+    let contract = setup_3_validators();
+    let joined = ink::env::block_number::<crate::CustomEnvironment>();
+
+    set_block(joined + VALIDATOR_INACTIVITY_BLOCKS);
+    assert!(contract.is_validator_active(account(3)));
+
+    advance_blocks(1);
+    assert!(!contract.is_validator_active(account(3)));
+}
+
+#[ink::test]
+fn heartbeat_extends_activity_window() {
+    let mut contract = setup_3_validators();
+
+    advance_blocks(VALIDATOR_INACTIVITY_BLOCKS);
+    set_caller(account(3));
+    contract.heartbeat().unwrap();
+
+    advance_blocks(VALIDATOR_INACTIVITY_BLOCKS);
+    assert!(contract.is_validator_active(account(3)));
+    assert!(!contract.is_validator_active(account(4)));
+}
+
+#[ink::test]
+fn owner_recovery_timelock_counts_from_proposal_block() {
+    let mut contract = setup_3_validators();
+
+    set_block(1_000);
+    set_caller(account(3));
+    contract.propose_owner_recovery(account(8)).unwrap();
+    set_caller(account(4));
+    contract.vote_owner_recovery().unwrap();
+
+    set_block(1_000 + OWNER_RECOVERY_TIMELOCK_BLOCKS - 1);
+    assert_eq!(
+        contract.execute_owner_recovery(),
+        Err(crate::Error::OwnerRecoveryTimelocked)
+    );
+
+    advance_blocks(1);
+    contract.execute_owner_recovery().unwrap();
+    assert_eq!(contract.owner(), account(8));
+}
+
+#[ink::test]
+fn issue_note_records_block() {
+    let (mut contract, id) = setup_active_issue_with_mock();
+    set_block(77);
+    set_caller(account(4));
+    contract.add_issue_note(id, [0xAB; 32]).unwrap();
+    assert_eq!(contract.get_issue_notes(id, 0, 1)[0].added_at_block, 77);
+}
+
+// ============================================================================
+// Multi-Validator Consensus Boundary Tests
+// ============================================================================
+
+/// Creates a contract with `n` whitelisted validators (account(10)..) and
+/// one Active, unfunded issue.
+fn setup_n_validator_active_issue(n: u8) -> (IssueBountyManager, u64) {
+    register_mock_extension();
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    for v in 0..n {
+        contract.add_validator(account(10 + v)).unwrap();
+    }
+    let id = register_active_issue(&mut contract, 1);
+    (contract, id)
+}
+
+/// Registers an unfunded issue with the given number and marks it Active.
+fn register_active_issue(contract: &mut IssueBountyManager, number: u32) -> u64 {
+    set_caller(account(1));
+    let id = contract
+        .register_issue(
+            format!("https://github.com/org/repo/issues/{}", number),
+            String::from("org/repo"),
+            number,
+            MIN_BOUNTY,
+            None,
+            None,
+        )
+        .unwrap();
+    let mut issue = contract.issues.get(id).unwrap();
+    issue.status = crate::IssueStatus::Active;
+    contract.issues.insert(id, &issue);
+    id
+}
+
+#[ink::test]
+fn solution_consensus_completes_exactly_at_majority() {
+    register_mock_extension();
+    let mut contract = create_default_contract();
+
+    for n in 1..=7u8 {
+        set_caller(account(1));
+        contract.add_validator(account(10 + n - 1)).unwrap();
+        let id = register_active_issue(&mut contract, u32::from(n));
+        let required = n / 2 + 1;
+
+        for v in 0..required {
+            assert_eq!(
+                contract.get_issue(id).unwrap().status,
+                crate::IssueStatus::Active,
+                "{} validators, {} votes",
+                n,
+                v
+            );
+            set_caller(account(10 + v));
+            contract
+                .vote_solution(id, account(6), account(5), 42)
+                .unwrap();
+        }
+
+        assert_eq!(
+            contract.get_issue(id).unwrap().status,
+            crate::IssueStatus::Completed,
+            "{} validators, {} votes",
+            n,
+            required
+        );
+    }
+}
+
+#[ink::test]
+fn cancel_consensus_completes_exactly_at_majority() {
+    register_mock_extension();
+    let mut contract = create_default_contract();
+
+    for n in 1..=7u8 {
+        set_caller(account(1));
+        contract.add_validator(account(10 + n - 1)).unwrap();
+        let id = register_active_issue(&mut contract, u32::from(n));
+        let required = n / 2 + 1;
+
+        for v in 0..required {
+            assert_eq!(
+                contract.get_issue(id).unwrap().status,
+                crate::IssueStatus::Active,
+                "{} validators, {} votes",
+                n,
+                v
+            );
+            set_caller(account(10 + v));
+            contract
+                .vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Pool, None)
+                .unwrap();
+        }
+
+        assert_eq!(
+            contract.get_issue(id).unwrap().status,
+            crate::IssueStatus::Cancelled,
+            "{} validators, {} votes",
+            n,
+            required
+        );
+    }
+}
+
+#[ink::test]
+fn duplicate_vote_does_not_count_towards_consensus() {
+    let (mut contract, id) = setup_n_validator_active_issue(4);
+
+    set_caller(account(10));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    for _ in 0..3 {
+        assert_eq!(
+            contract.vote_solution(id, account(6), account(5), 42),
+            Err(crate::Error::AlreadyVoted)
+        );
+    }
+
+    assert_eq!(contract.solution_votes.get(id).unwrap()[0].votes_count, 1);
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Active
+    );
+}
+
+#[ink::test]
+fn required_votes_override_sets_exact_threshold() {
+    let (mut contract, id) = setup_n_validator_active_issue(5);
+    set_caller(account(1));
+    contract.set_required_validator_votes(Some(5)).unwrap();
+
+    for v in 0..4 {
+        set_caller(account(10 + v));
+        contract
+            .vote_solution(id, account(6), account(5), 42)
+            .unwrap();
+    }
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Active
+    );
+
+    set_caller(account(14));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Completed
+    );
+}
+
+#[ink::test]
+fn inactive_validators_lower_the_threshold() {
+    let (mut contract, id) = setup_n_validator_active_issue(4);
+
+    // Only two of four validators stay active: majority of 2 is 2
+    advance_blocks(VALIDATOR_INACTIVITY_BLOCKS + 1);
+    set_caller(account(10));
+    contract.heartbeat().unwrap();
+    set_caller(account(11));
+    contract.heartbeat().unwrap();
+    assert_eq!(contract.get_config().required_validator_votes, 2);
+
+    set_caller(account(10));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    set_caller(account(11));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Completed
+    );
+}
+
+// ============================================================================
+// Event Topic Tests
+// ============================================================================
+
+#[ink::test]
+fn issue_registered_indexes_repository_and_url() {
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+
+    let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+    let event = events.last().unwrap();
+    // Signature topic plus three indexed fields
+    assert_eq!(event.topics.len(), 4);
+    assert_eq!(
+        event.topics[1],
+        id.encode().into_iter().chain([0u8; 24]).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        event.topics[2],
+        contract
+            .hash_string("https://github.com/org/repo/issues/1")
+            .to_vec()
+    );
+    assert_eq!(event.topics[3], contract.hash_string("org/repo").to_vec());
+}
+
+#[ink::test]
+fn bounty_filled_indexes_repository() {
+    register_mock_extension_with_stake(MIN_BOUNTY as u64);
+    let mut contract = create_default_contract();
+    register_test_issue(&mut contract);
+    contract.harvest_emissions().unwrap();
+
+    let signature = <crate::events::BountyFilled as ink::env::Event>::SIGNATURE_TOPIC.unwrap();
+    let event = ink::env::test::recorded_events()
+        .find(|ev| ev.topics[0] == signature.to_vec())
+        .unwrap();
+    assert_eq!(event.topics[2], contract.hash_string("org/repo").to_vec());
+
+    let decoded =
+        <crate::events::BountyFilled as scale::Decode>::decode(&mut &event.data[..]).unwrap();
+    assert_eq!(decoded.repository_hash, contract.hash_string("org/repo"));
+}
+
+// ============================================================================
+// URL Verification Tests
+// ============================================================================
+
+#[ink::test]
+fn verify_url_matches_registered_url_only() {
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+
+    assert!(contract.verify_url(id, String::from("https://github.com/org/repo/issues/1")));
+    assert!(!contract.verify_url(id, String::from("https://github.com/org/repo/issues/2")));
+    assert!(!contract.verify_url(99, String::from("https://github.com/org/repo/issues/1")));
+}
+
+#[ink::test]
+fn canonicalize_issue_url_normalizes_case_and_suffixes() {
+    let contract = create_default_contract();
+    let canonical = String::from("https://github.com/org/repo/issues/1");
+
+    for url in [
+        "https://github.com/org/repo/issues/1",
+        "HTTPS://GitHub.com/Org/Repo/issues/1",
+        "https://github.com/org/repo/issues/1/",
+        "https://github.com/org/repo/issues/1?utm_source=x",
+        "https://github.com/Org/repo/issues/1//#issuecomment-7",
+    ] {
+        let (result, hash) = contract.canonicalize_issue_url(String::from(url));
+        assert_eq!(result, canonical, "{url}");
+        assert_eq!(hash, contract.hash_string(&canonical));
+    }
+
+    // Only host and repo segments are case-insensitive
+    let (result, _) =
+        contract.canonicalize_issue_url(String::from("https://github.com/org/repo/Issues/1"));
+    assert_eq!(result, "https://github.com/org/repo/Issues/1");
+}
+
+#[ink::test]
+fn register_issue_dedupes_canonical_url() {
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+
+    set_caller(account(1));
+    assert_eq!(
+        contract.register_issue(
+            String::from("https://GitHub.com/Org/Repo/issues/1/?tab=comments"),
+            String::from("org/repo"),
+            1,
+            MIN_BOUNTY,
+            None,
+            None,
+        ),
+        Err(crate::Error::IssueAlreadyExists)
+    );
+
+    let (_, hash) =
+        contract.canonicalize_issue_url(String::from("https://github.com/ORG/REPO/issues/1"));
+    assert_eq!(contract.get_issue_by_url_hash(hash), id);
+    assert!(contract.verify_url(id, String::from("https://github.com/Org/Repo/issues/1/")));
+}
+
+// ============================================================================
+// Treasury Report Tests
+// ============================================================================
+
+#[ink::test]
+fn treasury_report_combines_stake_and_history() {
+    register_mock_extension_with_stake(MIN_BOUNTY as u64);
+    let mut contract = create_default_contract();
+    register_test_issue(&mut contract);
+
+    set_block(100);
+    contract.harvest_emissions().unwrap();
+
+    let report = contract.get_treasury_report();
+    assert_eq!(report.treasury_stake, MIN_BOUNTY);
+    assert_eq!(report.chain_emission, 0);
+    assert_eq!(report.total_harvested, MIN_BOUNTY);
+    assert_eq!(report.last_harvest_block, 100);
+    assert_eq!(report.recent_harvest_count, 1);
+    assert_eq!(report.average_harvest, MIN_BOUNTY);
+    assert_eq!(report.harvested_per_block, None);
+}
+
+#[ink::test]
+fn harvested_per_block_spans_recent_window() {
+    let mut contract = create_default_contract();
+
+    set_block(100);
+    contract.record_harvest(5_000);
+    set_block(200);
+    contract.record_harvest(1_000);
+    set_block(300);
+    contract.record_harvest(3_000);
+
+    // The first record only opens the window: (1_000 + 3_000) / 200 blocks
+    assert_eq!(contract.harvested_per_block(), Some(20));
+}
+
+#[ink::test]
+fn harvested_per_block_none_without_elapsed_blocks() {
+    let mut contract = create_default_contract();
+    assert_eq!(contract.harvested_per_block(), None);
+
+    set_block(100);
+    contract.record_harvest(5_000);
+    contract.record_harvest(1_000);
+    assert_eq!(contract.harvested_per_block(), None);
+}
+
+// ============================================================================
+// Stake Anomaly Tests
+// ============================================================================
+
+/// Funds one MIN_BOUNTY issue, then drops the treasury stake to `stake`.
+fn setup_funded_then_stake(stake: u64) -> IssueBountyManager {
+    register_mock_extension_with_stake(MIN_BOUNTY as u64);
+    let mut contract = create_default_contract();
+    register_test_issue(&mut contract);
+    contract.harvest_emissions().unwrap();
+    register_mock_extension_with_stake(stake);
+    contract
+}
+
+#[ink::test]
+fn harvest_pauses_on_stake_shortfall_beyond_threshold() {
+    let mut contract = setup_funded_then_stake((MIN_BOUNTY / 2) as u64);
+
+    let result = contract.harvest_emissions().unwrap();
+    assert_eq!(result.harvested, 0);
+    assert!(contract.get_config().harvest_paused);
+
+    let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+    let event = <crate::events::StakeAnomalyDetected as scale::Decode>::decode(
+        &mut &events.last().unwrap().data[..],
+    )
+    .unwrap();
+    assert_eq!(event.committed, MIN_BOUNTY);
+    assert_eq!(event.shortfall, MIN_BOUNTY / 2);
+
+    assert!(matches!(
+        contract.harvest_emissions(),
+        Err(crate::Error::HarvestPaused)
+    ));
+}
+
+#[ink::test]
+fn harvest_tolerates_shortfall_within_threshold() {
+    // 5% shortfall against the default 10% threshold
+    let mut contract = setup_funded_then_stake((MIN_BOUNTY * 95 / 100) as u64);
+    contract.harvest_emissions().unwrap();
+    assert!(!contract.get_config().harvest_paused);
+}
+
+#[ink::test]
+fn owner_acknowledgement_resumes_harvest() {
+    let mut contract = setup_funded_then_stake(0);
+    contract.harvest_emissions().unwrap();
+
+    set_caller(account(4));
+    assert_eq!(
+        contract.acknowledge_stake_anomaly(),
+        Err(crate::Error::NotOwner)
+    );
+
+    set_caller(account(1));
+    contract.acknowledge_stake_anomaly().unwrap();
+    assert!(!contract.get_config().harvest_paused);
+    assert_eq!(
+        contract.acknowledge_stake_anomaly(),
+        Err(crate::Error::NoStakeAnomaly)
+    );
+
+    // Disabling the check lets harvest proceed despite the shortfall
+    contract.set_stake_anomaly_threshold_bps(10_000).unwrap();
+    contract.harvest_emissions().unwrap();
+    assert!(!contract.get_config().harvest_paused);
+}
+
+#[ink::test]
+fn set_stake_anomaly_threshold_rejects_out_of_range() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    assert_eq!(
+        contract.set_stake_anomaly_threshold_bps(10_001),
+        Err(crate::Error::InvalidConfig)
+    );
+}
+
+// ============================================================================
+// Fill Budget Tests
+// ============================================================================
+
+/// Registers a MIN_BOUNTY issue with the given number as the owner.
+fn register_issue_n(contract: &mut IssueBountyManager, number: u32) -> u64 {
+    set_caller(account(1));
+    contract
+        .register_issue(
+            format!("https://github.com/org/repo/issues/{}", number),
+            String::from("org/repo"),
+            number,
+            MIN_BOUNTY,
+            None,
+            None,
+        )
+        .unwrap()
+}
+
+#[ink::test]
+fn harvest_resumes_fills_across_calls_when_budget_exhausted() {
+    register_mock_extension_with_stake((MIN_BOUNTY * 3) as u64);
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.set_max_fills_per_call(1).unwrap();
+    let ids: Vec<u64> = (1..=3)
+        .map(|n| register_issue_n(&mut contract, n))
+        .collect();
+
+    // Each harvest fills one entry and holds the rest back instead of recycling
+    for (filled, id) in ids.iter().enumerate() {
+        let result = contract.harvest_emissions().unwrap();
+        assert_eq!(result.bounties_filled, 1);
+        assert_eq!(result.recycled, 0);
+        assert_eq!(result.fills_pending, filled < 2);
+        assert_eq!(contract.get_issue(*id).unwrap().bounty_amount, MIN_BOUNTY);
+    }
+
+    assert!(contract.get_bounty_queue().is_empty());
+    // Carried-over funds are not counted as harvested twice
+    assert_eq!(contract.get_accounting().total_harvested, MIN_BOUNTY * 3);
+}
+
+#[ink::test]
+fn set_max_fills_per_call_rejects_zero_and_non_owner() {
+    let mut contract = create_default_contract();
+    set_caller(account(4));
+    assert_eq!(
+        contract.set_max_fills_per_call(10),
+        Err(crate::Error::NotOwner)
+    );
+
+    set_caller(account(1));
+    assert_eq!(
+        contract.set_max_fills_per_call(0),
+        Err(crate::Error::InvalidConfig)
+    );
+    contract.set_max_fills_per_call(10).unwrap();
+    assert_eq!(contract.get_config().max_fills_per_call, 10);
+}
+
+// ============================================================================
+// Bounded Status Scan Tests
+// ============================================================================
+
+#[ink::test]
+fn get_issues_by_status_pages_with_cursor() {
+    let mut contract = create_default_contract();
+    for n in 1..=5 {
+        register_active_issue(&mut contract, n);
+    }
+    let status = crate::IssueStatus::Active;
+
+    // Item limit: the cursor points at the first id not yet examined
+    let page = contract.get_issues_by_status(status, 1, 100, 2);
+    assert_eq!(
+        page.issues.iter().map(|i| i.id).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert_eq!(page.next_cursor, Some(3));
+
+    // Scan limit applies even when nothing matches
+    let page = contract.get_issues_by_status(crate::IssueStatus::Cancelled, 3, 2, 100);
+    assert!(page.issues.is_empty());
+    assert_eq!(page.next_cursor, Some(5));
+
+    let page = contract.get_issues_by_status(status, 5, 100, 100);
+    assert_eq!(page.issues.len(), 1);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[ink::test]
+fn get_issues_by_status_caps_scan_and_treats_zero_start_as_first() {
+    let mut contract = create_default_contract();
+    register_active_issue(&mut contract, 1);
+
+    let page = contract.get_issues_by_status(crate::IssueStatus::Active, 0, u32::MAX, 10);
+    assert_eq!(page.issues.len(), 1);
+    assert_eq!(page.next_cursor, None);
+}
+
+// ============================================================================
+// Vote Tally Tests
+// ============================================================================
+
+#[ink::test]
+fn vote_solution_returns_tally_progress() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+
+    set_caller(account(3));
+    let tally = contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert_eq!(tally.votes, 1);
+    assert_eq!(tally.required, 2);
+    assert_eq!(tally.active_validators, 3);
+    assert!(!tally.consensus_reached);
+
+    set_caller(account(4));
+    let tally = contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert_eq!(tally.votes, 2);
+    assert!(tally.consensus_reached);
+}
+
+#[ink::test]
+fn vote_cancel_issue_returns_tally_progress() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+
+    set_caller(account(3));
+    let tally = contract
+        .vote_cancel_issue(id, [0u8; 32], crate::types::CancelDestination::Pool, None)
+        .unwrap();
+    assert_eq!(tally.votes, 1);
+    assert_eq!(tally.required, 2);
+    assert!(!tally.consensus_reached);
+
+    set_caller(account(4));
+    let tally = contract
+        .vote_cancel_issue(id, [0u8; 32], crate::types::CancelDestination::Pool, None)
+        .unwrap();
+    assert!(tally.consensus_reached);
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Cancelled
+    );
+}
+
+// ============================================================================
+// Security-Critical Reserve Tests
+// ============================================================================
+
+#[ink::test]
+fn security_critical_issue_takes_reserved_share_ahead_of_fifo() {
+    register_mock_extension_with_stake((MIN_BOUNTY * 2) as u64);
+    let mut contract = create_default_contract();
+    let ids: Vec<u64> = (1..=3)
+        .map(|n| register_issue_n(&mut contract, n))
+        .collect();
+    contract.set_security_critical(ids[2], true).unwrap();
+
+    // 30% of 2 * MIN_BOUNTY goes to the flagged issue, FIFO splits the rest
+    contract.harvest_emissions().unwrap();
+    let bounty = |id: u64| contract.get_issue(id).unwrap().bounty_amount;
+    assert_eq!(bounty(ids[2]), MIN_BOUNTY * 6 / 10);
+    assert_eq!(bounty(ids[0]), MIN_BOUNTY);
+    assert_eq!(bounty(ids[1]), MIN_BOUNTY * 4 / 10);
+    assert_eq!(contract.get_bounty_queue(), vec![ids[1], ids[2]]);
+}
+
+#[ink::test]
+fn fully_funded_security_critical_issue_leaves_bounty_queue() {
+    register_mock_extension_with_stake((MIN_BOUNTY * 4) as u64);
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.set_security_reserve_bps(5_000).unwrap();
+    let ids: Vec<u64> = (1..=4)
+        .map(|n| register_issue_n(&mut contract, n))
+        .collect();
+    contract.set_security_critical(ids[3], true).unwrap();
+
+    let result = contract.harvest_emissions().unwrap();
+    assert_eq!(result.bounties_filled, 4);
+    assert!(contract.get_bounty_queue().is_empty());
+    assert!(contract.is_security_critical(ids[3]));
+}
+
+#[ink::test]
+fn finalized_or_funded_issues_leave_security_queue() {
+    let mut contract = create_default_contract();
+    let ids: Vec<u64> = (1..=4)
+        .map(|n| register_issue_n(&mut contract, n))
+        .collect();
+    for &id in &ids {
+        contract.set_security_critical(id, true).unwrap();
+    }
+    assert_eq!(contract.get_security_queue(), ids);
+
+    contract.cancel_issue(ids[0], None).unwrap();
+    contract
+        .execute_cancel_issue(ids[1], [0u8; 32], None, crate::CancelDestination::Pool)
+        .unwrap();
+    contract
+        .complete_issue(ids[2], account(6), 42, account(5))
+        .unwrap();
+    // An adjustment that leaves the issue fully funded activates it
+    let mut issue = contract.issues.get(ids[3]).unwrap();
+    issue.bounty_amount = issue.target_bounty + 1;
+    contract.release_excess_bounty(ids[3], &mut issue);
+
+    assert!(contract.get_security_queue().is_empty());
+    assert!(contract.get_bounty_queue().is_empty());
+}
+
+#[ink::test]
+fn fifo_funded_issue_leaves_security_queue() {
+    register_mock_extension_with_stake(MIN_BOUNTY as u64);
+    let mut contract = create_default_contract();
+    let id = register_issue_n(&mut contract, 1);
+    set_caller(account(1));
+    contract.set_security_reserve_bps(0).unwrap();
+    contract.set_security_critical(id, true).unwrap();
+    assert_eq!(contract.get_security_reserve(), 0);
+
+    contract.harvest_emissions().unwrap();
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Active
+    );
+    assert!(contract.get_security_queue().is_empty());
+}
+
+#[ink::test]
+fn get_security_reserve_reports_reserved_share_of_pool() {
+    let mut contract = create_default_contract();
+    contract.alpha_pool = MIN_BOUNTY;
+    assert_eq!(
+        contract.get_security_reserve(),
+        MIN_BOUNTY * u128::from(contract.get_config().security_reserve_bps) / 10_000
+    );
+    set_caller(account(1));
+    contract.set_security_reserve_bps(5_000).unwrap();
+    assert_eq!(contract.get_security_reserve(), MIN_BOUNTY / 2);
+}
+
+#[ink::test]
+fn set_security_critical_validates_caller_and_status() {
+    let (mut contract, active_id) = setup_active_issue_with_mock();
+    let id = register_issue_n(&mut contract, 2);
+
+    set_caller(account(4));
+    assert_eq!(
+        contract.set_security_critical(id, true),
+        Err(crate::Error::NotOwner)
+    );
+
+    set_caller(account(1));
+    assert_eq!(
+        contract.set_security_critical(active_id, true),
+        Err(crate::Error::IssueNotRegistered)
+    );
+    assert_eq!(
+        contract.set_security_critical(99, true),
+        Err(crate::Error::IssueNotFound)
+    );
+
+    contract.set_security_critical(id, true).unwrap();
+    assert!(contract.is_security_critical(id));
+    contract.set_security_critical(id, false).unwrap();
+    assert!(!contract.is_security_critical(id));
+    assert!(contract.security_queue.is_empty());
+}
+
+#[ink::test]
+fn set_security_reserve_bps_rejects_out_of_range() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    assert_eq!(
+        contract.set_security_reserve_bps(10_001),
+        Err(crate::Error::InvalidConfig)
+    );
+    contract.set_security_reserve_bps(0).unwrap();
+    assert_eq!(contract.get_config().security_reserve_bps, 0);
+}
+
+// ============================================================================
+// Scoring Snapshot Tests
+// ============================================================================
+
+#[ink::test]
+fn set_scoring_snapshot_records_once_for_completed_issue() {
+    let (mut contract, id) = setup_unpaid_completed_issue(MOCK_STAKE);
+    assert_eq!(contract.get_scoring_snapshot(id), None);
+
+    set_caller(account(1));
+    contract.set_scoring_snapshot(id, [7u8; 32]).unwrap();
+    assert_eq!(contract.get_scoring_snapshot(id), Some([7u8; 32]));
+
+    assert_eq!(
+        contract.set_scoring_snapshot(id, [8u8; 32]),
+        Err(crate::Error::ScoringSnapshotAlreadySet)
+    );
+    assert_eq!(contract.get_scoring_snapshot(id), Some([7u8; 32]));
+}
+
+#[ink::test]
+fn set_scoring_snapshot_rejects_non_owner_and_open_issues() {
+    let mut contract = create_default_contract();
+    let id = register_test_issue(&mut contract);
+
+    set_caller(account(4));
+    assert_eq!(
+        contract.set_scoring_snapshot(id, [7u8; 32]),
+        Err(crate::Error::NotOwner)
+    );
+
+    set_caller(account(1));
+    assert_eq!(
+        contract.set_scoring_snapshot(id, [7u8; 32]),
+        Err(crate::Error::BountyNotCompleted)
+    );
+}
+
+// ============================================================================
+// Whitelist Versioning Tests
+// ============================================================================
+
+#[ink::test]
+fn removed_validator_solution_vote_stops_counting() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+
+    set_caller(account(3));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+
+    set_caller(account(1));
+    contract.remove_validator(account(3)).unwrap();
+
+    // Only account(4)'s vote counts; 2 validators still need 2 votes
+    set_caller(account(4));
+    let tally = contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert_eq!(tally.votes, 1);
+    assert!(!tally.consensus_reached);
+    assert_eq!(
+        contract.solution_votes.get(id).unwrap()[0].voters,
+        vec![account(4)]
+    );
+
+    set_caller(account(5));
+    let tally = contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert!(tally.consensus_reached);
+}
+
+#[ink::test]
+fn removed_validator_cancel_vote_stops_counting() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+
+    set_caller(account(3));
+    contract
+        .vote_cancel_issue(id, [0u8; 32], crate::types::CancelDestination::Pool, None)
+        .unwrap();
+
+    set_caller(account(1));
+    contract.remove_validator(account(3)).unwrap();
+
+    set_caller(account(4));
+    let tally = contract
+        .vote_cancel_issue(id, [0u8; 32], crate::types::CancelDestination::Pool, None)
+        .unwrap();
+    assert_eq!(tally.votes, 1);
+    assert!(!tally.consensus_reached);
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Active
+    );
+}
+
+#[ink::test]
+fn readded_validator_vote_counts_once() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+
+    set_caller(account(3));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+
+    set_caller(account(1));
+    contract.remove_validator(account(3)).unwrap();
+    contract.add_validator(account(3)).unwrap();
+
+    // Voters are checked against the whitelist at the next vote, when
+    // account(3) is whitelisted again, so its vote is kept but not doubled
+    set_caller(account(3));
+    assert_eq!(
+        contract.vote_solution(id, account(6), account(5), 42),
+        Err(crate::Error::AlreadyVoted)
+    );
+    set_caller(account(4));
+    let tally = contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert_eq!(tally.votes, 2);
+    assert!(tally.consensus_reached);
+}
+
+// ============================================================================
+// Solver Registration Tests
+// ============================================================================
+
+#[ink::test]
+fn vote_solution_rejects_unregistered_solver() {
+    let (mut contract, id) = setup_active_issue_with_mock();
+    register_mock_extension_unregistered();
+
+    set_caller(account(4));
+    assert_eq!(
+        contract.vote_solution(id, account(6), account(5), 42),
+        Err(crate::Error::SolverNotRegistered)
+    );
+    assert!(contract.solution_votes.get(id).is_none());
+}
+
+// ============================================================================
+// Funding Deadline Tests
+// ============================================================================
+
+#[ink::test]
+fn harvest_cancels_issue_past_funding_deadline() {
+    register_mock_extension_with_stake(0);
+    let mut contract = create_default_contract();
+    let id = register_issue_n(&mut contract, 1);
+    contract.set_funding_deadline(id, Some(100)).unwrap();
+    assert_eq!(contract.get_funding_deadline(id), Some(100));
+
+    set_block(99);
+    assert_eq!(contract.harvest_emissions().unwrap().expired_issues, 0);
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Registered
+    );
+
+    set_block(100);
+    assert_eq!(contract.harvest_emissions().unwrap().expired_issues, 1);
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Cancelled
+    );
+    assert!(contract.get_bounty_queue().is_empty());
+    assert_eq!(
+        contract.get_cancellation(id).unwrap().reason.as_deref(),
+        Some(FUNDING_DEADLINE_REASON)
+    );
+}
+
+#[ink::test]
+fn expired_partial_bounty_returns_to_pool_for_next_issue() {
+    register_mock_extension_with_stake((MIN_BOUNTY / 2) as u64);
+    let mut contract = create_default_contract();
+    let late = register_issue_n(&mut contract, 1);
+    let next = register_issue_n(&mut contract, 2);
+    contract.set_funding_deadline(late, Some(50)).unwrap();
+
+    contract.harvest_emissions().unwrap();
+    assert_eq!(
+        contract.get_issue(late).unwrap().bounty_amount,
+        MIN_BOUNTY / 2
+    );
+
+    // The released half-bounty funds the next issue in the same harvest
+    // without being counted as harvested again
+    set_block(50);
+    let result = contract.harvest_emissions().unwrap();
+    assert_eq!(result.expired_issues, 1);
+    assert_eq!(
+        contract.get_issue(next).unwrap().bounty_amount,
+        MIN_BOUNTY / 2
+    );
+    assert_eq!(contract.get_accounting().total_harvested, MIN_BOUNTY / 2);
+}
+
+#[ink::test]
+fn funded_issue_is_not_cancelled_by_deadline() {
+    register_mock_extension_with_stake(MIN_BOUNTY as u64);
+    let mut contract = create_default_contract();
+    let id = register_issue_n(&mut contract, 1);
+    contract.set_funding_deadline(id, Some(10)).unwrap();
+    contract.harvest_emissions().unwrap();
+
+    set_block(20);
+    assert_eq!(contract.harvest_emissions().unwrap().expired_issues, 0);
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Active
+    );
+}
+
+#[ink::test]
+fn set_funding_deadline_validates_input() {
+    let mut contract = create_default_contract();
+    let id = register_issue_n(&mut contract, 1);
+    set_block(30);
+
+    set_caller(account(4));
+    assert_eq!(
+        contract.set_funding_deadline(id, Some(40)),
+        Err(crate::Error::NotOwner)
+    );
+
+    set_caller(account(1));
+    assert_eq!(
+        contract.set_funding_deadline(id, Some(30)),
+        Err(crate::Error::InvalidConfig)
+    );
+    contract.set_funding_deadline(id, Some(40)).unwrap();
+    contract.set_funding_deadline(id, None).unwrap();
+    assert_eq!(contract.get_funding_deadline(id), None);
+    assert!(contract.deadline_queue.is_empty());
+}
+
+// ============================================================================
+// Recycle Ratio Tests
+// ============================================================================
+
+#[ink::test]
+fn zero_recycle_bps_retains_leftover_for_later_issues() {
+    register_mock_extension_with_stake((MIN_BOUNTY * 2) as u64);
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.set_recycle_bps(0).unwrap();
+    register_issue_n(&mut contract, 1);
+
+    let result = contract.harvest_emissions().unwrap();
+    assert_eq!(result.recycled, 0);
+    assert_eq!(result.retained, MIN_BOUNTY);
+    let events = test::recorded_events().collect::<Vec<_>>();
+    let retained = <crate::events::EmissionsRetained as scale::Decode>::decode(
+        &mut &events[events.len() - 2].data[..],
+    )
+    .expect("EmissionsRetained precedes EmissionsHarvested");
+    assert_eq!(retained.amount, MIN_BOUNTY);
+
+    // The retained amount funds a later issue without being counted twice
+    let id = register_issue_n(&mut contract, 2);
+    let result = contract.harvest_emissions().unwrap();
+    assert_eq!(result.retained, 0);
+    assert_eq!(contract.get_issue(id).unwrap().bounty_amount, MIN_BOUNTY);
+    assert_eq!(contract.get_accounting().total_harvested, MIN_BOUNTY * 2);
+}
+
+#[ink::test]
+fn set_recycle_bps_rejects_out_of_range() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    assert_eq!(
+        contract.set_recycle_bps(10_001),
+        Err(crate::Error::InvalidConfig)
+    );
+    contract.set_recycle_bps(2_500).unwrap();
+    assert_eq!(contract.get_config().recycle_bps, 2_500);
+}
+
+// ============================================================================
+// Repository Allow-List Tests
+// ============================================================================
+
+#[ink::test]
+fn allowlist_restricts_registration_when_enabled() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.set_repo_allowlist_enabled(true).unwrap();
+    assert!(contract.get_config().repo_allowlist_enabled);
+
+    assert_eq!(
+        contract.register_issue(
+            String::from("https://github.com/org/repo/issues/1"),
+            String::from("org/repo"),
+            1,
+            MIN_BOUNTY,
+            None,
+            None,
+        ),
+        Err(crate::Error::RepositoryNotAllowed)
+    );
+
+    // Names are matched case-insensitively
+    contract
+        .add_allowed_repository(String::from("Org/Repo"))
+        .unwrap();
+    assert!(contract.is_repository_allowed(String::from("org/repo")));
+    register_test_issue(&mut contract);
+
+    contract
+        .remove_allowed_repository(String::from("org/repo"))
+        .unwrap();
+    assert!(!contract.is_repository_allowed(String::from("Org/Repo")));
+}
+
+#[ink::test]
+fn allowlist_is_not_enforced_when_disabled() {
+    let mut contract = create_default_contract();
+    register_test_issue(&mut contract);
+    assert!(!contract.is_repository_allowed(String::from("org/repo")));
+}
+
+#[ink::test]
+fn allowlist_management_is_owner_only() {
+    let mut contract = create_default_contract();
+    set_caller(account(4));
+    assert_eq!(
+        contract.add_allowed_repository(String::from("org/repo")),
+        Err(crate::Error::NotOwner)
+    );
+    assert_eq!(
+        contract.remove_allowed_repository(String::from("org/repo")),
+        Err(crate::Error::NotOwner)
+    );
+    assert_eq!(
+        contract.set_repo_allowlist_enabled(true),
+        Err(crate::Error::NotOwner)
+    );
+
+    set_caller(account(1));
+    assert_eq!(
+        contract.add_allowed_repository(String::from("not-a-repo")),
+        Err(crate::Error::InvalidRepositoryName)
+    );
+}
+
+// ============================================================================
+// Runtime Expectations Tests
+// ============================================================================
+
+#[ink::test]
+fn runtime_expectations_match_encoded_calls() {
+    let contract = create_default_contract();
+    let expected = contract.get_runtime_expectations();
+    assert_eq!(expected.netuid, TEST_NETUID);
+    assert_eq!(expected.chain_extension_id, 5001);
+    assert_eq!(expected.get_stake_info_func_id, 0);
+
+    // The reported indices are the ones actually written into dispatched calls
+    let transfer = RawCall::proxied_transfer_stake(
+        &account(1),
+        &account(5),
+        &account(2),
+        1,
+        1,
+        AlphaAmount::from(7),
+    );
+    assert_eq!(
+        &transfer.0[..2],
+        &[expected.proxy_pallet_index, expected.proxy_call_index]
+    );
+    assert_eq!(transfer.0[36], expected.proxy_type_transfer);
+    assert_eq!(
+        &transfer.0[37..39],
+        &[
+            expected.subtensor_module_pallet_index,
+            expected.transfer_stake_call_index
+        ]
+    );
+
+    let recycle = RawCall::proxied_recycle_alpha(&account(1), &account(2), AlphaAmount::from(7), 1);
+    assert_eq!(recycle.0[36], expected.proxy_type_non_critical);
+    assert_eq!(recycle.0[38], expected.recycle_alpha_call_index);
+}
+
+// ============================================================================
+// Emission Rate Tests
+// ============================================================================
+
+#[ink::test]
+fn emission_rate_spreads_epoch_emission_over_tempo() {
+    register_mock_extension_with_emission(3_600);
+    let mut contract = create_default_contract();
+    assert_eq!(contract.get_emission_rate(), Some(10));
+
+    set_caller(account(1));
+    contract.set_emission_tempo_blocks(100).unwrap();
+    assert_eq!(contract.get_emission_rate(), Some(36));
+    assert_eq!(
+        contract.set_emission_tempo_blocks(0),
+        Err(crate::Error::InvalidConfig)
+    );
+    assert_eq!(
+        contract.set_emission_tempo_blocks(MAX_EMISSION_TEMPO_BLOCKS + 1),
+        Err(crate::Error::InvalidConfig)
+    );
+    contract
+        .set_emission_tempo_blocks(MAX_EMISSION_TEMPO_BLOCKS)
+        .unwrap();
+}
+
+#[ink::test]
+fn funding_progress_estimates_blocks_from_emission_rate() {
+    register_mock_extension_with_emission(3_600);
+    let mut contract = create_default_contract();
+    let first = register_issue_n(&mut contract, 1);
+    let second = register_issue_n(&mut contract, 2);
+
+    let progress = contract.get_funding_progress(first).unwrap();
+    assert_eq!(
+        progress.estimated_blocks_to_full,
+        Some((MIN_BOUNTY / 10) as u32)
+    );
+    // The second issue waits for the first to fill
+    let progress = contract.get_funding_progress(second).unwrap();
+    assert_eq!(
+        progress.estimated_blocks_to_full,
+        Some((MIN_BOUNTY / 5) as u32)
+    );
+}
+
+#[ink::test]
+fn funding_progress_has_no_block_estimate_without_rate() {
+    register_mock_extension_with_stake(0);
+    let mut contract = create_default_contract();
+    let id = register_issue_n(&mut contract, 1);
+    assert_eq!(contract.get_emission_rate(), None);
+    assert_eq!(
+        contract
+            .get_funding_progress(id)
+            .unwrap()
+            .estimated_blocks_to_full,
+        None
+    );
+}
+
+// ============================================================================
+// Vote Getter Tests
+// ============================================================================
+
+#[ink::test]
+fn vote_getters_return_open_proposals() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+    assert!(contract.get_solution_votes(id).is_empty());
+    assert!(contract.get_cancel_vote(id).is_none());
+
+    set_caller(account(3));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    contract
+        .vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Pool, None)
+        .unwrap();
+
+    let solution = &contract.get_solution_votes(id)[0];
+    assert_eq!(solution.solver_hotkey, account(6));
+    assert_eq!(solution.pr_number, 42);
+    assert_eq!(solution.votes_count, 1);
+    assert_eq!(solution.voters, vec![account(3)]);
+
+    let cancel = contract.get_cancel_vote(id).unwrap();
+    assert_eq!(cancel.reason_hash, [0xCC; 32]);
+    assert_eq!(cancel.voters, vec![account(3)]);
+
+    // Consensus clears the proposal
+    set_caller(account(4));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert!(contract.get_solution_votes(id).is_empty());
+}
+
+#[ink::test]
+fn has_voted_reports_recorded_votes_per_kind() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+    let kind = crate::types::VoteKind::Solution;
+    assert!(!contract.has_voted(id, kind, account(3)));
+
+    set_caller(account(3));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert!(contract.has_voted(id, kind, account(3)));
+    assert!(!contract.has_voted(id, kind, account(4)));
+    assert!(!contract.has_voted(id, crate::types::VoteKind::Cancel, account(3)));
+}
+
+// ============================================================================
+// Vote Revocation Tests
+// ============================================================================
+
+#[ink::test]
+fn revoke_solution_vote_lowers_count_and_allows_revote() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+    let kind = crate::types::VoteKind::Solution;
+
+    set_caller(account(3));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    contract.revoke_solution_vote(id).unwrap();
+    assert!(!contract.has_voted(id, kind, account(3)));
+
+    // Last vote withdrawn: proposal is discarded, a different PR can be proposed
+    assert!(contract.get_solution_votes(id).is_empty());
+    contract
+        .vote_solution(id, account(6), account(5), 43)
+        .unwrap();
+    assert_eq!(contract.get_solution_votes(id)[0].pr_number, 43);
+}
+
+#[ink::test]
+fn revoke_solution_vote_keeps_other_voters() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+    // Add a fourth validator so two votes do not reach consensus (required = 3)
+    set_caller(account(1));
+    contract.add_validator(account(7)).unwrap();
+
+    set_caller(account(3));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    set_caller(account(4));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    contract.revoke_solution_vote(id).unwrap();
+
+    let vote = &contract.get_solution_votes(id)[0];
+    assert_eq!(vote.votes_count, 1);
+    assert_eq!(vote.voters, vec![account(3)]);
+    assert_eq!(
+        contract.get_issue(id).unwrap().status,
+        crate::IssueStatus::Active
+    );
+}
+
+#[ink::test]
+fn revoke_cancel_vote_clears_empty_proposal() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+
+    set_caller(account(3));
+    contract
+        .vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Pool, None)
+        .unwrap();
+    contract.revoke_cancel_vote(id).unwrap();
+    assert!(contract.get_cancel_vote(id).is_none());
+    assert!(!contract.has_voted(id, crate::types::VoteKind::Cancel, account(3)));
+}
+
+#[ink::test]
+fn revoke_without_vote_fails() {
+    let (mut contract, id) = setup_3_validator_active_issue();
+
+    set_caller(account(3));
+    assert_eq!(contract.revoke_solution_vote(id), Err(Error::NotVoted));
+    assert_eq!(contract.revoke_cancel_vote(id), Err(Error::NotVoted));
+
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    set_caller(account(4));
+    assert_eq!(contract.revoke_solution_vote(id), Err(Error::NotVoted));
+}
+
+// ============================================================================
+// Alpha Amount Tests
+// ============================================================================
+
+#[ink::test]
+fn alpha_amount_converts_within_u64() {
+    let amount = AlphaAmount::try_from(u128::from(u64::MAX)).unwrap();
+    assert_eq!(amount.get(), u64::MAX);
+    assert_eq!(u128::from(amount), u128::from(u64::MAX));
+    assert_eq!(AlphaAmount::try_from(0u128).unwrap().get(), 0);
+}
+
+#[ink::test]
+fn alpha_amount_rejects_values_above_u64() {
+    assert_eq!(
+        AlphaAmount::try_from(u128::from(u64::MAX) + 1),
+        Err(crate::Error::AmountOverflow)
+    );
+    assert_eq!(
+        AlphaAmount::try_from(u128::MAX),
+        Err(crate::Error::AmountOverflow)
+    );
+}
+
+#[ink::test]
+fn alpha_amount_encodes_little_endian_in_calls() {
+    let amount = AlphaAmount::from(0x0102_0304_0506_0708);
+    let call = RawCall::proxied_recycle_alpha(&account(1), &account(2), amount, 1);
+    // amount follows the 39-byte proxy header and the 32-byte hotkey
+    assert_eq!(&call.0[71..79], &0x0102_0304_0506_0708u64.to_le_bytes());
+}
+
+#[ink::test]
+fn recycle_refuses_amount_above_u64_without_dispatching() {
+    let mut contract = create_default_contract();
+    let amount = u128::from(u64::MAX) + 1;
+
+    // Would panic off-chain if a runtime call were dispatched
+    assert!(!contract.recycle(amount));
+    assert_eq!(contract.get_alpha_pool(), amount);
+    assert_eq!(contract.total_recycled, 0);
+}
+
+// ============================================================================
+// Verbose Harvest Tests
+// ============================================================================
+
+/// HarvestAllocation events recorded so far, as (issue_id, amount)
+fn harvest_allocations() -> Vec<(u64, u128)> {
+    let signature = <crate::events::HarvestAllocation as ink::env::Event>::SIGNATURE_TOPIC
+        .expect("event has a signature topic");
+    test::recorded_events()
+        .filter(|event| event.topics.first() == Some(&signature.to_vec()))
+        .map(|event| {
+            let decoded =
+                <crate::events::HarvestAllocation as scale::Decode>::decode(&mut &event.data[..])
+                    .expect("HarvestAllocation payload");
+            (decoded.issue_id, decoded.amount)
+        })
+        .collect()
+}
+
+#[ink::test]
+fn verbose_harvest_emits_allocation_per_issue() {
+    register_mock_extension_with_stake((MIN_BOUNTY * 3) as u64);
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract.set_verbose_harvest(true).unwrap();
+    assert!(contract.get_config().verbose_harvest);
+    let first = register_issue_n(&mut contract, 1);
+    let second = register_issue_n(&mut contract, 2);
+    let third = register_issue_n(&mut contract, 3);
+
+    contract.harvest_emissions().unwrap();
+
+    assert_eq!(
+        harvest_allocations(),
+        vec![
+            (first, MIN_BOUNTY),
+            (second, MIN_BOUNTY),
+            (third, MIN_BOUNTY)
+        ]
+    );
+}
+
+#[ink::test]
+fn harvest_allocations_not_emitted_by_default() {
+    register_mock_extension_with_stake((MIN_BOUNTY * 2) as u64);
+    let mut contract = create_default_contract();
+    register_issue_n(&mut contract, 1);
+    register_issue_n(&mut contract, 2);
+
+    contract.harvest_emissions().unwrap();
+    assert!(harvest_allocations().is_empty());
+}
+
+#[ink::test]
+fn set_verbose_harvest_requires_owner() {
+    let mut contract = create_default_contract();
+    set_caller(account(3));
+    assert_eq!(
+        contract.set_verbose_harvest(true),
+        Err(crate::Error::NotOwner)
+    );
+}
+
+#[ink::test]
+fn bounty_filled_event_snapshots_funding() {
+    register_mock_extension_with_stake(MIN_BOUNTY as u64);
+    let mut contract = create_default_contract();
+    let id = register_issue_n(&mut contract, 1);
+
+    contract.harvest_emissions().unwrap();
+
+    let signature = <crate::events::BountyFilled as ink::env::Event>::SIGNATURE_TOPIC
+        .expect("event has a signature topic");
+    let event = test::recorded_events()
+        .find(|event| event.topics.first() == Some(&signature.to_vec()))
+        .expect("BountyFilled emitted");
+    let filled = <crate::events::BountyFilled as scale::Decode>::decode(&mut &event.data[..])
+        .expect("BountyFilled payload");
+    assert_eq!(filled.issue_id, id);
+    assert_eq!(filled.repository_hash, contract.hash_string("org/repo"));
+    assert_eq!(filled.amount, MIN_BOUNTY);
+    assert_eq!(filled.target_bounty, MIN_BOUNTY);
+}
+
+// ============================================================================
+// Vote Expiry Tests
+// ============================================================================
+
+/// Vote expiry used by these tests, well inside the validator inactivity window
+const EXPIRY: u32 = 100;
+
+fn setup_3_validator_active_issue_with_expiry() -> (IssueBountyManager, u64) {
+    let (mut contract, id) = setup_3_validator_active_issue();
+    set_caller(account(1));
+    contract.set_vote_expiry_blocks(EXPIRY).unwrap();
+    (contract, id)
+}
+
+#[ink::test]
+fn expired_solution_vote_restarts_on_next_vote() {
+    let (mut contract, id) = setup_3_validator_active_issue_with_expiry();
+    set_caller(account(3));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    assert_eq!(contract.get_solution_votes(id)[0].vote_started_at_block, 0);
+
+    advance_blocks(EXPIRY + 1);
+    set_caller(account(4));
+    let tally = contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+
+    // The stale vote no longer counts, so consensus is not reached
+    assert_eq!(tally.votes, 1);
+    assert!(!tally.consensus_reached);
+    let vote = &contract.get_solution_votes(id)[0];
+    assert_eq!(vote.voters, vec![account(4)]);
+    assert_eq!(vote.vote_started_at_block, EXPIRY + 1);
+
+    // The first validator's vote was released and can be cast again
+    set_caller(account(3));
+    assert!(
+        contract
+            .vote_solution(id, account(6), account(5), 42)
+            .unwrap()
+            .consensus_reached
+    );
+}
+
+#[ink::test]
+fn solution_vote_within_expiry_still_counts() {
+    let (mut contract, id) = setup_3_validator_active_issue_with_expiry();
+    set_caller(account(3));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+
+    advance_blocks(EXPIRY);
+    set_caller(account(4));
+    assert!(
+        contract
+            .vote_solution(id, account(6), account(5), 42)
+            .unwrap()
+            .consensus_reached
+    );
+}
+
+#[ink::test]
+fn sweep_expired_votes_clears_stale_proposals() {
+    let (mut contract, id) = setup_3_validator_active_issue_with_expiry();
+    set_caller(account(3));
+    contract
+        .vote_solution(id, account(6), account(5), 42)
+        .unwrap();
+    contract
+        .vote_cancel_issue(id, [0xCC; 32], crate::CancelDestination::Pool, None)
+        .unwrap();
+
+    // Nothing to clear yet
+    set_caller(account(9));
+    assert_eq!(contract.sweep_expired_votes(vec![id]).unwrap(), 0);
+
+    advance_blocks(EXPIRY + 1);
+    assert_eq!(contract.sweep_expired_votes(vec![id, 99]).unwrap(), 2);
+    assert!(contract.get_solution_votes(id).is_empty());
+    assert!(contract.get_cancel_vote(id).is_none());
+    assert!(!contract.has_voted(id, crate::types::VoteKind::Solution, account(3)));
+    assert!(!contract.has_voted(id, crate::types::VoteKind::Cancel, account(3)));
+
+    let last = test::recorded_events().last().expect("expiry event");
+    let event = <crate::events::VoteExpired as scale::Decode>::decode(&mut &last.data[..])
+        .expect("last event should be VoteExpired");
+    assert_eq!(event.issue_id, id);
+    assert_eq!(event.kind, crate::types::VoteKind::Cancel);
+    assert_eq!(event.votes_count, 1);
+}
+
+#[ink::test]
+fn sweep_expired_votes_rejects_oversized_batch() {
+    let mut contract = create_default_contract();
+    let ids = (1..=MAX_BATCH_SIZE as u64 + 1).collect();
+    assert_eq!(
+        contract.sweep_expired_votes(ids),
+        Err(crate::Error::BatchTooLarge)
+    );
+}
+
+#[ink::test]
+fn set_vote_expiry_blocks_validates_input() {
+    let mut contract = create_default_contract();
+    set_caller(account(3));
+    assert_eq!(
+        contract.set_vote_expiry_blocks(100),
+        Err(crate::Error::NotOwner)
+    );
+    set_caller(account(1));
+    assert_eq!(
+        contract.set_vote_expiry_blocks(0),
+        Err(crate::Error::InvalidConfig)
+    );
+    contract.set_vote_expiry_blocks(100).unwrap();
+    assert_eq!(contract.get_config().vote_expiry_blocks, 100);
+}
+
+// ============================================================================
+// State Sync Tests
+// ============================================================================
+
+#[ink::test]
+fn sync_state_returns_issues_written_since_block() {
+    register_mock_extension_with_stake(MIN_BOUNTY as u64);
+    let mut contract = create_default_contract();
+    set_block(10);
+    let first = register_issue_n(&mut contract, 1);
+    register_issue_n(&mut contract, 2);
+    register_issue_n(&mut contract, 3);
+
+    // Harvest at block 20 funds only the first issue
+    set_block(20);
+    contract.harvest_emissions().unwrap();
+
+    let sync = contract.sync_state(20, 0, 10);
+    assert_eq!(sync.synced_at_block, 20);
+    assert_eq!(sync.next_cursor, None);
+    assert_eq!(sync.issues.len(), 1);
+    assert_eq!(sync.issues[0].id, first);
+    assert_eq!(sync.issues[0].status, crate::IssueStatus::Active);
+
+    assert_eq!(contract.sync_state(10, 0, 10).issues.len(), 3);
+    assert!(contract.sync_state(21, 0, 10).issues.is_empty());
+}
+
+#[ink::test]
+fn sync_state_pages_with_cursor() {
+    let mut contract = create_default_contract();
+    let ids: Vec<u64> = (1..=3)
+        .map(|n| register_issue_n(&mut contract, n))
+        .collect();
+
+    let page = contract.sync_state(0, 0, 2);
+    assert_eq!(
+        page.issues.iter().map(|i| i.id).collect::<Vec<_>>(),
+        ids[..2]
+    );
+    assert_eq!(page.next_cursor, Some(ids[2]));
+
+    let rest = contract.sync_state(0, ids[2], 2);
+    assert_eq!(rest.issues[0].id, ids[2]);
+    assert_eq!(rest.next_cursor, None);
+}
+
+// ============================================================================
+// Config Timelock Tests
+// ============================================================================
+
+#[ink::test]
+fn only_one_config_change_can_be_pending() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    contract
+        .schedule_config_change(crate::ScheduledChange::Netuid(2))
+        .unwrap();
+    assert_eq!(
+        contract.schedule_config_change(crate::ScheduledChange::Netuid(3)),
+        Err(crate::Error::ConfigChangePending)
+    );
+
+    // The owner can withdraw it and schedule another
+    contract.cancel_config_change().unwrap();
+    contract
+        .schedule_config_change(crate::ScheduledChange::Netuid(3))
+        .unwrap();
+    assert_eq!(
+        contract.get_pending_config_change().unwrap().change,
+        crate::ScheduledChange::Netuid(3)
+    );
+}
+
+#[ink::test]
+fn validators_cancel_config_change_by_consensus() {
+    let (mut contract, _) = setup_3_validator_active_issue();
+    set_caller(account(1));
+    contract
+        .schedule_config_change(crate::ScheduledChange::TreasuryHotkey(account(7)))
+        .unwrap();
+
+    set_caller(account(3));
+    let tally = contract.vote_cancel_config_change().unwrap();
+    assert!(!tally.consensus_reached);
+    assert_eq!(
+        contract.vote_cancel_config_change(),
+        Err(crate::Error::AlreadyVoted)
+    );
+    assert_eq!(
+        contract.get_pending_config_change().unwrap().cancel_votes,
+        1
+    );
+
+    set_caller(account(4));
+    assert!(
+        contract
+            .vote_cancel_config_change()
+            .unwrap()
+            .consensus_reached
+    );
+    assert!(contract.get_pending_config_change().is_none());
+
+    advance_blocks(CONFIG_TIMELOCK_BLOCKS);
+    assert_eq!(
+        contract.execute_config_change(),
+        Err(crate::Error::NoConfigChange)
+    );
+    assert_eq!(contract.treasury_hotkey(), account(2));
+}
+
+#[ink::test]
+fn cancel_votes_do_not_carry_over_to_next_change() {
+    let (mut contract, _) = setup_3_validator_active_issue();
+    set_caller(account(1));
+    contract
+        .schedule_config_change(crate::ScheduledChange::Netuid(2))
+        .unwrap();
+    set_caller(account(3));
+    contract.vote_cancel_config_change().unwrap();
+
+    set_caller(account(1));
+    contract.cancel_config_change().unwrap();
+    contract
+        .schedule_config_change(crate::ScheduledChange::Netuid(3))
+        .unwrap();
+
+    set_caller(account(3));
+    assert_eq!(contract.vote_cancel_config_change().unwrap().votes, 1);
+}
+
+/// Drops a validator from the whitelist the way remove_validator does, for
+/// states remove_validator itself refuses to reach.
+fn force_remove_validator(contract: &mut IssueBountyManager, hotkey: AccountId) {
+    contract.validators.retain(|v| v != &hotkey);
+    contract.whitelist_version += 1;
+}
+
+#[ink::test]
+fn cancel_votes_from_removed_validators_are_dropped() {
+    let (mut contract, _) = setup_3_validator_active_issue();
+    set_caller(account(1));
+    contract
+        .schedule_config_change(crate::ScheduledChange::Netuid(2))
+        .unwrap();
+    let issue_id = register_issue_n(&mut contract, 7);
+    let repair_id = contract
+        .schedule_repair(crate::RepairAction::DequeueIssue(issue_id))
+        .unwrap();
+    set_caller(account(3));
+    contract.vote_cancel_config_change().unwrap();
+    contract.vote_cancel_repair(repair_id).unwrap();
+
+    force_remove_validator(&mut contract, account(3));
+    set_caller(account(4));
+    assert_eq!(contract.vote_cancel_config_change().unwrap().votes, 1);
+    assert_eq!(contract.vote_cancel_repair(repair_id).unwrap().votes, 1);
+    assert_eq!(
+        contract.get_pending_config_change().unwrap().cancel_voters,
+        vec![account(4)]
+    );
+    assert_eq!(
+        contract
+            .get_pending_repair(repair_id)
+            .unwrap()
+            .cancel_voters,
+        vec![account(4)]
+    );
+}
+
+#[ink::test]
+fn config_timelock_change_is_itself_timelocked() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    assert_eq!(
+        contract.schedule_config_change(crate::ScheduledChange::ConfigTimelockBlocks(0)),
+        Err(crate::Error::InvalidConfig)
+    );
+
+    apply_config_change(
+        &mut contract,
+        crate::ScheduledChange::ConfigTimelockBlocks(10),
+    );
+    assert_eq!(contract.get_config().config_timelock_blocks, 10);
+
+    set_caller(account(1));
+    contract
+        .schedule_config_change(crate::ScheduledChange::Netuid(2))
+        .unwrap();
+    assert_eq!(
+        contract
+            .get_pending_config_change()
+            .unwrap()
+            .executable_at_block,
+        CONFIG_TIMELOCK_BLOCKS + 10
+    );
+}
+
+// ============================================================================
+// Queue Ordering Tests
+// ============================================================================
+
+#[ink::test]
+fn remove_from_bounty_queue_keeps_relative_order() {
+    let mut contract = create_default_contract();
+    contract.bounty_queue = vec![1, 2, 3, 4, 5];
+
+    contract.remove_from_bounty_queue(2);
+    contract.remove_from_bounty_queue(4);
+    assert_eq!(contract.bounty_queue, vec![1, 3, 5]);
+}
+
+#[ink::test]
+fn repeated_partial_fills_keep_queue_order() {
+    let mut contract = create_default_contract();
+    let ids: Vec<u64> = (1..=3)
+        .map(|n| register_issue_n(&mut contract, n))
+        .collect();
+
+    // Two partial fills on the head: it stays first, the rest keep their order
+    for _ in 0..2 {
+        contract.alpha_pool = MIN_BOUNTY / 4;
+        contract.fill_bounties();
+        assert_eq!(contract.get_bounty_queue(), ids);
+    }
+
+    // Completing the head promotes the next issue without reordering the tail
+    contract.alpha_pool = MIN_BOUNTY / 2 + MIN_BOUNTY / 4;
+    contract.fill_bounties();
+    assert_eq!(contract.get_bounty_queue(), ids[1..]);
+    assert_eq!(
+        contract.get_issue(ids[1]).unwrap().bounty_amount,
+        MIN_BOUNTY / 4
+    );
+}
+
+#[ink::test]
+fn dropping_finalized_entries_keeps_queue_order() {
+    let mut contract = create_default_contract();
+    let ids: Vec<u64> = (1..=4)
+        .map(|n| register_issue_n(&mut contract, n))
+        .collect();
+
+    // Finalize the second issue behind the queue's back; the next fill pass drops it
+    let mut issue = contract.issues.get(ids[1]).unwrap();
+    issue.status = crate::IssueStatus::Cancelled;
+    contract.issues.insert(ids[1], &issue);
+
+    contract.alpha_pool = MIN_BOUNTY + MIN_BOUNTY / 2;
+    contract.fill_bounties();
+    assert_eq!(contract.get_bounty_queue(), vec![ids[2], ids[3]]);
+    assert_eq!(
+        contract.get_issue(ids[2]).unwrap().bounty_amount,
+        MIN_BOUNTY / 2
+    );
+}
+
+#[ink::test]
+fn queue_position_tracks_fifo_order_after_removal() {
+    let mut contract = create_default_contract();
+    let ids: Vec<u64> = (1..=3)
+        .map(|n| register_issue_n(&mut contract, n))
+        .collect();
+
+    set_caller(account(1));
+    contract.cancel_issue(ids[0], None).unwrap();
+    assert_eq!(contract.get_queue_position(ids[1]), Some(0));
+    assert_eq!(contract.get_queue_position(ids[2]), Some(1));
+    assert_eq!(contract.get_queue_position(ids[0]), None);
+}
+
+// ============================================================================
+// Stake Operation Audit Tests
+// ============================================================================
+
+#[ink::test]
+fn call_hash_is_blake2_of_encoded_call() {
+    let call = RawCall::proxied_recycle_alpha(&account(1), &account(2), AlphaAmount::from(7), 1);
+    let mut expected = [0u8; 32];
+    ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&call.encode(), &mut expected);
+    assert_eq!(IssueBountyManager::call_hash(&call), expected);
+
+    let other = RawCall::proxied_recycle_alpha(&account(1), &account(2), AlphaAmount::from(8), 1);
+    assert_ne!(IssueBountyManager::call_hash(&other), expected);
+}
+
+#[ink::test]
+fn undispatched_amounts_do_not_consume_op_ids() {
+    let mut contract = create_default_contract();
+    let oversized = u128::from(u64::MAX) + 1;
+
+    assert!(!contract.recycle(oversized));
+    assert_eq!(
+        contract.execute_payout_internal(1, account(5), oversized),
+        Err(crate::Error::AmountOverflow)
+    );
+    assert_eq!(contract.get_stake_op_count(), 0);
+
+    let signature = <crate::events::StakeOperation as ink::env::Event>::SIGNATURE_TOPIC
+        .expect("event has a signature topic");
+    assert!(!test::recorded_events().any(|e| e.topics.first() == Some(&signature.to_vec())));
+}
+
+// ============================================================================
+// Bounty Adjustment Tests
+// ============================================================================
+
+/// Helper: validators 3, 4, 5 whitelisted and a Registered issue targeting
+/// twice MIN_BOUNTY.
+fn setup_3_validator_registered_issue() -> (IssueBountyManager, u64) {
+    register_mock_extension();
+    let mut contract = setup_3_validators();
+    let id = contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/1"),
+            String::from("org/repo"),
+            1,
+            MIN_BOUNTY * 2,
+            None,
+            None,
+        )
+        .unwrap();
+    (contract, id)
+}
+
+#[ink::test]
+fn bounty_adjustment_raises_target_on_consensus() {
+    let (mut contract, id) = setup_3_validator_registered_issue();
+    let kind = crate::types::VoteKind::BountyAdjustment;
+
+    set_caller(account(3));
+    let tally = contract
+        .propose_bounty_adjustment(id, MIN_BOUNTY * 3)
+        .unwrap();
+    assert!(!tally.consensus_reached);
+    assert_eq!(
+        contract.get_issue(id).unwrap().target_bounty,
+        MIN_BOUNTY * 2
+    );
+    assert!(contract.has_voted(id, kind, account(3)));
+
+    set_caller(account(4));
+    assert!(
+        contract
+            .vote_bounty_adjustment(id)
+            .unwrap()
+            .consensus_reached
+    );
+
+    let issue = contract.get_issue(id).unwrap();
+    assert_eq!(issue.target_bounty, MIN_BOUNTY * 3);
+    assert_eq!(issue.status, crate::IssueStatus::Registered);
+    assert_eq!(contract.get_bounty_queue(), vec![id]);
+    assert!(contract.get_bounty_adjustment(id).is_none());
+    assert!(!contract.has_voted(id, kind, account(3)));
+}
+
+#[ink::test]
+fn bounty_adjustment_below_funding_releases_excess_and_activates() {
+    let (mut contract, id) = setup_3_validator_registered_issue();
+    contract.alpha_pool = MIN_BOUNTY + MIN_BOUNTY / 2;
+    contract.fill_bounties();
+
+    set_caller(account(3));
+    contract.propose_bounty_adjustment(id, MIN_BOUNTY).unwrap();
+    set_caller(account(4));
+    contract.vote_bounty_adjustment(id).unwrap();
+
+    let issue = contract.get_issue(id).unwrap();
+    assert_eq!(issue.bounty_amount, MIN_BOUNTY);
+    assert_eq!(issue.status, crate::IssueStatus::Active);
+    assert_eq!(contract.get_alpha_pool(), MIN_BOUNTY / 2);
+    // total_committed is cumulative, so the released excess stays counted
+    assert_eq!(
+        contract.get_accounting().total_committed,
+        MIN_BOUNTY + MIN_BOUNTY / 2
+    );
+    assert!(contract.get_bounty_queue().is_empty());
+
+    let signature = <crate::events::BountyAdjusted as ink::env::Event>::SIGNATURE_TOPIC
+        .expect("event has a signature topic");
+    let event = test::recorded_events()
+        .find(|e| e.topics.first() == Some(&signature.to_vec()))
+        .expect("BountyAdjusted emitted");
+    let adjusted =
+        <crate::events::BountyAdjusted as scale::Decode>::decode(&mut &event.data[..]).unwrap();
+    assert_eq!(adjusted.old_target, MIN_BOUNTY * 2);
+    assert_eq!(adjusted.released, MIN_BOUNTY / 2);
+    assert!(adjusted.activated);
+}
+
+#[ink::test]
+fn propose_bounty_adjustment_validates_inputs() {
+    let (mut contract, id) = setup_3_validator_registered_issue();
+
+    set_caller(account(7));
+    assert_eq!(
+        contract.propose_bounty_adjustment(id, MIN_BOUNTY),
+        Err(Error::NotWhitelistedValidator)
+    );
+
+    set_caller(account(3));
+    assert_eq!(
+        contract.propose_bounty_adjustment(id, MIN_BOUNTY - 1),
+        Err(Error::BountyTooLow)
+    );
+    assert_eq!(
+        contract.propose_bounty_adjustment(id, MIN_BOUNTY * 2),
+        Err(Error::BountyTargetUnchanged)
+    );
+    contract.propose_bounty_adjustment(id, MIN_BOUNTY).unwrap();
+    set_caller(account(4));
+    assert_eq!(
+        contract.propose_bounty_adjustment(id, MIN_BOUNTY * 3),
+        Err(Error::BountyAdjustmentPending)
+    );
+
+    let mut issue = contract.issues.get(id).unwrap();
+    issue.status = crate::IssueStatus::Active;
+    contract.issues.insert(id, &issue);
+    assert_eq!(
+        contract.vote_bounty_adjustment(id),
+        Err(Error::IssueNotRegistered)
+    );
+}
+
+#[ink::test]
+fn vote_bounty_adjustment_requires_open_proposal() {
+    let (mut contract, id) = setup_3_validator_registered_issue();
+
+    set_caller(account(3));
+    assert_eq!(
+        contract.vote_bounty_adjustment(id),
+        Err(Error::NoBountyAdjustment)
+    );
+    contract.propose_bounty_adjustment(id, MIN_BOUNTY).unwrap();
+    assert_eq!(
+        contract.vote_bounty_adjustment(id),
+        Err(Error::AlreadyVoted)
+    );
+}
+
+#[ink::test]
+fn expired_bounty_adjustment_is_swept() {
+    let (mut contract, id) = setup_3_validator_registered_issue();
+    set_caller(account(1));
+    contract.set_vote_expiry_blocks(EXPIRY).unwrap();
+
+    set_caller(account(3));
+    contract.propose_bounty_adjustment(id, MIN_BOUNTY).unwrap();
+    advance_blocks(EXPIRY + 1);
+
+    assert_eq!(contract.sweep_expired_votes(vec![id]), Ok(1));
+    assert!(contract.get_bounty_adjustment(id).is_none());
+
+    // The proposer's vote was released with the proposal
+    contract
+        .propose_bounty_adjustment(id, MIN_BOUNTY * 3)
+        .unwrap();
+    assert_eq!(
+        contract.get_bounty_adjustment(id).unwrap().voters,
+        vec![account(3)]
+    );
+}
+
+// ============================================================================
+// Issue Proposal Tests
+// ============================================================================
+
+const PROPOSED_URL: &str = "https://github.com/org/repo/issues/9";
+
+fn propose_test_issue(contract: &mut IssueBountyManager) -> Result<crate::VoteTally, Error> {
+    contract.propose_issue(
+        String::from(PROPOSED_URL),
+        String::from("org/repo"),
+        9,
+        MIN_BOUNTY,
+    )
+}
+
+#[ink::test]
+fn proposed_issue_registers_on_consensus() {
+    let mut contract = setup_3_validators();
+    let url_hash = contract
+        .canonicalize_issue_url(String::from(PROPOSED_URL))
+        .1;
+
+    set_caller(account(3));
+    assert!(!propose_test_issue(&mut contract).unwrap().consensus_reached);
+    assert_eq!(contract.get_issue_by_url_hash(url_hash), 0);
+    assert_eq!(
+        contract.get_issue_proposal(url_hash).unwrap().voters,
+        vec![account(3)]
+    );
+
+    set_caller(account(4));
+    assert!(
+        contract
+            .vote_issue_proposal(url_hash)
+            .unwrap()
+            .consensus_reached
+    );
+
+    let id = contract.get_issue_by_url_hash(url_hash);
+    let issue = contract.get_issue(id).unwrap();
+    assert_eq!(issue.issue_number, 9);
+    assert_eq!(issue.target_bounty, MIN_BOUNTY);
+    assert_eq!(issue.registered_by, account(3));
+    assert_eq!(contract.get_bounty_queue(), vec![id]);
+    assert!(contract.get_issue_proposal(url_hash).is_none());
+}
+
+#[ink::test]
+fn propose_issue_validates_caller_and_inputs() {
+    let mut contract = setup_3_validators();
+    register_test_issue(&mut contract);
+
+    set_caller(account(7));
+    assert_eq!(
+        propose_test_issue(&mut contract),
+        Err(Error::NotWhitelistedValidator)
+    );
+
+    set_caller(account(3));
+    assert_eq!(
+        contract.propose_issue(
+            String::from("https://github.com/org/repo/issues/1"),
+            String::from("org/repo"),
+            1,
+            MIN_BOUNTY
+        ),
+        Err(Error::IssueAlreadyExists)
+    );
+    assert_eq!(
+        contract.propose_issue(String::from(PROPOSED_URL), String::from("org/repo"), 9, 0),
+        Err(Error::BountyTooLow)
+    );
+
+    propose_test_issue(&mut contract).unwrap();
+    set_caller(account(4));
+    assert_eq!(
+        propose_test_issue(&mut contract),
+        Err(Error::IssueProposalPending)
+    );
+}
+
+#[ink::test]
+fn vote_issue_proposal_rejects_repeat_and_unknown() {
+    let mut contract = setup_3_validators();
+    let url_hash = contract
+        .canonicalize_issue_url(String::from(PROPOSED_URL))
+        .1;
+
+    set_caller(account(3));
+    assert_eq!(
+        contract.vote_issue_proposal(url_hash),
+        Err(Error::NoIssueProposal)
+    );
+    propose_test_issue(&mut contract).unwrap();
+    assert_eq!(
+        contract.vote_issue_proposal(url_hash),
+        Err(Error::AlreadyVoted)
+    );
+}
+
+#[ink::test]
+fn expired_issue_proposal_can_be_reproposed() {
+    let mut contract = setup_3_validators();
+    contract.set_vote_expiry_blocks(EXPIRY).unwrap();
+    let url_hash = contract
+        .canonicalize_issue_url(String::from(PROPOSED_URL))
+        .1;
+
+    set_caller(account(3));
+    propose_test_issue(&mut contract).unwrap();
+    advance_blocks(EXPIRY + 1);
+
+    set_caller(account(4));
+    assert_eq!(
+        contract.vote_issue_proposal(url_hash),
+        Err(Error::NoIssueProposal)
+    );
+    propose_test_issue(&mut contract).unwrap();
+    assert_eq!(
+        contract.get_issue_proposal(url_hash).unwrap().proposer,
+        account(4)
+    );
+}
+
+// ============================================================================
+// Audit Tests
+// ============================================================================
+
+fn finding(issue_id: u64, violation: crate::InvariantViolation) -> crate::AuditFinding {
+    crate::AuditFinding {
+        issue_id,
+        violation,
+    }
+}
+
+#[ink::test]
+fn audit_reports_nothing_for_consistent_state() {
+    let mut contract = create_default_contract();
+    let id = register_issue_n(&mut contract, 1);
+    contract.cancel_issue(id, None).unwrap();
+    register_issue_n(&mut contract, 2);
+
+    assert_eq!(contract.audit(0), crate::AuditReport::default());
+}
+
+#[ink::test]
+fn audit_reports_each_invariant_violation() {
+    use crate::InvariantViolation::*;
+    let mut contract = create_default_contract();
+    let cancelled = register_issue_n(&mut contract, 1);
+    contract.cancel_issue(cancelled, None).unwrap();
+    let unqueued = register_issue_n(&mut contract, 2);
+    let overfunded = register_issue_n(&mut contract, 3);
+
+    contract.bounty_queue = vec![overfunded, cancelled, 99, overfunded];
+    let mut issue = contract.issues.get(overfunded).unwrap();
+    issue.bounty_amount = issue.target_bounty + 1;
+    contract.issues.insert(overfunded, &issue);
+    contract
+        .url_hash_to_id
+        .insert(issue.github_url_hash, &unqueued);
+
+    assert_eq!(
+        contract.audit(0).findings,
+        vec![
+            finding(cancelled, QueuedIssueFinalized),
+            finding(overfunded, QueuedIssueDuplicate),
+            finding(99, QueuedIssueMissing),
+            finding(unqueued, RegisteredIssueNotQueued),
+            finding(overfunded, BountyExceedsTarget),
+            finding(overfunded, UrlIndexMismatch),
+        ]
+    );
+
+    // Later pages skip the queue checks
+    assert_eq!(
+        contract.audit(overfunded).findings,
+        vec![
+            finding(overfunded, BountyExceedsTarget),
+            finding(overfunded, UrlIndexMismatch),
+        ]
+    );
+}
+
+// ============================================================================
+// State Repair Tests
+// ============================================================================
+
+/// Schedules a repair as the owner and executes it once the timelock passes.
+fn apply_repair(contract: &mut IssueBountyManager, action: crate::RepairAction) {
+    set_caller(account(1));
+    let repair_id = contract.schedule_repair(action).unwrap();
+    advance_blocks(contract.get_config().config_timelock_blocks);
+    contract.execute_repair(repair_id).unwrap();
+}
+
+#[ink::test]
+fn scheduled_repairs_clear_audit_findings() {
+    let mut contract = create_default_contract();
+    let cancelled = register_issue_n(&mut contract, 1);
+    contract.cancel_issue(cancelled, None).unwrap();
+    let unqueued = register_issue_n(&mut contract, 2);
+    let overfunded = register_issue_n(&mut contract, 3);
+
+    contract.bounty_queue = vec![overfunded, cancelled, overfunded];
+    let mut issue = contract.issues.get(overfunded).unwrap();
+    issue.bounty_amount = issue.target_bounty + 5;
+    contract.issues.insert(overfunded, &issue);
+    contract.url_hash_to_id.remove(issue.github_url_hash);
+    assert_eq!(contract.audit(0).findings.len(), 5);
+
+    // All repairs queue at once, alongside a pending config change
+    contract
+        .schedule_config_change(crate::ScheduledChange::Netuid(TEST_NETUID))
+        .unwrap();
+    let repair_ids: Vec<u64> = [
+        crate::RepairAction::DequeueIssue(cancelled),
+        crate::RepairAction::DequeueIssue(overfunded),
+        crate::RepairAction::RequeueIssue(unqueued),
+        crate::RepairAction::ReleaseExcessBounty(overfunded),
+        crate::RepairAction::ReindexIssueUrl(overfunded),
+    ]
+    .into_iter()
+    .map(|action| contract.schedule_repair(action).unwrap())
+    .collect();
+    assert_eq!(repair_ids, vec![1, 2, 3, 4, 5]);
+    assert_eq!(contract.get_pending_repair_ids(), repair_ids);
+    assert!(contract.get_pending_config_change().is_some());
+
+    assert_eq!(
+        contract.execute_repair(1),
+        Err(crate::Error::RepairTimelocked)
+    );
+    advance_blocks(contract.get_config().config_timelock_blocks);
+    for repair_id in repair_ids {
+        contract.execute_repair(repair_id).unwrap();
+    }
+
+    assert!(contract.get_pending_repair_ids().is_empty());
+    assert_eq!(
+        contract.execute_repair(1),
+        Err(crate::Error::RepairNotFound)
+    );
+    assert_eq!(contract.audit(0), crate::AuditReport::default());
+    let issue = contract.get_issue(overfunded).unwrap();
+    assert_eq!(issue.status, crate::IssueStatus::Active);
+    assert_eq!(contract.get_alpha_pool(), 5);
+    assert_eq!(contract.get_bounty_queue(), vec![unqueued]);
+}
+
+#[ink::test]
+fn repair_on_consistent_state_is_a_no_op() {
+    let mut contract = create_default_contract();
+    let id = register_issue_n(&mut contract, 1);
+
+    apply_repair(&mut contract, crate::RepairAction::DequeueIssue(id));
+    apply_repair(&mut contract, crate::RepairAction::RequeueIssue(id));
+    assert_eq!(contract.get_bounty_queue(), vec![id]);
+
+    let signature = <crate::events::StateRepaired as ink::env::Event>::SIGNATURE_TOPIC
+        .expect("event has a signature topic");
+    assert!(!test::recorded_events().any(|e| e.topics.first() == Some(&signature.to_vec())));
+}
+
+/// Registers an issue under `repo` and re-keys it by the hash of its raw URL,
+/// as issues registered before URL canonicalization were stored.
+fn register_legacy_issue(contract: &mut IssueBountyManager, repo: &str, number: u32) -> u64 {
+    set_caller(account(1));
+    let url = format!("https://github.com/{}/issues/{}", repo, number);
+    let id = contract
+        .register_issue(
+            url.clone(),
+            String::from(repo),
+            number,
+            MIN_BOUNTY,
+            None,
+            None,
+        )
+        .unwrap();
+    let mut issue = contract.issues.get(id).unwrap();
+    contract.url_hash_to_id.remove(issue.github_url_hash);
+    issue.github_url_hash = contract.hash_string(&url);
+    contract.url_hash_to_id.insert(issue.github_url_hash, &id);
+    contract.issues.insert(id, &issue);
+    id
+}
+
+#[ink::test]
+fn canonicalize_repair_rekeys_legacy_url_hashes() {
+    use crate::InvariantViolation::UrlHashNotCanonical;
+    let mut contract = create_default_contract();
+    let legacy = register_legacy_issue(&mut contract, "Org/Repo", 1);
+    let shadowed = register_legacy_issue(&mut contract, "Org/Repo", 2);
+
+    // The canonical URL of a legacy issue is not deduplicated against it
+    let duplicate = contract
+        .register_issue(
+            String::from("https://github.com/org/repo/issues/2"),
+            String::from("org/repo"),
+            2,
+            MIN_BOUNTY,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        contract.audit(0).findings,
+        vec![
+            finding(legacy, UrlHashNotCanonical),
+            finding(shadowed, UrlHashNotCanonical),
+        ]
+    );
+
+    let legacy_hash = contract.get_issue(legacy).unwrap().github_url_hash;
+    apply_repair(
+        &mut contract,
+        crate::RepairAction::CanonicalizeIssueUrl(legacy),
+    );
+    apply_repair(
+        &mut contract,
+        crate::RepairAction::CanonicalizeIssueUrl(shadowed),
+    );
+
+    let (_, canonical_hash) =
+        contract.canonicalize_issue_url(String::from("https://github.com/Org/Repo/issues/1"));
+    assert_eq!(
+        contract.get_issue(legacy).unwrap().github_url_hash,
+        canonical_hash
+    );
+    assert_eq!(contract.get_issue_by_url_hash(canonical_hash), legacy);
+    assert_eq!(contract.get_issue_by_url_hash(legacy_hash), 0);
+    assert!(contract.verify_url(
+        legacy,
+        String::from("https://github.com/org/repo/issues/1/")
+    ));
+    set_caller(account(1));
+    assert_eq!(
+        contract.register_issue(
+            String::from("https://github.com/org/repo/issues/1"),
+            String::from("org/repo"),
+            1,
+            MIN_BOUNTY,
+            None,
+            None,
+        ),
+        Err(crate::Error::IssueAlreadyExists)
+    );
+
+    // A duplicate already holds the canonical hash: left for the owner to cancel
+    let (_, duplicate_hash) =
+        contract.canonicalize_issue_url(String::from("https://github.com/org/repo/issues/2"));
+    assert_eq!(contract.get_issue_by_url_hash(duplicate_hash), duplicate);
+    assert_eq!(
+        contract.audit(0).findings,
+        vec![finding(shadowed, UrlHashNotCanonical)]
+    );
+}
+
+#[ink::test]
+fn scheduled_repair_can_be_withdrawn_or_cancelled_by_validators() {
+    let mut contract = setup_3_validators();
+    let id = register_issue_n(&mut contract, 1);
+
+    set_caller(account(4));
+    assert_eq!(
+        contract.schedule_repair(crate::RepairAction::DequeueIssue(id)),
+        Err(crate::Error::NotOwner)
+    );
+
+    set_caller(account(1));
+    let withdrawn = contract
+        .schedule_repair(crate::RepairAction::DequeueIssue(id))
+        .unwrap();
+    let voted_down = contract
+        .schedule_repair(crate::RepairAction::ReindexIssueUrl(id))
+        .unwrap();
+    contract.cancel_repair(withdrawn).unwrap();
+    assert_eq!(contract.get_pending_repair_ids(), vec![voted_down]);
+
+    set_caller(account(3));
+    assert!(
+        !contract
+            .vote_cancel_repair(voted_down)
+            .unwrap()
+            .consensus_reached
+    );
+    assert_eq!(
+        contract.vote_cancel_repair(voted_down),
+        Err(crate::Error::AlreadyVoted)
+    );
+    set_caller(account(4));
+    assert!(
+        contract
+            .vote_cancel_repair(voted_down)
+            .unwrap()
+            .consensus_reached
+    );
+    assert!(contract.get_pending_repair(voted_down).is_none());
+    assert!(contract.get_pending_repair_ids().is_empty());
+    assert_eq!(contract.get_bounty_queue(), vec![id]);
+}
+
+#[ink::test]
+fn quorum_settings_frozen_while_repair_pending() {
+    let mut contract = setup_3_validators();
+    let id = register_issue_n(&mut contract, 1);
+    set_caller(account(1));
+    let repair_id = contract
+        .schedule_repair(crate::RepairAction::DequeueIssue(id))
+        .unwrap();
+
+    let locked = Err(crate::Error::QuorumLocked);
+    assert_eq!(contract.remove_validator(account(4)), locked);
+    assert_eq!(contract.set_required_validator_votes(Some(1)), locked);
+    assert_eq!(contract.set_min_votes_required(1), locked);
+
+    // Withdrawing the last pending repair lifts the lock
+    contract.cancel_repair(repair_id).unwrap();
+    contract.remove_validator(account(4)).unwrap();
+}
+
+#[ink::test]
+fn schedule_repair_caps_pending_repairs() {
+    let mut contract = create_default_contract();
+    set_caller(account(1));
+    for id in 0..MAX_PENDING_REPAIRS as u64 {
+        contract
+            .schedule_repair(crate::RepairAction::RequeueIssue(id))
+            .unwrap();
+    }
+    assert_eq!(
+        contract.schedule_repair(crate::RepairAction::RequeueIssue(0)),
+        Err(crate::Error::TooManyPendingRepairs)
+    );
+}
@@ -1,4 +1,5 @@
 use ink::prelude::string::String;
+use ink::prelude::vec::Vec;
 use ink::primitives::AccountId;
 use scale::{Compact, Decode, Encode};
 
@@ -37,7 +38,7 @@ pub enum IssueStatus {
 
 
 /// Represents a GitHub issue registered for bounty
-#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
 pub struct Issue {
     /// Unique issue ID
@@ -56,15 +57,88 @@ pub struct Issue {
     pub status: IssueStatus,
     /// Block number when registered
     pub registered_at_block: u32,
+    /// Account that registered the issue
+    pub registered_by: AccountId,
     /// Solver coldkey (set when issue is completed via consensus) - receives payout
     pub solver_coldkey: Option<AccountId>,
     /// Solver hotkey (set when issue is completed via consensus) - the miner identity
     pub solver_hotkey: Option<AccountId>,
     /// Winning PR number (set when issue is completed) - combined with repository_full_name to form URL
     pub winning_pr_number: Option<u32>,
+    /// Optional difficulty rating set at registration, used to weight payouts
+    pub difficulty: Option<u8>,
+    /// Optional hash of the issue's label set at registration
+    pub label_hash: Option<[u8; 32]>,
+}
+
+impl Default for Issue {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            github_url_hash: [0u8; 32],
+            repository_full_name: String::new(),
+            issue_number: 0,
+            bounty_amount: 0,
+            target_bounty: 0,
+            status: IssueStatus::default(),
+            registered_at_block: 0,
+            registered_by: AccountId::from([0u8; 32]),
+            solver_coldkey: None,
+            solver_hotkey: None,
+            winning_pr_number: None,
+            difficulty: None,
+            label_hash: None,
+        }
+    }
 }
 
 
+/// A single issue to register via register_issues()
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct IssueInput {
+    /// Full GitHub issue URL
+    pub github_url: String,
+    /// Repository in "owner/repo" format
+    pub repository_full_name: String,
+    /// Issue number within the repository
+    pub issue_number: u32,
+    /// Target bounty amount
+    pub target_bounty: u128,
+    /// Optional difficulty rating
+    pub difficulty: Option<u8>,
+    /// Optional hash of the issue's label set
+    pub label_hash: Option<[u8; 32]>,
+}
+
+/// Tally after a vote, returned by vote_solution() and vote_cancel_issue()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct VoteTally {
+    /// Votes cast for the proposal, including this one
+    pub votes: u32,
+    /// Votes needed for consensus
+    pub required: u32,
+    /// Validators currently counted in the quorum
+    pub active_validators: u32,
+    /// Whether this vote reached consensus and executed the proposal
+    pub consensus_reached: bool,
+}
+
+/// A single solution vote cast via vote_solutions_batch()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct SolutionVoteInput {
+    /// Issue to vote on
+    pub issue_id: u64,
+    /// Proposed solver's hotkey
+    pub solver_hotkey: AccountId,
+    /// Proposed solver's coldkey (for payout)
+    pub solver_coldkey: AccountId,
+    /// Winning PR number
+    pub pr_number: u32,
+}
+
 /// Votes for a solution on an issue
 #[derive(Debug, Clone, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
@@ -79,6 +153,26 @@ pub struct SolutionVote {
     pub pr_number: u32,
     /// Number of votes cast
     pub votes_count: u32,
+    /// Validators whose votes count towards this proposal
+    pub voters: Vec<AccountId>,
+    /// Whitelist version the voters were last checked against
+    pub whitelist_version: u32,
+    /// Block of the first vote; the proposal expires vote_expiry_blocks later
+    pub vote_started_at_block: u32,
+}
+
+impl SolutionVote {
+    /// True if this vote is for the given solver and PR
+    pub fn is_candidate(
+        &self,
+        solver_hotkey: AccountId,
+        solver_coldkey: AccountId,
+        pr_number: u32,
+    ) -> bool {
+        self.solver_hotkey == solver_hotkey
+            && self.solver_coldkey == solver_coldkey
+            && self.pr_number == pr_number
+    }
 }
 
 impl Default for SolutionVote {
@@ -89,10 +183,24 @@ impl Default for SolutionVote {
             solver_coldkey: AccountId::from([0u8; 32]),
             pr_number: 0,
             votes_count: 0,
+            voters: Vec::new(),
+            whitelist_version: 0,
+            vote_started_at_block: 0,
         }
     }
 }
 
+/// Where the bounty of a cancelled issue goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum CancelDestination {
+    /// Destroy the bounty via recycle_alpha
+    #[default]
+    Recycle,
+    /// Release the bounty back to the alpha pool to fund other issues
+    Pool,
+}
+
 /// Votes for cancelling an issue
 #[derive(Debug, Clone, Encode, Decode, Default)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
@@ -101,8 +209,222 @@ pub struct CancelVote {
     pub issue_id: u64,
     /// Hash of the reason for cancellation
     pub reason_hash: [u8; 32],
+    /// Optional human-readable reason, bounded by MAX_CANCEL_REASON_LEN
+    pub reason: Option<String>,
+    /// Destination for the cancelled bounty
+    pub destination: CancelDestination,
     /// Number of votes cast
     pub votes_count: u32,
+    /// Validators whose votes count towards this proposal
+    pub voters: Vec<AccountId>,
+    /// Whitelist version the voters were last checked against
+    pub whitelist_version: u32,
+    /// Block of the first vote; the proposal expires vote_expiry_blocks later
+    pub vote_started_at_block: u32,
+}
+
+/// Validator proposal to register an issue, applied once consensus is reached
+#[derive(Debug, Clone, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct IssueProposal {
+    /// Hash of the canonical GitHub issue URL
+    pub github_url_hash: [u8; 32],
+    /// Repository in "owner/repo" format
+    pub repository_full_name: String,
+    /// Issue number within the repository
+    pub issue_number: u32,
+    /// Target bounty amount
+    pub target_bounty: u128,
+    /// Validator that opened the proposal; recorded as registered_by
+    pub proposer: AccountId,
+    /// Number of votes cast (including the proposer)
+    pub votes_count: u32,
+    /// Validators whose votes count towards this proposal
+    pub voters: Vec<AccountId>,
+    /// Whitelist version the voters were last checked against
+    pub whitelist_version: u32,
+    /// Block of the first vote; the proposal expires vote_expiry_blocks later
+    pub vote_started_at_block: u32,
+}
+
+/// Validator proposal to change a Registered issue's target bounty
+#[derive(Debug, Clone, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct BountyAdjustment {
+    /// Issue this proposal is for
+    pub issue_id: u64,
+    /// Target bounty applied once consensus is reached
+    pub new_target: u128,
+    /// Validator that opened the proposal
+    pub proposer: AccountId,
+    /// Number of votes cast (including the proposer)
+    pub votes_count: u32,
+    /// Validators whose votes count towards this proposal
+    pub voters: Vec<AccountId>,
+    /// Whitelist version the voters were last checked against
+    pub whitelist_version: u32,
+    /// Block of the first vote; the proposal expires vote_expiry_blocks later
+    pub vote_started_at_block: u32,
+}
+
+/// Kind of proxied stake movement dispatched by the contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum StakeOpKind {
+    /// transfer_stake from the treasury to a solver (bounty payout)
+    Transfer,
+    /// recycle_alpha from the treasury
+    Recycle,
+}
+
+/// Kind of per-issue proposal a validator can vote on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum VoteKind {
+    /// Solution vote via vote_solution
+    Solution,
+    /// Cancellation vote via vote_cancel_issue
+    Cancel,
+    /// Target bounty vote via propose_bounty_adjustment / vote_bounty_adjustment
+    BountyAdjustment,
+}
+
+/// Why and when an issue was cancelled
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct CancellationRecord {
+    /// Hash of the reason for cancellation
+    pub reason_hash: [u8; 32],
+    /// Optional human-readable reason
+    pub reason: Option<String>,
+    /// Block number when the issue was cancelled
+    pub cancelled_at_block: u32,
+}
+
+/// A single allocation made to an issue during fill_bounties
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BountyFill {
+    /// Issue that received funds
+    pub issue_id: u64,
+    /// Keccak-256 of the issue's repository_full_name
+    pub repository_hash: [u8; 32],
+    /// Amount allocated in this pass
+    pub amount: u128,
+    /// Issue bounty_amount after the allocation
+    pub total: u128,
+    /// Issue target_bounty
+    pub target: u128,
+}
+
+/// Allocations made by a single fill_bounties pass
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FillReport {
+    /// Issues that reached their target and became Active
+    pub fully_filled: Vec<BountyFill>,
+    /// Issues that received funds but remain below target
+    pub partially_filled: Vec<BountyFill>,
+    /// True if the pass stopped at max_fills_per_call with funds and queue left
+    pub more_pending: bool,
+}
+
+/// Evidence note attached to an issue by a validator
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct IssueNote {
+    /// Validator hotkey that added the note
+    pub author: AccountId,
+    /// Hash of the note content (e.g., review summary, CI run)
+    pub note_hash: [u8; 32],
+    /// Block number when the note was added
+    pub added_at_block: u32,
+}
+
+/// Pending validator-driven owner replacement
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct OwnerRecovery {
+    /// Account that becomes owner once executed
+    pub new_owner: AccountId,
+    /// Block number when the recovery was proposed
+    pub proposed_at_block: u32,
+    /// First block at which the recovery can be executed
+    pub executable_at_block: u32,
+    /// Number of votes from still-whitelisted validators (including the proposer)
+    pub votes_count: u32,
+    /// Validators who voted for the recovery
+    pub voters: Vec<AccountId>,
+    /// Whitelist version the voters were last reconciled against
+    pub whitelist_version: u32,
+}
+
+/// Config change that only takes effect after config_timelock_blocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum ScheduledChange {
+    /// Transfer contract ownership to a new account
+    Owner(AccountId),
+    /// Move the treasury to a new hotkey (resets funded bounties)
+    TreasuryHotkey(AccountId),
+    /// Move to a new subnet (resets funded bounties)
+    Netuid(u16),
+    /// Change the timelock applied to later config changes
+    ConfigTimelockBlocks(u32),
+    /// Change the delay between an owner recovery proposal and its execution
+    OwnerRecoveryTimelockBlocks(u32),
+}
+
+/// State repair for an inconsistency reported by audit(); only takes effect
+/// after config_timelock_blocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum RepairAction {
+    /// Drop bounty_queue entries audit() flags as missing, finalized or duplicated
+    DequeueIssue(u64),
+    /// Re-queue a Registered issue missing from bounty_queue
+    RequeueIssue(u64),
+    /// Return bounty above a live issue's target to the alpha pool
+    ReleaseExcessBounty(u64),
+    /// Point the issue's URL hash back at the issue
+    ReindexIssueUrl(u64),
+    /// Re-key an issue registered before URL canonicalization under its
+    /// canonical URL hash
+    CanonicalizeIssueUrl(u64),
+}
+
+/// State repair waiting out its timelock
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct PendingRepair {
+    /// Repair to apply
+    pub action: RepairAction,
+    /// Block number when the repair was scheduled
+    pub scheduled_at_block: u32,
+    /// First block at which the repair can be executed
+    pub executable_at_block: u32,
+    /// Votes from still-whitelisted validators to cancel the repair
+    pub cancel_votes: u32,
+    /// Validators who voted to cancel the repair
+    pub cancel_voters: Vec<AccountId>,
+    /// Whitelist version the cancel voters were last reconciled against
+    pub whitelist_version: u32,
+}
+
+/// Config change waiting out its timelock
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct PendingConfigChange {
+    /// Change to apply
+    pub change: ScheduledChange,
+    /// Block number when the change was scheduled
+    pub scheduled_at_block: u32,
+    /// First block at which the change can be executed
+    pub executable_at_block: u32,
+    /// Votes from still-whitelisted validators to cancel the change
+    pub cancel_votes: u32,
+    /// Validators who voted to cancel the change
+    pub cancel_voters: Vec<AccountId>,
+    /// Whitelist version the cancel voters were last reconciled against
+    pub whitelist_version: u32,
 }
 
 /// Result of a harvest_emissions call
@@ -111,10 +433,217 @@ pub struct CancelVote {
 pub struct HarvestResult {
     /// Total amount harvested from emissions
     pub harvested: u128,
-    /// Number of bounties filled
+    /// Number of bounties fully filled (became Active)
     pub bounties_filled: u32,
+    /// Number of bounties that received funds but remain below target
+    pub bounties_partially_filled: u32,
     /// Amount recycled to owner
     pub recycled: u128,
+    /// True if the fill budget ran out; the rest is filled by the next harvest
+    pub fills_pending: bool,
+    /// Issues cancelled because their funding deadline passed
+    pub expired_issues: u32,
+    /// Unallocated amount kept in the pool for the next harvest
+    pub retained: u128,
+}
+
+/// A single harvest kept in the recent harvest history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct HarvestRecord {
+    /// Block number of the harvest
+    pub block: u32,
+    /// Amount harvested
+    pub amount: u128,
+}
+
+/// Treasury economics snapshot returned by get_treasury_report()
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct TreasuryReport {
+    /// Current stake on the treasury hotkey
+    pub treasury_stake: u128,
+    /// Emission reported by the chain for the treasury hotkey
+    pub chain_emission: u128,
+    /// Total emissions harvested over the contract lifetime
+    pub total_harvested: u128,
+    /// Block number of the last harvest
+    pub last_harvest_block: u32,
+    /// Number of harvests in the recent history window
+    pub recent_harvest_count: u32,
+    /// Average harvest over the recent history window
+    pub average_harvest: u128,
+    /// Harvested alpha per block across the recent history window,
+    /// None until two harvests at different blocks are recorded
+    pub harvested_per_block: Option<u128>,
+}
+
+/// Cumulative accounting totals returned by get_accounting()
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct AccountingTotals {
+    /// Total emissions harvested over the contract lifetime
+    pub total_harvested: u128,
+    /// Total alpha allocated to bounties over the contract lifetime; released
+    /// excess is not subtracted
+    pub total_committed: u128,
+    /// Total alpha paid out to solvers
+    pub total_paid: u128,
+    /// Total alpha recycled
+    pub total_recycled: u128,
+    /// Funds currently reserved for Registered/Active issues and unpaid completions
+    pub outstanding_committed: u128,
+    /// Current stake on the treasury hotkey
+    pub treasury_stake: u128,
+}
+
+/// Funding progress of a single issue returned by get_funding_progress()
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct FundingProgress {
+    /// Target bounty amount
+    pub target: u128,
+    /// Bounty allocated so far
+    pub funded: u128,
+    /// funded / target in basis points (10_000 = fully funded)
+    pub percent_bps: u32,
+    /// Position in the bounty queue (0 = next to be filled), None if not queued
+    pub queue_position: Option<u32>,
+    /// Harvests needed to reach target at the recent average harvest size,
+    /// None if not queued or no harvest history is available
+    pub estimated_harvests_to_full: Option<u32>,
+    /// Blocks needed to reach target at the treasury's current emission rate
+    /// (falling back to the observed harvest rate), None if not queued or unknown
+    pub estimated_blocks_to_full: Option<u32>,
+}
+
+/// One page of a bounded status scan returned by get_issues_by_status()
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct IssuePage {
+    /// Matching issues, in id order
+    pub issues: Vec<Issue>,
+    /// Issue id to pass as start_id for the next page, None when the scan is complete
+    pub next_cursor: Option<u64>,
+}
+
+/// Issues changed since a given block, returned by sync_state()
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct StateSync {
+    /// Issues written at or after the requested block, in id order
+    pub issues: Vec<Issue>,
+    /// Issue id to pass as start_id for the next page, None when the scan is complete
+    pub next_cursor: Option<u64>,
+    /// Block the sync was taken at; pass as since_block on the next sync
+    pub synced_at_block: u32,
+}
+
+/// State inconsistency detected by audit()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum InvariantViolation {
+    /// bounty_queue references an issue id with no stored issue
+    QueuedIssueMissing,
+    /// bounty_queue references a Completed or Cancelled issue
+    QueuedIssueFinalized,
+    /// bounty_queue holds the same issue id more than once
+    QueuedIssueDuplicate,
+    /// A Registered issue is missing from bounty_queue and will never be funded
+    RegisteredIssueNotQueued,
+    /// A Registered or Active issue holds more bounty than its target
+    BountyExceedsTarget,
+    /// The issue's URL hash does not map back to its id
+    UrlIndexMismatch,
+    /// The issue is keyed by the hash of its pre-canonicalization URL
+    UrlHashNotCanonical,
+}
+
+/// A single inconsistency found by audit()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct AuditFinding {
+    /// Issue the inconsistency concerns
+    pub issue_id: u64,
+    pub violation: InvariantViolation,
+}
+
+/// One page of an audit() scan
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct AuditReport {
+    /// Inconsistencies found, queue findings first, then in issue id order
+    pub findings: Vec<AuditFinding>,
+    /// Issue id to pass as start_id for the next page, None when the scan is complete
+    pub next_cursor: Option<u64>,
+}
+
+/// Owner-tunable configuration values, identified in ConfigUpdated events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ConfigField {
+    /// Minimum target bounty for new issues
+    MinBounty,
+    /// Subnet ID
+    Netuid,
+    /// Maximum number of whitelisted validators
+    MaxValidators,
+    /// Fixed number of votes required for consensus (0 = simple majority)
+    RequiredValidatorVotes,
+    /// Blocks of silence after which a validator leaves the quorum
+    ValidatorInactivityBlocks,
+    /// Treasury shortfall, in basis points of commitments, that pauses harvesting
+    StakeAnomalyThresholdBps,
+    /// Maximum queue entries processed per harvest
+    MaxFillsPerCall,
+    /// Share of each harvest, in basis points, reserved for security-critical issues
+    SecurityReserveBps,
+    /// Share of unallocated harvest, in basis points, recycled instead of retained
+    RecycleBps,
+    /// Whether registration is restricted to allow-listed repositories (0 or 1)
+    RepoAllowlistEnabled,
+    /// Subnet tempo in blocks, used to turn per-epoch emission into a per-block rate
+    EmissionTempoBlocks,
+    /// Whether harvests emit a HarvestAllocation event per allocation (0 or 1)
+    VerboseHarvest,
+    /// Blocks an open solution or cancel proposal stays valid
+    VoteExpiryBlocks,
+    /// Blocks a scheduled owner, treasury, netuid or timelock change waits before execution
+    ConfigTimelockBlocks,
+    /// Minimum number of votes any proposal needs, whatever the majority rule
+    MinVotesRequired,
+    /// Applicant's own stake required to join via apply_as_validator (0 = disabled)
+    MinValidatorStake,
+    /// Blocks between an owner recovery proposal and its execution
+    OwnerRecoveryTimelockBlocks,
+}
+
+/// Runtime layout the contract was compiled against, returned by
+/// get_runtime_expectations() so deployment tooling can check it against the
+/// live chain before instantiation
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct RuntimeExpectations {
+    /// SubtensorModule pallet index
+    pub subtensor_module_pallet_index: u8,
+    /// Proxy pallet index
+    pub proxy_pallet_index: u8,
+    /// Proxy::proxy call index
+    pub proxy_call_index: u8,
+    /// SubtensorModule::transfer_stake call index
+    pub transfer_stake_call_index: u8,
+    /// SubtensorModule::recycle_alpha call index
+    pub recycle_alpha_call_index: u8,
+    /// ProxyType variant the owner must grant for transfer_stake
+    pub proxy_type_transfer: u8,
+    /// ProxyType variant the owner must grant for recycle_alpha
+    pub proxy_type_non_critical: u8,
+    /// Subtensor chain extension id
+    pub chain_extension_id: u16,
+    /// Chain extension function id of get_stake_info
+    pub get_stake_info_func_id: u16,
+    /// Subnet the contract operates on
+    pub netuid: u16,
 }
 
 /// Contract configuration returned by get_config()
@@ -123,6 +652,42 @@ pub struct HarvestResult {
 pub struct ContractConfig {
     /// Number of validator votes required for consensus
     pub required_validator_votes: u32,
+    /// Fixed vote requirement set by the owner, None for simple majority of active validators
+    pub required_validator_votes_override: Option<u32>,
+    /// Minimum number of votes any proposal needs, whatever the majority rule
+    pub min_votes_required: u32,
+    /// Applicant's own stake required to join via apply_as_validator, 0 if disabled
+    pub min_validator_stake: u128,
     /// Subnet ID
     pub netuid: u16,
+    /// Minimum target bounty for new issues
+    pub min_bounty: u128,
+    /// Number of whitelisted validators
+    pub validator_count: u32,
+    /// Maximum number of whitelisted validators
+    pub max_validators: u32,
+    /// Blocks of silence after which a validator leaves the quorum
+    pub validator_inactivity_blocks: u32,
+    /// Treasury shortfall, in basis points of commitments, that pauses harvesting
+    pub stake_anomaly_threshold_bps: u32,
+    /// Whether harvesting is paused pending owner acknowledgement of a stake anomaly
+    pub harvest_paused: bool,
+    /// Maximum queue entries processed per harvest
+    pub max_fills_per_call: u32,
+    /// Share of each harvest, in basis points, reserved for security-critical issues
+    pub security_reserve_bps: u32,
+    /// Share of unallocated harvest, in basis points, recycled instead of retained
+    pub recycle_bps: u32,
+    /// Whether registration is restricted to allow-listed repositories
+    pub repo_allowlist_enabled: bool,
+    /// Subnet tempo in blocks, used to turn per-epoch emission into a per-block rate
+    pub emission_tempo_blocks: u32,
+    /// Whether harvests emit a HarvestAllocation event per allocation
+    pub verbose_harvest: bool,
+    /// Blocks an open solution or cancel proposal stays valid
+    pub vote_expiry_blocks: u32,
+    /// Blocks a scheduled owner, treasury, netuid or timelock change waits before execution
+    pub config_timelock_blocks: u32,
+    /// Blocks between an owner recovery proposal and its execution
+    pub owner_recovery_timelock_blocks: u32,
 }
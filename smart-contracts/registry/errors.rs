@@ -0,0 +1,16 @@
+use scale::{Decode, Encode};
+
+/// Errors that can occur in the ContractRegistry contract
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Error {
+    /// Caller is not the registry owner
+    NotOwner,
+    /// Name is empty, longer than MAX_NAME_LEN, or uses characters other than
+    /// lowercase ASCII letters, digits, '-' and '.'
+    InvalidName,
+    /// No entry is registered under the given name
+    NameNotFound,
+    /// Registry already holds MAX_ENTRIES names
+    TooManyEntries,
+}
@@ -0,0 +1,32 @@
+use ink::prelude::string::String;
+use ink::primitives::{AccountId, Hash};
+
+/// Event emitted when a name is registered or pointed at a new deployment
+#[ink::event]
+pub struct EntryUpdated {
+    #[ink(topic)]
+    pub name: String,
+    #[ink(topic)]
+    pub address: AccountId,
+    pub code_hash: Hash,
+    pub version: u32,
+    /// Address the name resolved to before this update, None for a new name
+    pub previous_address: Option<AccountId>,
+}
+
+/// Event emitted when a name is removed from the registry
+#[ink::event]
+pub struct EntryRemoved {
+    #[ink(topic)]
+    pub name: String,
+    pub address: AccountId,
+}
+
+/// Event emitted when registry ownership changes
+#[ink::event]
+pub struct OwnerChanged {
+    #[ink(topic)]
+    pub old_owner: AccountId,
+    #[ink(topic)]
+    pub new_owner: AccountId,
+}
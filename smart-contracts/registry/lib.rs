@@ -0,0 +1,188 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+mod errors;
+mod events;
+mod types;
+
+pub use errors::Error;
+pub use types::*;
+
+/// Maps logical deployment names ("issues-v0", "competitions-v1") to the
+/// contract address and code hash currently in use, so tooling resolves
+/// addresses here instead of hard-coding them across migrations.
+#[ink::contract]
+mod contract_registry {
+    use crate::events::*;
+    use crate::types::*;
+    use crate::Error;
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    // ========================================================================
+    // Constants
+    // ========================================================================
+
+    /// Maximum length in bytes of a registered name
+    pub const MAX_NAME_LEN: usize = 64;
+
+    /// Maximum number of registered names, bounding get_names()
+    pub const MAX_ENTRIES: usize = 64;
+
+    // ========================================================================
+    // Contract Storage
+    // ========================================================================
+
+    #[ink(storage)]
+    pub struct ContractRegistry {
+        /// Governance account allowed to update entries
+        owner: AccountId,
+        /// Current deployment per name
+        entries: Mapping<String, RegistryEntry>,
+        /// Registered names, in registration order
+        names: Vec<String>,
+    }
+
+    impl ContractRegistry {
+        // ========================================================================
+        // Constructor
+        // ========================================================================
+
+        /// Creates an empty registry governed by `owner`
+        #[ink(constructor)]
+        pub fn new(owner: AccountId) -> Self {
+            Self {
+                owner,
+                entries: Mapping::default(),
+                names: Vec::new(),
+            }
+        }
+
+        // ========================================================================
+        // Governance Functions
+        // ========================================================================
+
+        /// Registers `name` or points it at a new deployment (owner only).
+        /// Returns the entry's new version.
+        #[ink(message)]
+        pub fn set_entry(
+            &mut self,
+            name: String,
+            address: AccountId,
+            code_hash: Hash,
+        ) -> Result<u32, Error> {
+            self.ensure_owner()?;
+            if !Self::is_valid_name(&name) {
+                return Err(Error::InvalidName);
+            }
+
+            let previous = self.entries.get(&name);
+            if previous.is_none() {
+                if self.names.len() >= MAX_ENTRIES {
+                    return Err(Error::TooManyEntries);
+                }
+                self.names.push(name.clone());
+            }
+
+            let version = previous.map_or(1, |entry| entry.version.saturating_add(1));
+            self.entries.insert(
+                &name,
+                &RegistryEntry {
+                    address,
+                    code_hash,
+                    version,
+                    updated_at_block: self.env().block_number(),
+                },
+            );
+
+            self.env().emit_event(EntryUpdated {
+                name,
+                address,
+                code_hash,
+                version,
+                previous_address: previous.map(|entry| entry.address),
+            });
+            Ok(version)
+        }
+
+        /// Removes `name` from the registry (owner only).
+        #[ink(message)]
+        pub fn remove_entry(&mut self, name: String) -> Result<(), Error> {
+            self.ensure_owner()?;
+            let entry = self.entries.take(&name).ok_or(Error::NameNotFound)?;
+            self.names.retain(|n| n != &name);
+
+            self.env().emit_event(EntryRemoved {
+                name,
+                address: entry.address,
+            });
+            Ok(())
+        }
+
+        /// Transfers registry ownership (owner only).
+        #[ink(message)]
+        pub fn set_owner(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            let old_owner = self.owner;
+            self.owner = new_owner;
+            self.env().emit_event(OwnerChanged {
+                old_owner,
+                new_owner,
+            });
+            Ok(())
+        }
+
+        // ========================================================================
+        // Query Functions
+        // ========================================================================
+
+        /// Returns the address currently registered under `name`
+        #[ink(message)]
+        pub fn resolve(&self, name: String) -> Option<AccountId> {
+            self.entries.get(&name).map(|entry| entry.address)
+        }
+
+        /// Returns the full entry registered under `name`
+        #[ink(message)]
+        pub fn get_entry(&self, name: String) -> Option<RegistryEntry> {
+            self.entries.get(&name)
+        }
+
+        /// Returns all registered names, in registration order
+        #[ink(message)]
+        pub fn get_names(&self) -> Vec<String> {
+            self.names.clone()
+        }
+
+        #[ink(message)]
+        pub fn owner(&self) -> AccountId {
+            self.owner
+        }
+
+        // ========================================================================
+        // Internal Helpers
+        // ========================================================================
+
+        fn ensure_owner(&self) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            Ok(())
+        }
+
+        /// Accepts 1..=MAX_NAME_LEN bytes of lowercase ASCII letters, digits,
+        /// '-' and '.', so each deployment has exactly one spelling.
+        fn is_valid_name(name: &str) -> bool {
+            !name.is_empty()
+                && name.len() <= MAX_NAME_LEN
+                && name
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'.')
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        include!("tests.rs");
+    }
+}
@@ -0,0 +1,141 @@
+use super::*;
+use ink::env::test;
+
+/// Creates distinct AccountIds for testing.
+fn account(byte: u8) -> AccountId {
+    AccountId::from([byte; 32])
+}
+
+/// Sets the caller for the next contract call.
+fn set_caller(caller: AccountId) {
+    test::set_caller::<ink::env::DefaultEnvironment>(caller);
+}
+
+/// Creates a registry owned by account(1), with account(1) as caller.
+fn create_default_registry() -> ContractRegistry {
+    set_caller(account(1));
+    ContractRegistry::new(account(1))
+}
+
+#[ink::test]
+fn set_entry_registers_and_resolves() {
+    let mut registry = create_default_registry();
+    assert_eq!(registry.resolve(String::from("issues-v0")), None);
+
+    let version = registry
+        .set_entry(String::from("issues-v0"), account(7), Hash::from([7; 32]))
+        .unwrap();
+    assert_eq!(version, 1);
+    assert_eq!(
+        registry.resolve(String::from("issues-v0")),
+        Some(account(7))
+    );
+    assert_eq!(registry.get_names(), vec![String::from("issues-v0")]);
+
+    let entry = registry.get_entry(String::from("issues-v0")).unwrap();
+    assert_eq!(entry.code_hash, Hash::from([7; 32]));
+    assert_eq!(entry.version, 1);
+}
+
+#[ink::test]
+fn set_entry_migration_bumps_version() {
+    let mut registry = create_default_registry();
+    registry
+        .set_entry(String::from("issues-v0"), account(7), Hash::from([7; 32]))
+        .unwrap();
+    test::set_block_number::<ink::env::DefaultEnvironment>(10);
+
+    let version = registry
+        .set_entry(String::from("issues-v0"), account(8), Hash::from([8; 32]))
+        .unwrap();
+    assert_eq!(version, 2);
+
+    let entry = registry.get_entry(String::from("issues-v0")).unwrap();
+    assert_eq!(entry.address, account(8));
+    assert_eq!(entry.updated_at_block, 10);
+    assert_eq!(registry.get_names().len(), 1);
+
+    let event = test::recorded_events().last().unwrap();
+    let updated =
+        <crate::events::EntryUpdated as scale::Decode>::decode(&mut &event.data[..]).unwrap();
+    assert_eq!(updated.previous_address, Some(account(7)));
+}
+
+#[ink::test]
+fn remove_entry_clears_name() {
+    let mut registry = create_default_registry();
+    registry
+        .set_entry(String::from("issues-v0"), account(7), Hash::from([7; 32]))
+        .unwrap();
+    registry
+        .set_entry(
+            String::from("competitions-v1"),
+            account(8),
+            Hash::from([8; 32]),
+        )
+        .unwrap();
+
+    registry.remove_entry(String::from("issues-v0")).unwrap();
+    assert_eq!(registry.resolve(String::from("issues-v0")), None);
+    assert_eq!(registry.get_names(), vec![String::from("competitions-v1")]);
+    assert_eq!(
+        registry.remove_entry(String::from("issues-v0")),
+        Err(Error::NameNotFound)
+    );
+}
+
+#[ink::test]
+fn updates_require_owner() {
+    let mut registry = create_default_registry();
+    set_caller(account(4));
+    assert_eq!(
+        registry.set_entry(String::from("issues-v0"), account(7), Hash::from([7; 32])),
+        Err(Error::NotOwner)
+    );
+    assert_eq!(
+        registry.remove_entry(String::from("issues-v0")),
+        Err(Error::NotOwner)
+    );
+    assert_eq!(registry.set_owner(account(4)), Err(Error::NotOwner));
+
+    set_caller(account(1));
+    registry.set_owner(account(4)).unwrap();
+    assert_eq!(registry.owner(), account(4));
+    assert_eq!(
+        registry.set_entry(String::from("issues-v0"), account(7), Hash::from([7; 32])),
+        Err(Error::NotOwner)
+    );
+}
+
+#[ink::test]
+fn set_entry_rejects_invalid_names() {
+    let mut registry = create_default_registry();
+    let too_long = "a".repeat(MAX_NAME_LEN + 1);
+    for name in ["", "Issues-v0", "issues v0", "issues/v0", too_long.as_str()] {
+        assert_eq!(
+            registry.set_entry(String::from(name), account(7), Hash::from([7; 32])),
+            Err(Error::InvalidName)
+        );
+    }
+    assert!(registry.get_names().is_empty());
+}
+
+#[ink::test]
+fn set_entry_caps_name_count() {
+    let mut registry = create_default_registry();
+    for i in 0..MAX_ENTRIES {
+        registry
+            .set_entry(format!("contract-{}", i), account(7), Hash::from([7; 32]))
+            .unwrap();
+    }
+    assert_eq!(
+        registry.set_entry(String::from("one-more"), account(7), Hash::from([7; 32])),
+        Err(Error::TooManyEntries)
+    );
+
+    // Updating an existing name is still allowed at the cap
+    assert_eq!(
+        registry.set_entry(String::from("contract-0"), account(8), Hash::from([8; 32])),
+        Ok(2)
+    );
+}
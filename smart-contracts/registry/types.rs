@@ -0,0 +1,16 @@
+use ink::primitives::{AccountId, Hash};
+use scale::{Decode, Encode};
+
+/// Current deployment registered under a logical name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct RegistryEntry {
+    /// Contract address callers should use
+    pub address: AccountId,
+    /// Code hash the contract at `address` is expected to run
+    pub code_hash: Hash,
+    /// Incremented on every update of this name, starting at 1
+    pub version: u32,
+    /// Block number of the last update
+    pub updated_at_block: u32,
+}
@@ -0,0 +1,77 @@
+# The MIT License (MIT)
+# Copyright © 2025 Entrius
+
+"""Tests for contract registry name resolution."""
+
+from unittest.mock import MagicMock, patch
+
+from gittensor.utils.utils import get_contract_address, is_registry_name
+from gittensor.validator.issue_competitions.registry_client import RESOLVE_SELECTOR, resolve_contract_name
+
+SS58 = '5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY'
+CONTRACT_BYTES = bytes(range(32))
+
+
+def test_is_registry_name():
+    assert is_registry_name('issues-v0')
+    assert is_registry_name('issues.v1')
+    assert not is_registry_name(SS58)
+    assert not is_registry_name('Issues-v0')
+    assert not is_registry_name('')
+    assert is_registry_name('a' * 48)
+    assert is_registry_name('a' * 64)
+    assert not is_registry_name('a' * 65)
+
+
+def test_resolve_contract_name_decodes_some():
+    substrate = MagicMock()
+    substrate.ss58_encode.return_value = '5Resolved'
+    with patch(
+        'gittensor.validator.issue_competitions.registry_client.dry_run_contract_call',
+        return_value=b'\x01' + CONTRACT_BYTES,
+    ) as call:
+        assert resolve_contract_name(substrate, 'issues-v0', registry_address='5Registry') == '5Resolved'
+
+    input_data = call.call_args.args[2]
+    assert input_data == RESOLVE_SELECTOR + bytes([9 << 2]) + b'issues-v0'
+    substrate.ss58_encode.assert_called_once_with(CONTRACT_BYTES.hex())
+
+
+def test_resolve_contract_name_returns_none_when_unregistered():
+    with patch(
+        'gittensor.validator.issue_competitions.registry_client.dry_run_contract_call',
+        return_value=b'\x00',
+    ):
+        assert resolve_contract_name(MagicMock(), 'issues-v0', registry_address='5Registry') is None
+
+
+def test_resolve_contract_name_without_registry_skips_call():
+    with (
+        patch('gittensor.validator.issue_competitions.registry_client.get_registry_address', return_value=''),
+        patch('gittensor.validator.issue_competitions.registry_client.dry_run_contract_call') as call,
+    ):
+        assert resolve_contract_name(MagicMock(), 'issues-v0') is None
+    call.assert_not_called()
+
+
+def test_get_contract_address_prefers_env_address(monkeypatch):
+    monkeypatch.setenv('CONTRACT_ADDRESS', SS58)
+    assert get_contract_address(MagicMock()) == SS58
+
+
+def test_get_contract_address_falls_back_when_unresolved(monkeypatch):
+    monkeypatch.setenv('CONTRACT_ADDRESS', 'issues-v0')
+    with (
+        patch('gittensor.validator.issue_competitions.registry_client.resolve_contract_name', return_value=None),
+        patch('gittensor.utils.utils.CONTRACT_ADDRESS', SS58),
+    ):
+        assert get_contract_address(MagicMock()) == SS58
+
+
+def test_get_contract_address_resolves_registry_name(monkeypatch):
+    monkeypatch.setenv('CONTRACT_ADDRESS', 'issues-v0')
+    with patch(
+        'gittensor.validator.issue_competitions.registry_client.resolve_contract_name', return_value='5Resolved'
+    ) as resolve:
+        assert get_contract_address(MagicMock()) == '5Resolved'
+    assert resolve.call_args.args[1] == 'issues-v0'
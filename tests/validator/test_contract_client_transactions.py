@@ -2,7 +2,6 @@
 
 """Tests for IssueCompetitionContractClient transaction methods."""
 
-import hashlib
 import struct
 from types import SimpleNamespace
 from unittest.mock import MagicMock, patch
@@ -11,12 +10,26 @@ import pytest
 
 from gittensor.validator.issue_competitions.contract_client import (
     DEFAULT_GAS_LIMIT,
+    CancelDestination,
+    ConfigChangeKind,
     IssueCompetitionContractClient,
     _scale_compact_length,
+    canonical_issue_url,
+    compute_label_hash,
+    compute_url_hash,
+    hash_string,
 )
 
 # (method, call_kwargs, expected_contract_method, expected_args, uses_hotkey, explicit_gas)
 METHOD_TABLE = [
+    (
+        'heartbeat',
+        lambda w: dict(wallet=w),
+        'heartbeat',
+        {},
+        True,
+        False,
+    ),
     (
         'vote_solution',
         lambda w: dict(issue_id=1, solver_hotkey='5Solver', solver_coldkey='5Cold', pr_number=42, wallet=w),
@@ -29,26 +42,47 @@ METHOD_TABLE = [
         'vote_cancel_issue',
         lambda w: dict(issue_id=2, reason='stale', wallet=w),
         'vote_cancel_issue',
-        {'issue_id': 2, 'reason_hash': hashlib.sha256(b'stale').digest()},
+        {
+            'issue_id': 2,
+            'reason_hash': hash_string('stale'),
+            'destination': CancelDestination.RECYCLE,
+            'reason': 'stale',
+        },
         True,
         False,
     ),
-    ('cancel_issue', lambda w: dict(issue_id=3, wallet=w), 'cancel_issue', {'issue_id': 3}, False, True),
     (
-        'set_owner',
-        lambda w: dict(new_owner='5NewOwner', wallet=w),
-        'set_owner',
-        {'new_owner': '5NewOwner'},
+        'cancel_issue',
+        lambda w: dict(issue_id=3, wallet=w),
+        'cancel_issue',
+        {'issue_id': 3, 'reason': None},
+        False,
+        True,
+    ),
+    (
+        'cancel_issue',
+        lambda w: dict(issue_id=4, wallet=w, reason='duplicate'),
+        'cancel_issue',
+        {'issue_id': 4, 'reason': 'duplicate'},
+        False,
+        True,
+    ),
+    (
+        'schedule_config_change',
+        lambda w: dict(kind=ConfigChangeKind.OWNER, value='5NewOwner', wallet=w),
+        'schedule_config_change',
+        {'change': (ConfigChangeKind.OWNER, '5NewOwner')},
         False,
         True,
     ),
+    ('execute_config_change', lambda w: dict(wallet=w), 'execute_config_change', {}, False, True),
     ('add_validator', lambda w: dict(hotkey='5Val', wallet=w), 'add_validator', {'hotkey': '5Val'}, False, True),
     ('remove_validator', lambda w: dict(hotkey='5Val', wallet=w), 'remove_validator', {'hotkey': '5Val'}, False, True),
     (
-        'set_treasury_hotkey',
-        lambda w: dict(new_hotkey='5Treasury', wallet=w),
-        'set_treasury_hotkey',
-        {'new_hotkey': '5Treasury'},
+        'schedule_config_change',
+        lambda w: dict(kind=ConfigChangeKind.TREASURY_HOTKEY, value='5Treasury', wallet=w),
+        'schedule_config_change',
+        {'change': (ConfigChangeKind.TREASURY_HOTKEY, '5Treasury')},
         False,
         True,
     ),
@@ -196,6 +230,8 @@ class TestEncodeArgsStr:
                 'repository_full_name': repo,
                 'issue_number': 1,
                 'target_bounty': 10_000_000_000,
+                'difficulty': None,
+                'label_hash': None,
             },
         )
 
@@ -216,7 +252,27 @@ class TestEncodeArgsStr:
         low = struct.unpack_from('<Q', encoded, offset)[0]
         high = struct.unpack_from('<Q', encoded, offset + 8)[0]
         assert low + (high << 64) == 10_000_000_000
-        assert len(encoded) == offset + 16
+        offset += 16
+
+        # difficulty and label_hash are both None
+        assert encoded[offset:] == b'\x00\x00'
+
+    def test_register_issue_encodes_optional_metadata(self, client):
+        label_hash = compute_label_hash(['Bug', 'good first issue'])
+        encoded = client._encode_args(
+            'register_issue',
+            {
+                'github_url': 'https://github.com/owner/repo/issues/1',
+                'repository_full_name': 'owner/repo',
+                'issue_number': 1,
+                'target_bounty': 1,
+                'difficulty': 3,
+                'label_hash': label_hash,
+            },
+        )
+
+        assert encoded[-35:] == b'\x01\x03' + b'\x01' + label_hash
+        assert compute_label_hash(['good first issue', 'bug', 'BUG']) == label_hash
 
     def test_register_issue_long_url_uses_mode_1(self, client):
         long_repo = 'a' * 30 + '/' + 'b' * 30
@@ -231,6 +287,8 @@ class TestEncodeArgsStr:
                 'repository_full_name': long_repo,
                 'issue_number': 12345,
                 'target_bounty': 1,
+                'difficulty': None,
+                'label_hash': None,
             },
         )
 
@@ -247,6 +305,8 @@ class TestEncodeArgsStr:
                     'repository_full_name': 'owner/repo',
                     'issue_number': 1,
                     'target_bounty': 1,
+                    'difficulty': None,
+                    'label_hash': None,
                 },
             )
 
@@ -261,8 +321,93 @@ class TestEncodeArgsStr:
                 'repository_full_name': 'owner/repo',
                 'issue_number': 1,
                 'target_bounty': 1,
+                'difficulty': None,
+                'label_hash': None,
             },
         )
 
         assert encoded[0] == len(url_bytes) << 2
         assert encoded[1 : 1 + len(url_bytes)] == url_bytes
+
+
+class TestEncodeArgsVoteCancel:
+    """SCALE encoding of the enum and Option<String> arguments of vote_cancel_issue."""
+
+    def _encode(self, client, destination, reason):
+        return client._encode_args(
+            'vote_cancel_issue',
+            {'issue_id': 5, 'reason_hash': b'\x11' * 32, 'destination': destination, 'reason': reason},
+        )
+
+    def test_some_reason_and_pool_destination(self, client):
+        encoded = self._encode(client, CancelDestination.POOL, 'dup')
+        assert encoded[:8] == struct.pack('<Q', 5)
+        assert encoded[8:40] == b'\x11' * 32
+        assert encoded[40] == 1
+        assert encoded[41:] == b'\x01' + bytes([3 << 2]) + b'dup'
+
+    def test_none_reason_encodes_single_byte(self, client):
+        encoded = self._encode(client, CancelDestination.RECYCLE, None)
+        assert encoded[40:] == b'\x00\x00'
+
+
+class TestEncodeArgsScheduledChange:
+    """SCALE encoding of the ScheduledChange enum passed to schedule_config_change."""
+
+    def test_owner_change_encodes_variant_and_account(self, client):
+        client.subtensor.substrate.ss58_decode.return_value = '22' * 32
+        encoded = client._encode_args('schedule_config_change', {'change': (ConfigChangeKind.OWNER, '5NewOwner')})
+        assert encoded == b'\x00' + b'\x22' * 32
+
+    def test_timelock_change_encodes_u32_payload(self, client):
+        encoded = client._encode_args(
+            'schedule_config_change', {'change': (ConfigChangeKind.CONFIG_TIMELOCK_BLOCKS, 7200)}
+        )
+        assert encoded == b'\x03' + struct.pack('<I', 7200)
+
+
+class TestCanonicalIssueUrl:
+    """Python port of the contract's URL canonicalization used for dedup hashes."""
+
+    CANONICAL = 'https://github.com/org/repo/issues/1'
+
+    @pytest.mark.parametrize(
+        'url',
+        [
+            'https://github.com/org/repo/issues/1',
+            'HTTPS://GitHub.com/Org/Repo/issues/1',
+            'https://github.com/org/repo/issues/1/',
+            'https://github.com/org/repo/issues/1?utm_source=x',
+            'https://github.com/Org/repo/issues/1//#issuecomment-7',
+        ],
+    )
+    def test_variants_share_canonical_form(self, url):
+        assert canonical_issue_url(url) == self.CANONICAL
+
+    def test_path_after_repo_keeps_case(self):
+        assert canonical_issue_url('https://github.com/Org/Repo/Issues/1') == 'https://github.com/org/repo/Issues/1'
+
+    def test_hash_matches_contract(self):
+        # keccak256 of CANONICAL as computed by the contract's hash_string
+        expected = bytes.fromhex('3901c4547b44f5afa0ae819617f9d1826de9e8ce2eedcead4ebf3d8a939e8d3e')
+        assert compute_url_hash('https://github.com/Org/Repo/issues/1/') == expected
+
+    def test_reason_hash_matches_contract(self):
+        # vote_cancel_issue rejects a reason_hash that is not hash_string(reason)
+        expected = bytes.fromhex('413b78c8c30652ee47eb6fcadce8506b4f96263dc2c48e71e29de6bf5fdff2ea')
+        assert hash_string('stale') == expected
+
+    def test_get_issue_id_by_url_sends_canonical_hash(self, client):
+        with (
+            patch.object(client, '_raw_contract_read', return_value=struct.pack('<Q', 9)) as read,
+            patch(
+                'gittensor.validator.issue_competitions.contract_client.compute_url_hash', return_value=b'\x07' * 32
+            ) as url_hash,
+        ):
+            assert client.get_issue_id_by_url('https://github.com/Org/Repo/issues/1') == 9
+        url_hash.assert_called_once_with('https://github.com/Org/Repo/issues/1')
+        read.assert_called_once_with('get_issue_by_url_hash', {'url_hash': b'\x07' * 32})
+
+    def test_get_issue_id_by_url_unregistered(self, client):
+        with patch.object(client, '_raw_contract_read', return_value=None):
+            assert client.get_issue_id_by_url(self.CANONICAL) == 0
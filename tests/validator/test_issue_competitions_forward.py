@@ -10,6 +10,7 @@ from typing import Any, cast
 from unittest.mock import MagicMock, patch
 
 from gittensor.classes import MinerEvaluation
+from gittensor.constants import VALIDATOR_HEARTBEAT_INTERVAL_BLOCKS
 from gittensor.validator.issue_competitions.forward import issue_competitions
 
 
@@ -26,6 +27,7 @@ def _make_validator():
         subtensor=MagicMock(),
         wallet=MagicMock(),
         config=SimpleNamespace(netuid=1),
+        block=10_000,
     )
 
 
@@ -42,6 +44,7 @@ def test_solver_lookup_failure_does_not_cancel():
     validator = _make_validator()
     contract_client = MagicMock()
     contract_client.harvest_emissions.return_value = None
+    contract_client.get_validator_last_seen.return_value = None
     contract_client.get_issues_by_status.return_value = [_make_issue()]
 
     with (
@@ -71,6 +74,7 @@ def test_no_solver_without_lookup_failure_votes_cancel():
     validator = _make_validator()
     contract_client = MagicMock()
     contract_client.harvest_emissions.return_value = None
+    contract_client.get_validator_last_seen.return_value = None
     contract_client.get_issues_by_status.return_value = [_make_issue()]
     contract_client.vote_cancel_issue.return_value = True
 
@@ -107,6 +111,7 @@ def test_ineligible_miner_still_receives_solution_vote():
     validator = _make_validator()
     contract_client = MagicMock()
     contract_client.harvest_emissions.return_value = None
+    contract_client.get_validator_last_seen.return_value = None
     contract_client.get_issues_by_status.return_value = [_make_issue()]
     contract_client.vote_solution.return_value = True
 
@@ -141,6 +146,7 @@ def test_failed_miner_does_not_receive_solution_vote():
     validator = _make_validator()
     contract_client = MagicMock()
     contract_client.harvest_emissions.return_value = None
+    contract_client.get_validator_last_seen.return_value = None
     contract_client.get_issues_by_status.return_value = [_make_issue()]
     contract_client.vote_cancel_issue.return_value = True
 
@@ -174,3 +180,36 @@ def test_failed_miner_does_not_receive_solution_vote():
         reason='Issue closed externally (not by a registered miner, solver: 999)',
         wallet=validator.wallet,
     )
+
+
+def _run_with_last_seen(validator, last_seen):
+    contract_client = MagicMock()
+    contract_client.harvest_emissions.return_value = None
+    contract_client.get_validator_last_seen.return_value = last_seen
+    contract_client.get_issues_by_status.return_value = []
+
+    with (
+        patch('gittensor.validator.issue_competitions.forward.GITTENSOR_VALIDATOR_PAT', 'ghp_validator'),
+        patch('gittensor.validator.issue_competitions.forward.get_contract_address', return_value='5Contract'),
+        patch(
+            'gittensor.validator.issue_competitions.forward.IssueCompetitionContractClient',
+            return_value=contract_client,
+        ),
+    ):
+        _run(issue_competitions(cast(Any, validator), {}))
+    return contract_client
+
+
+def test_heartbeat_sent_when_last_seen_is_stale():
+    validator = _make_validator()
+    contract_client = _run_with_last_seen(validator, validator.block - VALIDATOR_HEARTBEAT_INTERVAL_BLOCKS)
+    contract_client.heartbeat.assert_called_once_with(validator.wallet)
+
+
+def test_heartbeat_skipped_when_recently_seen_or_not_whitelisted():
+    validator = _make_validator()
+    contract_client = _run_with_last_seen(validator, validator.block - 1)
+    contract_client.heartbeat.assert_not_called()
+
+    contract_client = _run_with_last_seen(validator, None)
+    contract_client.heartbeat.assert_not_called()